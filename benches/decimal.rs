@@ -0,0 +1,34 @@
+//! Decimal parsing and arithmetic benchmarks.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::str::FromStr;
+use xsd_types::Decimal;
+
+fn parsing(c: &mut Criterion) {
+	c.bench_function("Decimal::from_str", |b| {
+		b.iter(|| Decimal::from_str(black_box("-12345.6789")).unwrap())
+	});
+}
+
+fn arithmetic(c: &mut Criterion) {
+	let a = Decimal::from_str("12345.6789").unwrap();
+	let b = Decimal::from_str("0.125").unwrap();
+
+	c.bench_function("Decimal::checked_add", |bench| {
+		bench.iter(|| black_box(&a).checked_add(black_box(&b)))
+	});
+
+	c.bench_function("Decimal::checked_sub", |bench| {
+		bench.iter(|| black_box(&a).checked_sub(black_box(&b)))
+	});
+
+	c.bench_function("Decimal::checked_mul", |bench| {
+		bench.iter(|| black_box(&a).checked_mul(black_box(&b)))
+	});
+
+	c.bench_function("Decimal::checked_div", |bench| {
+		bench.iter(|| black_box(&a).checked_div(black_box(&b)))
+	});
+}
+
+criterion_group!(benches, parsing, arithmetic);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+//! Integer construction, arithmetic and parsing benchmarks.
+//!
+//! Small values fit in a machine word while 100-digit values do not, so
+//! comparing both measures the cost of the arbitrary precision
+//! representation.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::str::FromStr;
+use xsd_types::{DateTime, Float, Integer, NonNegativeInteger};
+
+const SMALL: &str = "123456";
+const LARGE: &str = "1234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890";
+
+fn construction(c: &mut Criterion) {
+	c.bench_function("Integer::from(i64)", |b| {
+		b.iter(|| Integer::from(black_box(123456i64)))
+	});
+
+	c.bench_function("NonNegativeInteger::from(u64)", |b| {
+		b.iter(|| NonNegativeInteger::from(black_box(123456u64)))
+	});
+}
+
+fn addition(c: &mut Criterion) {
+	let small = Integer::from_str(SMALL).unwrap();
+	c.bench_function("Integer + Integer (small)", |b| {
+		b.iter(|| black_box(small.clone()) + black_box(small.clone()))
+	});
+
+	let large = Integer::from_str(LARGE).unwrap();
+	c.bench_function("Integer + Integer (100 digits)", |b| {
+		b.iter(|| black_box(large.clone()) + black_box(large.clone()))
+	});
+}
+
+fn parsing(c: &mut Criterion) {
+	c.bench_function("Integer::from_str (small)", |b| {
+		b.iter(|| Integer::from_str(black_box(SMALL)).unwrap())
+	});
+
+	c.bench_function("Integer::from_str (100 digits)", |b| {
+		b.iter(|| Integer::from_str(black_box(LARGE)).unwrap())
+	});
+
+	c.bench_function("Float::from_str", |b| {
+		b.iter(|| Float::from_str(black_box("1.2345E3")).unwrap())
+	});
+
+	c.bench_function("DateTime::from_str", |b| {
+		b.iter(|| DateTime::from_str(black_box("2002-10-10T12:00:00-05:00")).unwrap())
+	});
+}
+
+criterion_group!(benches, construction, addition, parsing);
+criterion_main!(benches);
@@ -0,0 +1,52 @@
+//! Using the integer types in a `no_std` environment.
+//!
+//! The `std` feature must be disabled:
+//!
+//! ```toml
+//! xsd-types = { version = "0.8", default-features = false }
+//! ```
+//!
+//! This example is built as a library so it can be checked without a target
+//! runtime: `cargo build --example no_std --no-default-features`.
+#![no_std]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use xsd_types::{lexical, Integer, IntegerDatatype, NonNegativeInteger, ParseRdf, UnsignedByte};
+
+/// Sums the given lexical integers, returning `None` if one is invalid.
+pub fn sum(values: &[&str]) -> Option<Integer> {
+	let mut result = Integer::zero();
+
+	for v in values {
+		let i: Integer = ParseRdf::parse_rdf(v).ok()?;
+		result = result + i;
+	}
+
+	Some(result)
+}
+
+/// Returns the big-endian bytes of the given integer if it is non negative.
+pub fn to_bytes(i: Integer) -> Option<Vec<u8>> {
+	let n = NonNegativeInteger::try_from(i).ok()?;
+	Some(n.to_bytes_be().1)
+}
+
+/// Returns the most specific XSD datatype of the given integer.
+pub fn datatype(i: &Integer) -> Option<IntegerDatatype> {
+	i.integer_type()
+}
+
+/// Parses a non negative integer and narrows it down to an `unsignedByte`.
+pub fn to_unsigned_byte(s: &str) -> Option<UnsignedByte> {
+	let lexical = lexical::NonNegativeInteger::new(s).ok()?;
+	let n: NonNegativeInteger = lexical.value();
+	n.try_into().ok()
+}
+
+/// Checks that the given positive integer is one.
+pub fn is_one(s: &str) -> bool {
+	lexical::PositiveInteger::new(s)
+		.map(|l| l.value().is_one())
+		.unwrap_or(false)
+}
@@ -1,19 +1,72 @@
+#[cfg(feature = "std")]
 mod any_uri;
+#[cfg(feature = "std")]
 mod base64_binary;
+#[cfg(feature = "std")]
 mod boolean;
+#[cfg(feature = "std")]
+#[allow(clippy::impl_hash_borrow_with_str_and_bytes)] // derived by `RegularGrammar`.
+mod date;
+#[cfg(feature = "std")]
+#[allow(clippy::impl_hash_borrow_with_str_and_bytes)] // derived by `RegularGrammar`.
 mod date_time;
 mod decimal;
+#[cfg(feature = "std")]
 pub mod double;
+#[cfg(feature = "std")]
+#[allow(clippy::impl_hash_borrow_with_str_and_bytes)] // derived by `RegularGrammar`.
+mod duration;
+#[cfg(feature = "std")]
 pub mod float;
+#[cfg(feature = "std")]
 mod hex_binary;
-
+#[cfg(feature = "std")]
+mod language;
+#[cfg(feature = "std")]
+mod name;
+#[cfg(feature = "std")]
+mod nc_name;
+#[cfg(feature = "std")]
+mod nmtoken;
+#[cfg(feature = "std")]
+mod normalized_string;
+#[cfg(feature = "std")]
+#[allow(clippy::impl_hash_borrow_with_str_and_bytes)] // derived by `RegularGrammar`.
+mod time;
+#[cfg(feature = "std")]
+mod token;
+
+#[cfg(feature = "std")]
 pub use base64_binary::*;
+#[cfg(feature = "std")]
 pub use boolean::*;
+#[cfg(feature = "std")]
+pub use date::*;
+#[cfg(feature = "std")]
 pub use date_time::*;
 pub use decimal::*;
+#[cfg(feature = "std")]
 pub use double::{Double, DoubleBuf, InvalidDouble};
+#[cfg(feature = "std")]
+pub use duration::*;
+#[cfg(feature = "std")]
 pub use float::{Float, FloatBuf, InvalidFloat};
+#[cfg(feature = "std")]
 pub use hex_binary::*;
+#[cfg(feature = "std")]
+pub use language::*;
+#[cfg(feature = "std")]
+pub use name::*;
+#[cfg(feature = "std")]
+pub use nc_name::*;
+#[cfg(feature = "std")]
+pub use nmtoken::*;
+#[cfg(feature = "std")]
+pub use normalized_string::*;
+#[cfg(feature = "std")]
+pub use time::*;
+#[cfg(feature = "std")]
+pub use token::*;
 
 /// Lexical type.
 pub trait Lexical {
@@ -23,19 +76,171 @@ pub trait Lexical {
 }
 
 impl Lexical for str {
-	type Error = std::convert::Infallible;
+	type Error = core::convert::Infallible;
 
 	fn parse(value: &str) -> Result<&Self, Self::Error> {
 		Ok(value)
 	}
 }
 
+/// Parses a year following the XSD 1.1 lexical spaces (at least four
+/// digits, no leading zero beyond four digits, optional `-` sign), returning
+/// the year and the remaining input.
+///
+/// XSD 1.1 includes year `0000` (1 BCE).
+#[cfg(feature = "std")]
+pub(crate) fn parse_year(s: &str) -> Result<(i32, &str), crate::InvalidDate> {
+	let (negative, s) = match s.strip_prefix('-') {
+		Some(s) => (true, s),
+		None => (false, s),
+	};
+
+	let len = s.bytes().take_while(u8::is_ascii_digit).count();
+	let digits = &s[..len];
+	if len < 4 || (len > 4 && digits.starts_with('0')) {
+		return Err(crate::InvalidDate);
+	}
+
+	let year: i32 = digits.parse().map_err(|_| crate::InvalidDate)?;
+	Ok((if negative { -year } else { year }, &s[len..]))
+}
+
+/// Parses a two-digit number in the given range, returning the number and
+/// the remaining input.
+#[cfg(feature = "std")]
+pub(crate) fn parse_two_digits(
+	s: &str,
+	range: core::ops::RangeInclusive<u8>,
+) -> Result<(u8, &str), crate::InvalidDate> {
+	match s.as_bytes() {
+		[a @ b'0'..=b'9', b @ b'0'..=b'9', ..] => {
+			let n = (a - b'0') * 10 + (b - b'0');
+			if range.contains(&n) {
+				Ok((n, &s[2..]))
+			} else {
+				Err(crate::InvalidDate)
+			}
+		}
+		_ => Err(crate::InvalidDate),
+	}
+}
+
+/// Parses an optional time zone following the XSD lexical spaces (`Z`,
+/// `+hh:mm` or `-hh:mm`, between `-14:00` and `+14:00`). The whole input
+/// must be consumed.
+#[cfg(feature = "std")]
+pub(crate) fn parse_time_zone(s: &str) -> Result<Option<chrono::FixedOffset>, crate::InvalidDate> {
+	let (sign, s) = match s.as_bytes().first() {
+		None => return Ok(None),
+		Some(b'Z') if s.len() == 1 => return Ok(chrono::FixedOffset::east_opt(0)),
+		Some(b'+') => (1, &s[1..]),
+		Some(b'-') => (-1, &s[1..]),
+		_ => return Err(crate::InvalidDate),
+	};
+
+	let (hours, s) = parse_two_digits(s, 0..=14)?;
+	let s = s.strip_prefix(':').ok_or(crate::InvalidDate)?;
+	let (minutes, s) = parse_two_digits(s, 0..=59)?;
+	if !s.is_empty() || (hours == 14 && minutes != 0) {
+		return Err(crate::InvalidDate);
+	}
+
+	let seconds = (hours as i32 * 60 + minutes as i32) * 60;
+	Ok(chrono::FixedOffset::east_opt(sign * seconds))
+}
+
+/// Returns the time zone of a valid date or time lexical form, defaulting to
+/// UTC if it is empty.
+#[cfg(feature = "std")]
+fn time_zone_or_utc(s: &str) -> chrono::FixedOffset {
+	parse_time_zone(s)
+		.expect("time zone is valid")
+		.unwrap_or_else(|| chrono::FixedOffset::east_opt(0).unwrap())
+}
+
 pub trait LexicalFormOf<V>: Lexical {
 	type ValueError;
 
 	fn try_as_value(&self) -> Result<V, Self::ValueError>;
 }
 
+/// Result of a lexical form validation function, either a simple `bool` or a
+/// detailed `Result`.
+pub(crate) trait CheckResult<E> {
+	fn into_result(self) -> Result<(), E>;
+}
+
+impl<E: Default> CheckResult<E> for bool {
+	#[inline(always)]
+	fn into_result(self) -> Result<(), E> {
+		if self {
+			Ok(())
+		} else {
+			Err(E::default())
+		}
+	}
+}
+
+impl<E> CheckResult<E> for Result<(), E> {
+	#[inline(always)]
+	fn into_result(self) -> Result<(), E> {
+		self
+	}
+}
+
+/// Defines the error type of a lexical form, unless it is defined
+/// externally.
+macro_rules! lexical_error {
+	($error_ty:ident) => {
+		#[derive(Debug, Default)]
+		pub struct $error_ty;
+	};
+	($error_ty:ident external) => {};
+}
+
+pub(crate) use lexical_error;
+
+/// Completes the equality implementations of a lexical form derived with
+/// `RegularGrammar` to match the ones of [`lexical_form!`].
+///
+/// `RegularGrammar` only compares the owned buffer with the lexical form, so
+/// this adds the reverse comparison and the comparisons with strings.
+#[cfg(feature = "std")]
+macro_rules! grammar_eq {
+	($ty:ident, $buffer_ty:ident) => {
+		impl PartialEq<$buffer_ty> for $ty {
+			#[inline(always)]
+			fn eq(&self, other: &$buffer_ty) -> bool {
+				self.as_str() == other.as_str()
+			}
+		}
+
+		impl PartialEq<str> for $ty {
+			#[inline(always)]
+			fn eq(&self, other: &str) -> bool {
+				self.as_str() == other
+			}
+		}
+
+		impl PartialEq<str> for $buffer_ty {
+			#[inline(always)]
+			fn eq(&self, other: &str) -> bool {
+				self.as_str() == other
+			}
+		}
+
+		impl PartialEq<String> for $buffer_ty {
+			#[inline(always)]
+			fn eq(&self, other: &String) -> bool {
+				self.as_str() == other.as_str()
+			}
+		}
+	};
+}
+
+#[cfg(feature = "std")]
+pub(crate) use grammar_eq;
+
 macro_rules! lexical_form {
 	{
 		$(#[$ty_meta:meta])*
@@ -51,41 +256,39 @@ macro_rules! lexical_form {
 		new_unchecked,
 
 		value: $value_ty:ty,
-		error: $error_ty:ident,
+		error: $error_ty:ident $($external:ident)?,
 		as_ref: $as_ref:ident,
 		parent_forms: { $( $as_parent_form:ident: $parent_form:ty, $parent_buf_form:ty ),* }
 	} => {
-		#[derive(Debug)]
-		pub struct $error_ty;
+		$crate::lexical::lexical_error!($error_ty $($external)?);
 
 		$(#[$ty_meta])*
 		pub struct $ty([u8]);
 
 		$(#[$buffer_ty_meta])*
 		#[derive(Clone)]
-		pub struct $buffer_ty(Vec<u8>);
+		pub struct $buffer_ty(alloc::vec::Vec<u8>);
 
 		impl $ty {
 			$(#[$new_meta])*
 			#[inline(always)]
 			pub fn new<S: ?Sized + AsRef<[u8]>>(s: &S) -> Result<&Self, $error_ty> {
-				if check_bytes(s.as_ref()) {
-					Ok(unsafe { Self::new_unchecked(s) })
-				} else {
-					Err($error_ty)
+				match $crate::lexical::CheckResult::<$error_ty>::into_result(check_bytes(s.as_ref())) {
+					Ok(()) => Ok(unsafe { Self::new_unchecked(s) }),
+					Err(e) => Err(e),
 				}
 			}
 
 			$(#[$new_unchecked_meta])*
 			#[inline(always)]
 			pub unsafe fn new_unchecked<S: ?Sized + AsRef<[u8]>>(s: &S) -> &Self {
-				std::mem::transmute(s.as_ref())
+				core::mem::transmute(s.as_ref())
 			}
 
 			$(#[$new_unchecked_meta])*
 			#[inline(always)]
 			pub const unsafe fn new_unchecked_from_slice(s: &[u8]) -> &Self {
-				std::mem::transmute(s)
+				core::mem::transmute(s)
 			}
 
 			#[inline(always)]
@@ -99,9 +302,9 @@ macro_rules! lexical_form {
 			}
 		}
 
-		impl std::fmt::Debug for $ty {
+		impl core::fmt::Debug for $ty {
 			#[inline(always)]
-			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+			fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
 				self.as_str().fmt(f)
 			}
 		}
@@ -115,7 +318,7 @@ macro_rules! lexical_form {
 			}
 		}
 
-		impl std::ops::Deref for $ty {
+		impl core::ops::Deref for $ty {
 			type Target = str;
 
 			#[inline(always)]
@@ -136,7 +339,7 @@ macro_rules! lexical_form {
 			}
 		}
 
-		impl std::borrow::ToOwned for $ty {
+		impl alloc::borrow::ToOwned for $ty {
 			type Owned = $buffer_ty;
 
 			#[inline(always)]
@@ -145,9 +348,9 @@ macro_rules! lexical_form {
 			}
 		}
 
-		impl std::fmt::Display for $ty {
+		impl core::fmt::Display for $ty {
 			#[inline(always)]
-			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+			fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
 				self.as_str().fmt(f)
 			}
 		}
@@ -162,20 +365,19 @@ macro_rules! lexical_form {
 		impl $buffer_ty {
 			$(#[$new_meta])*
 			#[inline(always)]
-			pub fn new<S: AsRef<[u8]> + Into<Vec<u8>>>(
+			pub fn new<S: AsRef<[u8]> + Into<alloc::vec::Vec<u8>>>(
 				s: S,
 			) -> Result<Self, ($error_ty, S)> {
-				if check_bytes(s.as_ref()) {
-					Ok(unsafe { Self::new_unchecked(s) })
-				} else {
-					Err(($error_ty, s))
+				match $crate::lexical::CheckResult::<$error_ty>::into_result(check_bytes(s.as_ref())) {
+					Ok(()) => Ok(unsafe { Self::new_unchecked(s) }),
+					Err(e) => Err((e, s)),
 				}
 			}
 
 			$(#[$new_unchecked_meta])*
 			#[inline(always)]
-			pub unsafe fn new_unchecked(s: impl Into<Vec<u8>>) -> Self {
-				std::mem::transmute(s.into())
+			pub unsafe fn new_unchecked(s: impl Into<alloc::vec::Vec<u8>>) -> Self {
+				core::mem::transmute(s.into())
 			}
 
 			#[inline(always)]
@@ -183,23 +385,48 @@ macro_rules! lexical_form {
 				unsafe { <$ty>::new_unchecked(&self.0) }
 			}
 
+			/// Returns a mutable reference to the lexical representation.
+			///
+			/// The buffer does not implement `DerefMut` since arbitrary
+			/// mutations could break the lexical form.
+			///
+			/// # Safety
+			///
+			/// The string must still be a valid lexical form when the
+			/// returned reference is dropped.
+			#[inline(always)]
+			pub unsafe fn as_mut_str(&mut self) -> &mut str {
+				core::str::from_utf8_unchecked_mut(&mut self.0)
+			}
+
 			#[inline(always)]
-			pub fn into_bytes(self) -> Vec<u8> {
+			pub fn into_bytes(self) -> alloc::vec::Vec<u8> {
 				self.0
 			}
 
 			#[inline(always)]
-			pub fn into_string(mut self) -> String {
+			pub fn into_string(mut self) -> alloc::string::String {
 				let buf = self.0.as_mut_ptr();
 				let len = self.0.len();
 				let capacity = self.0.capacity();
 				core::mem::forget(self);
-				unsafe { String::from_raw_parts(buf, len, capacity) }
+				unsafe { alloc::string::String::from_raw_parts(buf, len, capacity) }
 			}
 
+			/// Consumes the lexical form and returns its value, moving the
+			/// buffer into the value when possible instead of cloning it.
 			#[inline(always)]
 			pub fn into_value(self) -> $value_ty {
-				self.value()
+				self.into()
+			}
+
+			/// Consumes the lexical form and returns its value.
+			///
+			/// Owned counterpart of [`crate::lexical::LexicalFormOf::try_as_value`]. Never
+			/// fails, see [`Self::into_value`].
+			#[inline(always)]
+			pub fn try_into_value(self) -> Result<$value_ty, core::convert::Infallible> {
+				Ok(self.into())
 			}
 		}
 
@@ -211,7 +438,7 @@ macro_rules! lexical_form {
 
 		impl Eq for $buffer_ty {}
 
-		impl std::str::FromStr for $buffer_ty {
+		impl core::str::FromStr for $buffer_ty {
 			type Err = $error_ty;
 
 			fn from_str(s: &str) -> Result<Self, $error_ty> {
@@ -233,7 +460,7 @@ macro_rules! lexical_form {
 			}
 		}
 
-		impl std::ops::Deref for $buffer_ty {
+		impl core::ops::Deref for $buffer_ty {
 			type Target = $ty;
 
 			#[inline(always)]
@@ -258,14 +485,14 @@ macro_rules! lexical_form {
 
 		impl fmt::Display for $buffer_ty {
 			#[inline(always)]
-			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+			fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
 				self.as_str().fmt(f)
 			}
 		}
 
-		impl std::fmt::Debug for $buffer_ty {
+		impl core::fmt::Debug for $buffer_ty {
 			#[inline(always)]
-			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+			fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
 				self.0.fmt(f)
 			}
 		}
@@ -273,7 +500,7 @@ macro_rules! lexical_form {
 		impl PartialEq<$ty> for $buffer_ty {
 			#[inline(always)]
 			fn eq(&self, other: &$ty) -> bool {
-				self == other
+				self.$as_ref() == other
 			}
 		}
 
@@ -287,7 +514,34 @@ macro_rules! lexical_form {
 		impl PartialEq<$buffer_ty> for $ty {
 			#[inline(always)]
 			fn eq(&self, other: &$buffer_ty) -> bool {
-				self == other
+				self == other.$as_ref()
+			}
+		}
+
+		/// Compares the lexical representation with a string, without any
+		/// normalization.
+		impl PartialEq<str> for $ty {
+			#[inline(always)]
+			fn eq(&self, other: &str) -> bool {
+				self.as_str() == other
+			}
+		}
+
+		/// Compares the lexical representation with a string, without any
+		/// normalization.
+		impl PartialEq<str> for $buffer_ty {
+			#[inline(always)]
+			fn eq(&self, other: &str) -> bool {
+				self.as_str() == other
+			}
+		}
+
+		/// Compares the lexical representation with a string, without any
+		/// normalization.
+		impl PartialEq<alloc::string::String> for $buffer_ty {
+			#[inline(always)]
+			fn eq(&self, other: &alloc::string::String) -> bool {
+				self.as_str() == other.as_str()
 			}
 		}
 
@@ -315,6 +569,15 @@ macro_rules! lexical_form {
 				}
 			}
 
+			/// Widening conversion, keeping the lexical representation
+			/// unchanged.
+			impl From<$buffer_ty> for $parent_buf_form {
+				#[inline(always)]
+				fn from(value: $buffer_ty) -> Self {
+					unsafe { <$parent_buf_form>::new_unchecked(value.0) }
+				}
+			}
+
 			impl TryFrom<$parent_buf_form> for $buffer_ty {
 				type Error = ($error_ty, $parent_buf_form);
 
@@ -352,3 +615,214 @@ macro_rules! lexical_form {
 }
 
 pub(crate) use lexical_form;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::borrow::ToOwned;
+	use core::ops::Deref;
+
+	fn assert_str<T: ?Sized + AsRef<str> + Deref<Target = str>>() {}
+
+	fn assert_buf_str<T: AsRef<str>>() {}
+
+	#[test]
+	fn as_str_01() {
+		assert_str::<Decimal>();
+		assert_str::<Integer>();
+		assert_str::<NonPositiveInteger>();
+		assert_str::<NegativeInteger>();
+		assert_str::<NonNegativeInteger>();
+		assert_str::<PositiveInteger>();
+		assert_buf_str::<DecimalBuf>();
+		assert_buf_str::<IntegerBuf>();
+		assert_buf_str::<NonPositiveIntegerBuf>();
+		assert_buf_str::<NegativeIntegerBuf>();
+		assert_buf_str::<NonNegativeIntegerBuf>();
+		assert_buf_str::<PositiveIntegerBuf>();
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn as_str_02() {
+		assert_str::<Float>();
+		assert_str::<Double>();
+		assert_str::<Boolean>();
+		assert_str::<HexBinary>();
+		assert_str::<Base64Binary>();
+		assert_str::<DateTime>();
+		assert_str::<Date>();
+		assert_str::<Time>();
+		assert_str::<NormalizedString>();
+		assert_str::<Token>();
+		assert_str::<Language>();
+		assert_str::<Name>();
+		assert_str::<NCName>();
+		assert_str::<NMToken>();
+		assert_buf_str::<FloatBuf>();
+		assert_buf_str::<DoubleBuf>();
+		assert_buf_str::<BooleanBuf>();
+		assert_buf_str::<HexBinaryBuf>();
+		assert_buf_str::<Base64BinaryBuf>();
+		assert_buf_str::<DateTimeBuf>();
+		assert_buf_str::<DateBuf>();
+		assert_buf_str::<TimeBuf>();
+		assert_buf_str::<NormalizedStringBuf>();
+		assert_buf_str::<TokenBuf>();
+		assert_buf_str::<LanguageBuf>();
+		assert_buf_str::<NameBuf>();
+		assert_buf_str::<NCNameBuf>();
+		assert_buf_str::<NMTokenBuf>();
+	}
+
+	fn assert_clone<T: ?Sized + ToOwned + PartialEq<T::Owned> + core::fmt::Debug>(value: &T)
+	where
+		T::Owned: Clone + PartialEq + core::fmt::Debug,
+	{
+		let owned = value.to_owned();
+		assert_eq!(owned.clone(), owned);
+		assert_eq!(*value, owned)
+	}
+
+	#[test]
+	fn to_owned_01() {
+		use alloc::borrow::Cow;
+
+		assert_clone(Decimal::new("-1.50").unwrap());
+		assert_clone(Integer::new("+42").unwrap());
+		assert_clone(NonPositiveInteger::new("-0").unwrap());
+		assert_clone(NegativeInteger::new("-7").unwrap());
+		assert_clone(NonNegativeInteger::new("007").unwrap());
+		assert_clone(PositiveInteger::new("12").unwrap());
+
+		let i = Integer::new("12").unwrap();
+		let owned: IntegerBuf = i.to_owned();
+		let cow: Cow<Integer> = Cow::Borrowed(i);
+		assert_eq!(cow.into_owned(), owned)
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn to_owned_02() {
+		assert_clone(Float::new("1.5E3").unwrap());
+		assert_clone(Double::new("INF").unwrap());
+		assert_clone(Boolean::new("true").unwrap());
+		assert_clone(HexBinary::new("0FB7").unwrap());
+		assert_clone(Base64Binary::new("AAEC").unwrap());
+		assert_clone(NCName::new("foo").unwrap());
+		assert_clone(Token::new("a b").unwrap());
+
+		let d = DateTime::new("2002-05-30T09:00:00Z").unwrap();
+		let owned: DateTimeBuf = d.to_owned();
+		assert!(owned.clone() == owned);
+		assert_eq!(owned.as_str(), d.as_str())
+	}
+
+	#[test]
+	fn eq_01() {
+		let buf: IntegerBuf = "42".parse().unwrap();
+		let i = Integer::new("42").unwrap();
+		assert_eq!(buf, *i);
+		assert_eq!(*i, buf);
+		assert_eq!(buf, i);
+		assert!(*i == *"42");
+		assert!(buf == *"42");
+		let s = alloc::string::String::from("42");
+		assert!(buf == s);
+
+		// Lexical forms are compared by value, strings are not.
+		let j = Integer::new("+042").unwrap();
+		assert_eq!(buf, *j);
+		assert!(*j != *"42")
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn eq_02() {
+		let d = Date::new("2002-10-10Z").unwrap();
+		let buf: DateBuf = d.to_owned();
+		assert!(buf == *d);
+		assert!(*d == buf);
+		assert!(buf == d);
+		assert!(*d == *"2002-10-10Z");
+		assert!(buf == *"2002-10-10Z");
+		let s = String::from("2002-10-10Z");
+		assert!(buf == s);
+		assert!(*d != *"2002-10-10+00:00");
+
+		let t = Time::new("12:00:00").unwrap();
+		let buf: TimeBuf = t.to_owned();
+		assert!(buf == *t);
+		assert!(*t == buf);
+		assert!(*t == *"12:00:00");
+		let s = String::from("12:00:00");
+		assert!(buf == s);
+
+		let dt = DateTime::new("2002-10-10T12:00:00Z").unwrap();
+		let buf: DateTimeBuf = dt.to_owned();
+		assert!(buf == *dt);
+		assert!(*dt == buf);
+		assert!(*dt == *"2002-10-10T12:00:00Z");
+		let s = String::from("2002-10-10T12:00:00Z");
+		assert!(buf == s)
+	}
+
+	#[test]
+	fn into_value_01() {
+		let buf: IntegerBuf = "-0042".parse().unwrap();
+		assert_eq!(buf.try_into_value().unwrap(), crate::Integer::from(-42i64));
+
+		let buf: DecimalBuf = "1.50".parse().unwrap();
+		assert_eq!(buf.into_value().lexical_representation().as_str(), "1.50")
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn into_value_02() {
+		let buf: TokenBuf = "foo bar".parse().unwrap();
+		let ptr = buf.as_str().as_ptr();
+		let value = buf.into_value();
+		assert_eq!(value.as_str().as_ptr(), ptr);
+
+		let buf: HexBinaryBuf = "0fb7".parse().unwrap();
+		assert_eq!(buf.try_into_value().unwrap().as_bytes(), [0x0f, 0xb7])
+	}
+
+	#[test]
+	fn widening_01() {
+		let i: IntegerBuf = "-042".parse().unwrap();
+		let d = DecimalBuf::from(i);
+		assert_eq!(d.as_str(), "-042");
+		assert_eq!(d.value(), "-42".parse::<crate::Decimal>().unwrap());
+
+		let n: NonNegativeIntegerBuf = "42".parse().unwrap();
+		assert_eq!(DecimalBuf::from(n.clone()).as_str(), "42");
+		assert_eq!(IntegerBuf::from(n).as_str(), "42");
+
+		let m: NegativeIntegerBuf = "-1".parse().unwrap();
+		assert_eq!(NonPositiveIntegerBuf::from(m.clone()).as_str(), "-1");
+		assert!(m.as_non_positive_integer().is_negative())
+	}
+
+	#[test]
+	fn str_methods_01() {
+		let i = Integer::new("123").unwrap();
+		assert_eq!(i.chars().collect::<alloc::string::String>(), "123");
+		assert_eq!(i.len(), 3);
+		assert_eq!(i.bytes().next(), Some(b'1'));
+
+		let buf: IntegerBuf = "-123".parse().unwrap();
+		assert!(buf.starts_with('-'))
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn as_mut_str_01() {
+		let mut buf: HexBinaryBuf = "0fb7".parse().unwrap();
+		unsafe {
+			// Upper case hexadecimal digits are still valid.
+			buf.as_mut_str().make_ascii_uppercase()
+		}
+		assert_eq!(buf.as_str(), "0FB7")
+	}
+}
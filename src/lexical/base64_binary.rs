@@ -18,7 +18,7 @@ lexical_form! {
 
 	/// Creates a new base 64 string from a string.
 	///
-	/// If the input string is ot a [valid XSD base 64 string](https://www.w3.org/TR/xmlschema-2/#base64Binary),
+	/// If the input string is not a [valid XSD base 64 string](https://www.w3.org/TR/xmlschema-2/#base64Binary),
 	/// an [`InvalidBase64Binary`] error is returned.
 	new,
 
@@ -36,9 +36,17 @@ lexical_form! {
 }
 
 impl Base64Binary {
+	/// Returns the characters of the canonical representation, without
+	/// whitespaces.
 	#[inline(always)]
-	fn as_canonical_str(&self) -> &str {
-		self.as_str()
+	fn canonical_bytes(&self) -> impl '_ + Iterator<Item = u8> {
+		self.0.iter().copied().filter(|c| !is_whitespace(*c))
+	}
+
+	/// Returns the canonical representation of this base 64 string, without
+	/// whitespaces.
+	pub fn normalize(&self) -> String {
+		self.canonical_bytes().map(char::from).collect()
 	}
 
 	#[inline(always)]
@@ -49,7 +57,7 @@ impl Base64Binary {
 
 impl PartialEq for Base64Binary {
 	fn eq(&self, other: &Self) -> bool {
-		self.as_canonical_str() == other.as_canonical_str()
+		self.canonical_bytes().eq(other.canonical_bytes())
 	}
 }
 
@@ -57,13 +65,15 @@ impl Eq for Base64Binary {}
 
 impl Hash for Base64Binary {
 	fn hash<H: Hasher>(&self, h: &mut H) {
-		self.as_canonical_str().hash(h)
+		for b in self.canonical_bytes() {
+			b.hash(h)
+		}
 	}
 }
 
 impl Ord for Base64Binary {
 	fn cmp(&self, other: &Self) -> Ordering {
-		self.as_canonical_str().cmp(other.as_canonical_str())
+		self.canonical_bytes().cmp(other.canonical_bytes())
 	}
 }
 
@@ -91,31 +101,58 @@ impl Ord for Base64BinaryBuf {
 	}
 }
 
+/// Checks if the given byte is a whitespace allowed inside a base 64 string
+/// (before whitespace collapsing).
+pub(crate) fn is_whitespace(c: u8) -> bool {
+	matches!(c, b' ' | b'\t' | b'\n' | b'\r')
+}
+
 fn check_bytes(s: &[u8]) -> bool {
-	check(s.iter().copied())
+	check(s.iter().copied().filter(|c| !is_whitespace(*c)))
 }
 
-fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
-	enum State {
-		Data,
-		Padding,
+/// Checks a whitespace-free base 64 string: its length must be a multiple of
+/// 4, with at most 2 padding characters at the end.
+fn check<C: Iterator<Item = u8>>(chars: C) -> bool {
+	let mut len = 0usize;
+	let mut padding = 0usize;
+
+	for c in chars {
+		match c {
+			b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'/' if padding == 0 => (),
+			b'=' if padding < 2 => padding += 1,
+			_ => return false,
+		}
+
+		len += 1
 	}
 
-	let mut state = State::Data;
-
-	loop {
-		state = match state {
-			State::Data => match chars.next() {
-				Some(b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'/') => State::Data,
-				Some(b'=') => State::Padding,
-				None => break true,
-				_ => break false,
-			},
-			State::Padding => match chars.next() {
-				Some(b'=') => State::Padding,
-				None => break true,
-				_ => break false,
-			},
+	len % 4 == 0
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in ["", "SGVsbG8=", "SGVs bG8=", "SGVs\nbG8h", "YQ==", " YWJj "] {
+			assert!(Base64Binary::new(input).is_ok(), "{input}")
 		}
 	}
+
+	#[test]
+	fn parse_02() {
+		for input in ["SGVsbG8", "YQ===", "Y=Q=", "SGV-bG8=", "Y\u{0}Q=="] {
+			assert!(Base64Binary::new(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn normalize_01() {
+		let a = Base64Binary::new("SGVs\n bG8=").unwrap();
+		assert_eq!(a.normalize(), "SGVsbG8=");
+		assert_eq!(a, Base64Binary::new("SGVsbG8=").unwrap());
+		assert_eq!(a.value().as_bytes(), b"Hello")
+	}
 }
@@ -17,7 +17,7 @@ lexical_form! {
 
 	/// Creates a new boolean from a string.
 	///
-	/// If the input string is ot a [valid XSD boolean](https://www.w3.org/TR/xmlschema-2/#boolean),
+	/// If the input string is not a [valid XSD boolean](https://www.w3.org/TR/xmlschema-2/#boolean),
 	/// an [`InvalidBoolean`] error is returned.
 	new,
 
@@ -0,0 +1,103 @@
+use static_regular_grammar::RegularGrammar;
+
+use super::{grammar_eq, Lexical, LexicalFormOf};
+
+/// Date.
+///
+/// ```abnf
+/// date = year "-" month "-" day [timezone]
+///
+/// year = [ "-" ] year-number
+///
+/// year-number = 3DIGIT NZDIGIT
+///             / 2DIGIT NZDIGIT DIGIT
+///             / 1DIGIT NZDIGIT 2DIGIT
+///             / NZDIGIT 3*DIGIT
+///
+/// month = "0" NZDIGIT
+///       / "1" ( "0" / "1" / "2" )
+///
+/// day = "0" NZDIGIT
+///     / ("1" / "2") DIGIT
+///     / "3" ("0" / "1")
+///
+/// minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+///
+/// timezone = ("+" / "-") timezone-offset
+///          / %s"Z"
+///
+/// timezone-offset = ("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute
+///                 / "14:00"
+///
+/// NZDIGIT = "1" / "2" / "3" / "4" / "5" / "6" / "7" / "8" / "9"
+/// ```
+///
+#[derive(RegularGrammar, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[grammar(sized(DateBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
+pub struct Date(str);
+
+grammar_eq!(Date, DateBuf);
+
+impl Lexical for Date {
+	type Error = InvalidDate<String>;
+
+	fn parse(value: &str) -> Result<&Self, Self::Error> {
+		Self::new(value).map_err(|_| InvalidDate(value.to_owned()))
+	}
+}
+
+impl LexicalFormOf<crate::Date> for Date {
+	type ValueError = crate::InvalidDate;
+
+	fn try_as_value(&self) -> Result<crate::Date, Self::ValueError> {
+		self.value()
+	}
+}
+
+impl Date {
+	/// Returns the date value.
+	///
+	/// A date without time zone is assumed to be in UTC. Fails if the date
+	/// does not exist (e.g. `2023-02-30`) or is out of the supported range.
+	pub fn value(&self) -> Result<crate::Date, crate::InvalidDate> {
+		let (year, s) = super::parse_year(self.as_str())?;
+		let month = s[1..3].parse().unwrap();
+		let day = s[4..6].parse().unwrap();
+		let offset = super::time_zone_or_utc(&s[6..]);
+
+		crate::Date::from_ymd(year, month, day).map(|d| crate::Date::new(d.date, offset))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in [
+			"2002-10-10",
+			"2002-10-10Z",
+			"2002-10-10-05:00",
+			"-0045-01-01",
+			"12345-12-31+14:00",
+		] {
+			assert!(Date::new(input).is_ok(), "{input}")
+		}
+	}
+
+	#[test]
+	fn parse_02() {
+		for input in [
+			"2002-10-10T00:00:00",
+			"02-10-10",
+			"2002-13-10",
+			"2002-10-32",
+			"0000-01-01",
+			"2002-10-10+15:00",
+			"2002-10-10-14:01",
+		] {
+			assert!(Date::new(input).is_err(), "{input}")
+		}
+	}
+}
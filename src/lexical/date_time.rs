@@ -1,6 +1,6 @@
 use static_regular_grammar::RegularGrammar;
 
-use super::{Lexical, LexicalFormOf};
+use super::{grammar_eq, Lexical, LexicalFormOf};
 
 /// Date and time.
 ///
@@ -12,9 +12,9 @@ use super::{Lexical, LexicalFormOf};
 ///
 /// year = [ "-" ] year-number
 ///
-/// year-number = *3DIGIT NZDIGIT
-///             / *2DIGIT NZDIGIT DIGIT
-///             / *1DIGIT NZDIGIT 2DIGIT
+/// year-number = 3DIGIT NZDIGIT
+///             / 2DIGIT NZDIGIT DIGIT
+///             / 1DIGIT NZDIGIT 2DIGIT
 ///             / NZDIGIT 3*DIGIT
 ///
 /// month = "0" NZDIGIT
@@ -24,21 +24,21 @@ use super::{Lexical, LexicalFormOf};
 ///     / ("1" / "2") DIGIT
 ///     / "3" ("0" / "1")
 ///
-/// hour = "0" NZDIGIT
-///      / "1" DIGIT
+/// hour = ("0" / "1") DIGIT
 ///      / "2" ("0" / "1" / "2" / "3")
 ///
-/// minute = "0" NZDIGIT
-///        / ("1" / "2" / "3" / "4" / "5") DIGIT
+/// minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
 ///
-/// second = "0" NZDIGIT
-///        / ("1" / "2" / "3" / "4" / "5") DIGIT
+/// second = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
 ///
 /// fraction = 1*DIGIT
 ///
-/// timezone = ("+" / "-") hour ":" minute
+/// timezone = ("+" / "-") timezone-offset
 ///          / %s"Z"
 ///
+/// timezone-offset = ("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute
+///                 / "14:00"
+///
 /// NZDIGIT = "1" / "2" / "3" / "4" / "5" / "6" / "7" / "8" / "9"
 /// ```
 ///
@@ -46,6 +46,8 @@ use super::{Lexical, LexicalFormOf};
 #[grammar(sized(DateTimeBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct DateTime(str);
 
+grammar_eq!(DateTime, DateTimeBuf);
+
 impl Lexical for DateTime {
 	type Error = InvalidDateTime<String>;
 
@@ -57,7 +59,129 @@ impl Lexical for DateTime {
 impl LexicalFormOf<crate::DateTime> for DateTime {
 	type ValueError = chrono::ParseError;
 
+	/// A date and time without time zone is assumed to be in UTC.
 	fn try_as_value(&self) -> Result<crate::DateTime, Self::ValueError> {
-		self.as_str().parse()
+		let s = self.as_str();
+		let time = &s[s.find('T').unwrap()..];
+		if time.contains(['Z', '+', '-']) {
+			s.parse()
+		} else {
+			format!("{s}Z").parse()
+		}
+	}
+}
+
+impl DateTime {
+	/// Splits the lexical form into its date, time and time zone parts.
+	fn parts(&self) -> (&str, &str, &str) {
+		let s = self.as_str();
+		let (date, time) = s.split_once('T').unwrap();
+		let (time, time_zone) = time.split_at(time.find(['Z', '+', '-']).unwrap_or(time.len()));
+		(date, time, time_zone)
+	}
+
+	/// Returns the date part of this lexical form (before the `T`).
+	pub fn date_str(&self) -> &str {
+		self.parts().0
+	}
+
+	/// Returns the time part of this lexical form (after the `T`), without
+	/// the time zone.
+	pub fn time_str(&self) -> &str {
+		self.parts().1
+	}
+
+	/// Checks if this date and time has an explicit time zone.
+	pub fn has_timezone(&self) -> bool {
+		!self.parts().2.is_empty()
+	}
+
+	/// Returns the time zone offset from UTC in minutes, if any.
+	///
+	/// For instance `+05:30` gives `330`, `-08:00` gives `-480` and `Z`
+	/// gives `0`.
+	pub fn timezone_offset(&self) -> Option<i16> {
+		let time_zone = self.parts().2;
+		if time_zone.is_empty() {
+			None
+		} else {
+			Some((super::time_zone_or_utc(time_zone).local_minus_utc() / 60) as i16)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in [
+			"2002-10-10T12:00:00-05:00",
+			"2002-10-10T00:00:00Z",
+			"2002-10-10T23:59:59.999",
+			"-0045-01-01T09:05:00+00:00",
+		] {
+			assert!(DateTime::new(input).is_ok(), "{input}")
+		}
+	}
+
+	#[test]
+	fn parse_02() {
+		for input in [
+			"2002-10-10T24:01:00",
+			"2002-10-10T12:60:00",
+			"2002-13-10T12:00:00",
+			"2002-10-10 12:00:00",
+			"02-10-10T12:00:00",
+			"2002-10-10T12:00:00+14:30",
+			"2002-10-10T12:00:00+23:59",
+		] {
+			assert!(DateTime::new(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn value_01() {
+		for (input, expected) in [
+			("2002-10-10T12:00:00-05:00", "2002-10-10T12:00:00-05:00"),
+			("2002-10-10T12:00:00+01:30", "2002-10-10T12:00:00+01:30"),
+			("2002-10-10T00:00:00Z", "2002-10-10T00:00:00Z"),
+			("2002-10-10T23:59:59.5", "2002-10-10T23:59:59.500Z"),
+		] {
+			let value = DateTime::new(input).unwrap().try_as_value().unwrap();
+			assert_eq!(value.to_string(), expected)
+		}
+	}
+
+	#[test]
+	fn parts_01() {
+		for (input, date, time, offset) in [
+			(
+				"2002-10-10T12:00:00-08:00",
+				"2002-10-10",
+				"12:00:00",
+				Some(-480),
+			),
+			(
+				"2002-10-10T12:00:00+05:30",
+				"2002-10-10",
+				"12:00:00",
+				Some(330),
+			),
+			(
+				"-0045-01-01T09:05:00.5Z",
+				"-0045-01-01",
+				"09:05:00.5",
+				Some(0),
+			),
+			("2002-10-10T23:59:59", "2002-10-10", "23:59:59", None),
+		] {
+			let dt = DateTime::new(input).unwrap();
+			assert_eq!(dt.date_str(), date);
+			assert_eq!(dt.time_str(), time);
+			assert_eq!(dt.has_timezone(), offset.is_some());
+			assert_eq!(dt.timezone_offset(), offset)
+		}
 	}
 }
@@ -1,8 +1,12 @@
 use super::lexical_form;
-use std::borrow::{Borrow, ToOwned};
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
+use alloc::{
+	borrow::ToOwned,
+	string::{String, ToString},
+};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
 
 /// Numeric sign.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
@@ -46,7 +50,7 @@ lexical_form! {
 
 	/// Creates a new decimal from a string.
 	///
-	/// If the input string is ot a [valid XSD decimal](https://www.w3.org/TR/xmlschema-2/#decimal),
+	/// If the input string is not a [valid XSD decimal](https://www.w3.org/TR/xmlschema-2/#decimal),
 	/// an [`InvalidDecimal`] error is returned.
 	new,
 
@@ -82,11 +86,11 @@ impl Decimal {
 	/// Returns `true` if `self` is negative
 	/// and `false` is the number is zero or positive.
 	pub fn is_negative(&self) -> bool {
-		let mut sign_negative = true;
+		let mut sign_negative = false;
 		for c in &self.0 {
 			match c {
-				b'-' | b'0' | b'.' => (),
-				b'+' => sign_negative = false,
+				b'+' | b'0' | b'.' => (),
+				b'-' => sign_negative = true,
 				_ => return sign_negative,
 			}
 		}
@@ -164,6 +168,39 @@ impl Decimal {
 		}
 	}
 
+	/// Returns the canonical representation of this decimal number.
+	///
+	/// The `+` sign, leading integer zeros and trailing fractional zeros are
+	/// removed. The decimal point is removed if the fractional part is zero,
+	/// and zero is always represented as `0`.
+	pub fn normalize(&self) -> DecimalBuf {
+		let mut result = String::new();
+
+		if !self.is_zero() {
+			if self.is_negative() {
+				result.push('-')
+			}
+
+			let (integer_part, fractional_part) = self.parts();
+			let integer_part = integer_part.as_str().trim_start_matches(['+', '-', '0']);
+			if integer_part.is_empty() {
+				result.push('0')
+			} else {
+				result.push_str(integer_part)
+			}
+
+			let fractional_part = fractional_part.map(FractionalPart::trimmed);
+			if let Some(f) = fractional_part.filter(|f| !f.is_empty()) {
+				result.push('.');
+				result.push_str(f.as_str())
+			}
+		} else {
+			result.push('0')
+		}
+
+		unsafe { DecimalBuf::new_unchecked(result) }
+	}
+
 	pub fn value(&self) -> crate::Decimal {
 		self.to_owned().into()
 	}
@@ -267,9 +304,11 @@ integer_conversion! {
 	isize
 }
 
+#[cfg(feature = "std")]
 const DTOA_CONFIG: pretty_dtoa::FmtFloatConfig =
 	pretty_dtoa::FmtFloatConfig::default().force_no_e_notation();
 
+#[cfg(feature = "std")]
 impl From<f32> for DecimalBuf {
 	fn from(i: f32) -> Self {
 		unsafe { DecimalBuf::new_unchecked(pretty_dtoa::ftoa(i, DTOA_CONFIG)) }
@@ -277,7 +316,7 @@ impl From<f32> for DecimalBuf {
 }
 
 impl<'a> TryFrom<&'a Decimal> for f32 {
-	type Error = <f32 as std::str::FromStr>::Err;
+	type Error = <f32 as core::str::FromStr>::Err;
 
 	fn try_from(i: &'a Decimal) -> Result<Self, Self::Error> {
 		i.as_str().parse()
@@ -285,13 +324,14 @@ impl<'a> TryFrom<&'a Decimal> for f32 {
 }
 
 impl TryFrom<DecimalBuf> for f32 {
-	type Error = <f32 as std::str::FromStr>::Err;
+	type Error = <f32 as core::str::FromStr>::Err;
 
 	fn try_from(i: DecimalBuf) -> Result<Self, Self::Error> {
 		i.as_str().parse()
 	}
 }
 
+#[cfg(feature = "std")]
 impl From<f64> for DecimalBuf {
 	fn from(i: f64) -> Self {
 		unsafe { DecimalBuf::new_unchecked(pretty_dtoa::dtoa(i, DTOA_CONFIG)) }
@@ -299,7 +339,7 @@ impl From<f64> for DecimalBuf {
 }
 
 impl<'a> TryFrom<&'a Decimal> for f64 {
-	type Error = <f64 as std::str::FromStr>::Err;
+	type Error = <f64 as core::str::FromStr>::Err;
 
 	fn try_from(i: &'a Decimal) -> Result<Self, Self::Error> {
 		i.as_str().parse()
@@ -307,7 +347,7 @@ impl<'a> TryFrom<&'a Decimal> for f64 {
 }
 
 impl TryFrom<DecimalBuf> for f64 {
-	type Error = <f64 as std::str::FromStr>::Err;
+	type Error = <f64 as core::str::FromStr>::Err;
 
 	fn try_from(i: DecimalBuf) -> Result<Self, Self::Error> {
 		i.as_str().parse()
@@ -325,7 +365,7 @@ impl FractionalPart {
 	/// representation.
 	#[inline(always)]
 	pub unsafe fn new_unchecked<S: ?Sized + AsRef<[u8]>>(s: &S) -> &Self {
-		std::mem::transmute(s.as_ref())
+		core::mem::transmute(s.as_ref())
 	}
 
 	#[inline(always)]
@@ -507,6 +547,7 @@ mod tests {
 	}
 
 	#[test]
+	#[cfg(feature = "std")]
 	fn format_01() {
 		assert_eq!(DecimalBuf::from(1.0e10f32).to_string(), "10000000000.0")
 	}
@@ -530,4 +571,36 @@ mod tests {
 	fn cmp_04() {
 		assert!(Decimal::new("-0.123").unwrap() > Decimal::new("-0.1234").unwrap())
 	}
+
+	#[test]
+	fn parse_11() {
+		for input in ["1E5", "1e5", "1.0E-2", "INF", "NaN", "1 ", ""] {
+			assert!(Decimal::new(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn normalize_01() {
+		for (input, expected) in [
+			("0", "0"),
+			("-0.000", "0"),
+			("+.0", "0"),
+			("0012.500", "12.5"),
+			("+42", "42"),
+			("-0042.", "-42"),
+			("-.50", "-0.5"),
+			("1.0", "1"),
+			("100", "100"),
+		] {
+			assert_eq!(Decimal::new(input).unwrap().normalize().as_str(), expected)
+		}
+	}
+
+	#[test]
+	fn normalize_02() {
+		for input in ["0012.500", "-.50", "+1.0", "-0.0"] {
+			let d = Decimal::new(input).unwrap();
+			assert_eq!(d.normalize().value(), d.value())
+		}
+	}
 }
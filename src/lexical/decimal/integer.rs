@@ -1,11 +1,12 @@
 use crate::lexical::lexical_form;
 
 use super::{Decimal, DecimalBuf, Overflow, Sign};
-use std::borrow::{Borrow, ToOwned};
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::str::FromStr;
+use alloc::{borrow::ToOwned, string::ToString};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
 mod non_negative_integer;
 mod non_positive_integer;
@@ -26,7 +27,7 @@ lexical_form! {
 
 	/// Creates a new integer from a string.
 	///
-	/// If the input string is ot a [valid XSD integer](https://www.w3.org/TR/xmlschema-2/#integer),
+	/// If the input string is not a [valid XSD integer](https://www.w3.org/TR/xmlschema-2/#integer),
 	/// an [`InvalidInteger`] error is returned.
 	new,
 
@@ -113,6 +114,25 @@ impl Integer {
 		Sign::Zero
 	}
 
+	/// Returns the number of leading zero digits in `self`, ignoring the sign.
+	///
+	/// A lone zero digit is not counted as leading, so this returns `0` for
+	/// both `7` and `0`, and `2` for `007` or `000`.
+	pub fn leading_zeros(&self) -> usize {
+		let digits = match self.0[0] {
+			b'+' | b'-' => &self.0[1..],
+			_ => &self.0,
+		};
+
+		let zeros = digits.iter().take_while(|c| **c == b'0').count();
+		zeros.min(digits.len() - 1)
+	}
+
+	/// Returns `true` if `self` starts with an explicit `+` or `-` sign.
+	pub fn has_explicit_sign(&self) -> bool {
+		matches!(self.0[0], b'+' | b'-')
+	}
+
 	/// Returns the absolute value of `self`.
 	///
 	/// The returned integer is in canonical form (without leading zeros).
@@ -425,4 +445,21 @@ mod tests {
 			Ordering::Equal
 		)
 	}
+
+	#[test]
+	fn leading_zeros_01() {
+		for (s, zeros, sign) in [
+			("7", 0, false),
+			("007", 2, false),
+			("+007", 2, true),
+			("-007", 2, true),
+			("0", 0, false),
+			("-0", 0, true),
+			("000", 2, false),
+		] {
+			let i = Integer::new(s).unwrap();
+			assert_eq!(i.leading_zeros(), zeros, "{s}");
+			assert_eq!(i.has_explicit_sign(), sign, "{s}")
+		}
+	}
 }
@@ -1,11 +1,12 @@
 use crate::lexical::lexical_form;
 
 use super::{Decimal, DecimalBuf, Integer, IntegerBuf, Overflow, Sign};
-use std::borrow::{Borrow, ToOwned};
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::str::FromStr;
+use alloc::{borrow::ToOwned, string::ToString};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
 mod positive_integer;
 
@@ -24,7 +25,7 @@ lexical_form! {
 
 	/// Creates a new non negative integer from a string.
 	///
-	/// If the input string is ot a [valid XSD non negative integer](https://www.w3.org/TR/xmlschema-2/#nonNegativeInteger),
+	/// If the input string is not a [valid XSD non negative integer](https://www.w3.org/TR/xmlschema-2/#nonNegativeInteger),
 	/// an [`InvalidNonNegativeInteger`] error is returned.
 	new,
 
@@ -1,11 +1,12 @@
 use crate::lexical::{lexical_form, Decimal, DecimalBuf, Integer, IntegerBuf, NonNegativeInteger};
 
 use super::{NonNegativeIntegerBuf, Overflow};
-use std::borrow::{Borrow, ToOwned};
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::str::FromStr;
+use alloc::{borrow::ToOwned, string::ToString};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
 lexical_form! {
 	/// Positive integer number.
@@ -20,8 +21,10 @@ lexical_form! {
 
 	/// Creates a new positive integer from a string.
 	///
-	/// If the input string is ot a [valid XSD positive integer](https://www.w3.org/TR/xmlschema-2/#positiveInteger),
-	/// an [`InvalidPositiveInteger`] error is returned.
+	/// If the input string is not a [valid XSD positive integer](https://www.w3.org/TR/xmlschema-2/#positiveInteger),
+	/// an [`InvalidPositiveInteger`] error is returned. As allowed by XSD,
+	/// the input may have a leading `+` sign and leading zeros; use
+	/// [`PositiveInteger::new_canonical`] to reject them.
 	new,
 
 	/// Creates a new positive integer from a string without checking it.
@@ -32,7 +35,7 @@ lexical_form! {
 	new_unchecked,
 
 	value: crate::PositiveInteger,
-	error: InvalidPositiveInteger,
+	error: InvalidPositiveInteger external,
 	as_ref: as_positive_integer,
 	parent_forms: {
 		as_non_negative_integer: NonNegativeInteger, NonNegativeIntegerBuf,
@@ -41,7 +44,56 @@ lexical_form! {
 	}
 }
 
+/// Invalid positive integer lexical representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum InvalidPositiveInteger {
+	/// The input has no digits.
+	#[cfg_attr(feature = "std", error("empty positive integer"))]
+	Empty,
+
+	/// The input has leading zeros or a `+` sign, which is not allowed in
+	/// the canonical form.
+	#[cfg_attr(feature = "std", error("non canonical positive integer"))]
+	LeadingZeros,
+
+	/// The input is a valid representation of zero.
+	#[cfg_attr(feature = "std", error("positive integer is zero"))]
+	IsZero,
+
+	/// The input is a valid representation of a negative integer.
+	#[cfg_attr(feature = "std", error("positive integer is negative"))]
+	IsNegative,
+
+	/// The input contains an unexpected character at the given byte offset.
+	#[cfg_attr(feature = "std", error("unexpected character `{0}` at offset {1}"))]
+	InvalidChar(char, usize),
+}
+
 impl PositiveInteger {
+	/// Creates a new positive integer from a string in canonical form,
+	/// matching `[1-9][0-9]*`.
+	pub fn new_canonical<S: ?Sized + AsRef<[u8]>>(s: &S) -> Result<&Self, InvalidPositiveInteger> {
+		let result = Self::new(s)?;
+		if result.0[0] == b'+' || result.0[0] == b'0' {
+			Err(InvalidPositiveInteger::LeadingZeros)
+		} else {
+			Ok(result)
+		}
+	}
+
+	/// Checks that `self` is in canonical form, without sign and leading
+	/// zeros.
+	pub fn is_canonical(&self) -> bool {
+		self.0[0] != b'+' && self.0[0] != b'0'
+	}
+
+	/// Returns the canonical representation of this positive integer,
+	/// without sign and leading zeros.
+	pub fn normalize(&self) -> PositiveIntegerBuf {
+		self.canonical().to_owned()
+	}
+
 	/// Returns the canonical form of `self` (without leading zeros).
 	pub fn canonical(&self) -> &Self {
 		let mut last_zero = 0;
@@ -154,36 +206,100 @@ number_conversion! {
 	isize
 }
 
-fn check_bytes(s: &[u8]) -> bool {
-	check(s.iter().copied())
+fn check_bytes(s: &[u8]) -> Result<(), InvalidPositiveInteger> {
+	let (is_negative, offset) = match s.first() {
+		Some(b'+') => (false, 1),
+		Some(b'-') => (true, 1),
+		_ => (false, 0),
+	};
+
+	let digits = &s[offset..];
+	if digits.is_empty() {
+		return Err(InvalidPositiveInteger::Empty);
+	}
+
+	if let Some(i) = digits.iter().position(|c| !c.is_ascii_digit()) {
+		let i = offset + i;
+		let c = (1..=4)
+			.filter_map(|len| s.get(i..i + len))
+			.find_map(|bytes| core::str::from_utf8(bytes).ok())
+			.and_then(|c| c.chars().next())
+			.unwrap_or(char::REPLACEMENT_CHARACTER);
+		return Err(InvalidPositiveInteger::InvalidChar(c, i));
+	}
+
+	if digits.iter().all(|c| *c == b'0') {
+		Err(InvalidPositiveInteger::IsZero)
+	} else if is_negative {
+		Err(InvalidPositiveInteger::IsNegative)
+	} else {
+		Ok(())
+	}
 }
 
-fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
-	enum State {
-		Initial,
-		NonEmptyInteger,
-		Integer,
-	}
-
-	let mut state = State::Initial;
-
-	loop {
-		state = match state {
-			State::Initial => match chars.next() {
-				Some(b'+' | b'0') => State::NonEmptyInteger,
-				Some(b'1'..=b'9') => State::Integer,
-				_ => break false,
-			},
-			State::NonEmptyInteger => match chars.next() {
-				Some(b'0') => State::NonEmptyInteger,
-				Some(b'1'..=b'9') => State::Integer,
-				_ => break false,
-			},
-			State::Integer => match chars.next() {
-				Some(b'0'..=b'9') => State::Integer,
-				Some(_) => break false,
-				None => break true,
-			},
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in ["1", "42", "+7", "007", "+0010"] {
+			assert!(PositiveInteger::new(input).is_ok(), "{input}")
 		}
 	}
+
+	#[test]
+	fn parse_02() {
+		for (input, expected) in [
+			("", InvalidPositiveInteger::Empty),
+			("+", InvalidPositiveInteger::Empty),
+			("0", InvalidPositiveInteger::IsZero),
+			("-000", InvalidPositiveInteger::IsZero),
+			("-12", InvalidPositiveInteger::IsNegative),
+			("1.0", InvalidPositiveInteger::InvalidChar('.', 1)),
+			("+1é", InvalidPositiveInteger::InvalidChar('é', 2)),
+			("--1", InvalidPositiveInteger::InvalidChar('-', 1)),
+		] {
+			assert_eq!(
+				PositiveInteger::new(input).unwrap_err(),
+				expected,
+				"{input}"
+			)
+		}
+	}
+
+	#[test]
+	fn canonical_01() {
+		assert!(PositiveInteger::new_canonical("120").is_ok());
+		assert_eq!(
+			PositiveInteger::new_canonical("0120").unwrap_err(),
+			InvalidPositiveInteger::LeadingZeros
+		);
+		assert_eq!(
+			PositiveInteger::new_canonical("+1").unwrap_err(),
+			InvalidPositiveInteger::LeadingZeros
+		);
+		assert_eq!(
+			PositiveInteger::new_canonical("0").unwrap_err(),
+			InvalidPositiveInteger::IsZero
+		);
+		assert!(!PositiveInteger::new("+007").unwrap().is_canonical());
+		assert_eq!(
+			PositiveInteger::new("+007").unwrap().normalize().as_str(),
+			"7"
+		);
+		assert_eq!(
+			PositiveInteger::new("100").unwrap().normalize().as_str(),
+			"100"
+		)
+	}
+
+	#[test]
+	fn buf_01() {
+		let buf = PositiveIntegerBuf::new("12".to_owned()).unwrap();
+		assert_eq!(buf.as_str(), "12");
+		let (e, s) = PositiveIntegerBuf::new("-12".to_owned()).unwrap_err();
+		assert_eq!(e, InvalidPositiveInteger::IsNegative);
+		assert_eq!(s, "-12")
+	}
 }
@@ -1,11 +1,12 @@
 use crate::lexical::lexical_form;
 
 use super::{Decimal, DecimalBuf, Integer, IntegerBuf, NonNegativeInteger, Overflow, Sign};
-use std::borrow::{Borrow, ToOwned};
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::str::FromStr;
+use alloc::{borrow::ToOwned, string::ToString};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
 mod negative_integer;
 
@@ -24,7 +25,7 @@ lexical_form! {
 
 	/// Creates a new non positive integer from a string.
 	///
-	/// If the input string is ot a [valid XSD non positive integer](https://www.w3.org/TR/xmlschema-2/#nonPositiveInteger),
+	/// If the input string is not a [valid XSD non positive integer](https://www.w3.org/TR/xmlschema-2/#nonPositiveInteger),
 	/// an [`InvalidNonPositiveInteger`] error is returned.
 	new,
 
@@ -243,6 +244,7 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 		Initial,
 		NonEmptyInteger,
 		Integer,
+		NonEmptyZero,
 		Zero,
 	}
 
@@ -252,7 +254,8 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 		state = match state {
 			State::Initial => match chars.next() {
 				Some(b'-') => State::NonEmptyInteger,
-				Some(b'+' | b'0') => State::Zero,
+				Some(b'+') => State::NonEmptyZero,
+				Some(b'0') => State::Zero,
 				_ => break false,
 			},
 			State::NonEmptyInteger => match chars.next() {
@@ -264,10 +267,15 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 				Some(_) => break false,
 				None => break true,
 			},
-			State::Zero => match chars.next() {
+			State::NonEmptyZero => match chars.next() {
 				Some(b'0') => State::Zero,
 				_ => break false,
 			},
+			State::Zero => match chars.next() {
+				Some(b'0') => State::Zero,
+				Some(_) => break false,
+				None => break true,
+			},
 		}
 	}
 }
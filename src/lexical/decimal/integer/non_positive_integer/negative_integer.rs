@@ -1,14 +1,15 @@
 use crate::lexical::{
-	lexical_form, Decimal, DecimalBuf, Integer, IntegerBuf, NonNegativeInteger,
-	NonNegativeIntegerBuf,
+	lexical_form, Decimal, DecimalBuf, Integer, IntegerBuf, NonNegativeInteger, NonPositiveInteger,
+	NonPositiveIntegerBuf,
 };
 
 use super::Overflow;
-use std::borrow::{Borrow, ToOwned};
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::str::FromStr;
+use alloc::{borrow::ToOwned, string::ToString};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
 lexical_form! {
 	/// Negative integer number.
@@ -23,7 +24,7 @@ lexical_form! {
 
 	/// Creates a new negative integer from a string.
 	///
-	/// If the input string is ot a [valid XSD negative integer](https://www.w3.org/TR/xmlschema-2/#negativeInteger),
+	/// If the input string is not a [valid XSD negative integer](https://www.w3.org/TR/xmlschema-2/#negativeInteger),
 	/// an [`InvalidNegativeInteger`] error is returned.
 	new,
 
@@ -38,7 +39,7 @@ lexical_form! {
 	error: InvalidNegativeInteger,
 	as_ref: as_negative_integer,
 	parent_forms: {
-		as_non_positive_integer: NonNegativeInteger, NonNegativeIntegerBuf,
+		as_non_positive_integer: NonPositiveInteger, NonPositiveIntegerBuf,
 		as_integer: Integer, IntegerBuf,
 		as_decimal: Decimal, DecimalBuf
 	}
@@ -167,6 +168,7 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 	enum State {
 		Initial,
 		NonEmptyInteger,
+		Zeros,
 		Integer,
 	}
 
@@ -179,7 +181,13 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 				_ => break false,
 			},
 			State::NonEmptyInteger => match chars.next() {
-				Some(b'0'..=b'9') => State::Integer,
+				Some(b'0') => State::Zeros,
+				Some(b'1'..=b'9') => State::Integer,
+				_ => break false,
+			},
+			State::Zeros => match chars.next() {
+				Some(b'0') => State::Zeros,
+				Some(b'1'..=b'9') => State::Integer,
 				_ => break false,
 			},
 			State::Integer => match chars.next() {
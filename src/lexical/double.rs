@@ -1,4 +1,7 @@
-use super::{lexical_form, Decimal, Float, FloatBuf, Integer, Overflow};
+use super::{
+	lexical_form, Decimal, Float, FloatBuf, Integer, NonNegativeInteger, NonPositiveInteger,
+	Overflow,
+};
 use std::borrow::{Borrow, ToOwned};
 use std::fmt;
 use std::hash::Hash;
@@ -16,7 +19,7 @@ lexical_form! {
 
 	/// Creates a new double from a string.
 	///
-	/// If the input string is ot a [valid XSD double](https://www.w3.org/TR/xmlschema-2/#double),
+	/// If the input string is not a [valid XSD double](https://www.w3.org/TR/xmlschema-2/#double),
 	/// an [`InvalidDouble`] error is returned.
 	new,
 
@@ -38,14 +41,18 @@ pub const POSITIVE_INFINITY: &Double = unsafe { Double::new_unchecked_from_slice
 pub const NEGATIVE_INFINITY: &Double = unsafe { Double::new_unchecked_from_slice(b"-INF") };
 
 impl Double {
+	/// Checks if this is `INF` or `-INF`, without converting it to `f64`.
 	pub fn is_infinite(&self) -> bool {
 		matches!(&self.0, b"INF" | b"-INF")
 	}
 
+	/// Checks if this is neither infinite nor `NaN`, without converting it to
+	/// `f64`.
 	pub fn is_finite(&self) -> bool {
 		!matches!(&self.0, b"INF" | b"-INF" | b"NaN")
 	}
 
+	/// Checks if this is `NaN`, without converting it to `f64`.
 	pub fn is_nan(&self) -> bool {
 		&self.0 == b"NaN"
 	}
@@ -201,6 +208,27 @@ impl<'a> From<&'a Decimal> for &'a Double {
 	}
 }
 
+impl<'a> From<&'a Integer> for &'a Double {
+	#[inline(always)]
+	fn from(d: &'a Integer) -> Self {
+		unsafe { Double::new_unchecked(d) }
+	}
+}
+
+impl<'a> From<&'a NonNegativeInteger> for &'a Double {
+	#[inline(always)]
+	fn from(d: &'a NonNegativeInteger) -> Self {
+		unsafe { Double::new_unchecked(d) }
+	}
+}
+
+impl<'a> From<&'a NonPositiveInteger> for &'a Double {
+	#[inline(always)]
+	fn from(d: &'a NonPositiveInteger) -> Self {
+		unsafe { Double::new_unchecked(d) }
+	}
+}
+
 impl DoubleBuf {
 	#[inline(always)]
 	pub fn nan() -> Self {
@@ -247,7 +275,7 @@ fn check_normal<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 			},
 			State::NonEmptyInteger => match chars.next() {
 				Some(b'0'..=b'9') => State::Integer,
-				Some(b'.') => State::Decimal,
+				Some(b'.') => State::NonEmptyDecimal,
 				_ => break false,
 			},
 			State::Integer => match chars.next() {
@@ -412,4 +440,59 @@ mod tests {
 	fn format_01() {
 		assert_eq!(DoubleBuf::from(1.0e10f64).to_string(), "1.0e10")
 	}
+
+	#[test]
+	fn parse_23() {
+		for input in [
+			"-1E4",
+			"1267.43233E12",
+			"12.78e-2",
+			"12",
+			"-0",
+			"0",
+			"INF",
+			"-INF",
+			"NaN",
+			"+1.5E3",
+			"-.5",
+		] {
+			assert!(Double::new(input).is_ok(), "{input}")
+		}
+	}
+
+	#[test]
+	fn parse_24() {
+		for input in [
+			"", "inf", "-inf", "+INF", "nan", "NAN", "infinity", "Infinity", "+.", "-.", "-.e1",
+			"1e", "1.5E3 ", " 1",
+		] {
+			assert!(Double::new(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn special_values_01() {
+		for (input, nan, infinite) in [
+			("NaN", true, false),
+			("INF", false, true),
+			("-INF", false, true),
+			("-1.5E3", false, false),
+		] {
+			let d = Double::new(input).unwrap();
+			assert_eq!(d.is_nan(), nan, "{input}");
+			assert_eq!(d.is_infinite(), infinite, "{input}");
+			assert_eq!(d.is_finite(), !nan && !infinite, "{input}")
+		}
+
+		assert!(NAN.value().is_nan());
+		assert_eq!(POSITIVE_INFINITY.value(), crate::Double::INFINITY);
+		assert_eq!(NEGATIVE_INFINITY.value(), crate::Double::NEG_INFINITY)
+	}
+
+	#[test]
+	fn integer_01() {
+		let i = Integer::new("-12").unwrap();
+		let d: &Double = i.into();
+		assert_eq!(d.value(), crate::Double::new(-12.0))
+	}
 }
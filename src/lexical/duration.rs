@@ -1,27 +1,160 @@
-fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
-	enum State {
-		Initial,
-		P,
-		DuYearMonFragOrDuDayTimeFrag
+use static_regular_grammar::RegularGrammar;
+
+use super::{grammar_eq, Lexical, LexicalFormOf};
+
+/// Duration.
+///
+/// ```abnf
+/// duration = [ "-" ] %s"P" ( year-month [ day-time ] / day-time )
+///
+/// year-month = 1*DIGIT %s"Y" [ 1*DIGIT %s"M" ]
+///            / 1*DIGIT %s"M"
+///
+/// day-time = 1*DIGIT %s"D" [ time ]
+///          / time
+///
+/// time = %s"T" ( 1*DIGIT %s"H" [ 1*DIGIT %s"M" ] [ seconds ]
+///              / 1*DIGIT %s"M" [ seconds ]
+///              / seconds )
+///
+/// seconds = ( 1*DIGIT [ "." *DIGIT ] / "." 1*DIGIT ) %s"S"
+/// ```
+///
+#[derive(RegularGrammar, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[grammar(sized(DurationBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
+pub struct Duration(str);
+
+grammar_eq!(Duration, DurationBuf);
+
+impl Lexical for Duration {
+	type Error = InvalidDuration<String>;
+
+	fn parse(value: &str) -> Result<&Self, Self::Error> {
+		Self::new(value).map_err(|_| InvalidDuration(value.to_owned()))
 	}
+}
 
-	let mut state = State::Initial;
-
-	loop {
-		state = match state {
-			State::Initial => match chars.next() {
-				Some(b'-') => State::P,
-				Some(b'P') => State::DuYearMonFragOrDuDayTimeFrag,
-				_ => todo!()
-			},
-			State::P => match chars.next() {
-				Some(b'P') => State::DuYearMonFragOrDuDayTimeFrag,
-				_ => todo!()
-			}
-			State::DuYearMonFragOrDuDayTimeFrag => match chars.next() {
-				Some(b'P') => State::DuYearMonFragOrDuDayTimeFrag,
-				_ => todo!()
+impl LexicalFormOf<crate::Duration> for Duration {
+	type ValueError = crate::DurationOverflow;
+
+	fn try_as_value(&self) -> Result<crate::Duration, Self::ValueError> {
+		self.value()
+	}
+}
+
+impl Duration {
+	/// Returns the duration value.
+	///
+	/// Fractional seconds are truncated to the nanosecond. Fails if the
+	/// number of months overflows a `u32` or the number of seconds overflows
+	/// a `u64`.
+	pub fn value(&self) -> Result<crate::Duration, crate::DurationOverflow> {
+		let s = self.as_str();
+		let (is_negative, s) = match s.strip_prefix('-') {
+			Some(s) => (true, s),
+			None => (false, s),
+		};
+
+		let mut builder = crate::Duration::builder();
+		if is_negative {
+			builder = builder.negative()
+		}
+
+		let mut is_time = false;
+		let mut number = &s[1..];
+		while let Some(i) = number.find(|c: char| c.is_ascii_uppercase()) {
+			let (n, rest) = number.split_at(i);
+			number = &rest[1..];
+			match (is_time, rest.as_bytes()[0]) {
+				(_, b'T') => is_time = true,
+				(false, b'Y') => builder = builder.years(parse_u64(n)?),
+				(false, b'M') => builder = builder.months(parse_u64(n)?),
+				(false, _) => builder = builder.days(parse_u64(n)?),
+				(true, b'H') => builder = builder.hours(parse_u64(n)?),
+				(true, b'M') => builder = builder.minutes(parse_u64(n)?),
+				(true, _) => {
+					let (whole, fraction) = n.split_once('.').unwrap_or((n, ""));
+					let nano_seconds = fraction
+						.bytes()
+						.chain(core::iter::repeat(b'0'))
+						.take(9)
+						.fold(0, |n, c| n * 10 + (c - b'0') as u32);
+					builder = builder
+						.seconds(parse_u64(whole)?)
+						.nano_seconds(nano_seconds)
+				}
 			}
 		}
+
+		builder.checked_build().ok_or(crate::DurationOverflow)
+	}
+}
+
+/// Parses a (possibly empty) sequence of digits.
+fn parse_u64(s: &str) -> Result<u64, crate::DurationOverflow> {
+	if s.is_empty() {
+		Ok(0)
+	} else {
+		s.parse().map_err(|_| crate::DurationOverflow)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in [
+			"P1Y",
+			"-P1Y2M3DT4H5M6.5S",
+			"P2M",
+			"P3D",
+			"PT4H",
+			"PT5M",
+			"PT6S",
+			"P1YT1M",
+			"PT1.S",
+			"PT.5S",
+			"PT0S",
+			"P0Y0M0DT0H0M0.0S",
+		] {
+			assert!(Duration::new(input).is_ok(), "{input}")
+		}
+	}
+
+	#[test]
+	fn parse_02() {
+		for input in [
+			"", "P", "-P", "PT", "P1YT", "P2W", "PT1,5S", "PT.S", "P1D2Y", "PT1S2M", "P1H", "PT1D",
+			"p1y", "P-1Y", "+P1Y", "P1.5Y", "PT1.5M", "P1Y ",
+		] {
+			assert!(Duration::new(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn value_01() {
+		for (input, expected) in [
+			("PT1.S", "PT1S"),
+			("PT.5S", "PT0.5S"),
+			("-P1Y2M3DT4H5M6.5S", "-P1Y2M3DT4H5M6.5S"),
+			("P13M", "P1Y1M"),
+			("PT36H", "P1DT12H"),
+			("-P0Y", "PT0S"),
+			("PT0.1234567891S", "PT0.123456789S"),
+		] {
+			assert_eq!(
+				Duration::new(input).unwrap().value().unwrap().to_string(),
+				expected,
+				"{input}"
+			)
+		}
+
+		assert!(Duration::new("P4294967296M").unwrap().value().is_err());
+		assert!(Duration::new("PT18446744073709551616S")
+			.unwrap()
+			.value()
+			.is_err())
 	}
-}
\ No newline at end of file
+}
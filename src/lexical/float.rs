@@ -16,7 +16,7 @@ lexical_form! {
 
 	/// Creates a new float from a string.
 	///
-	/// If the input string is ot a [valid XSD float](https://www.w3.org/TR/xmlschema-2/#float),
+	/// If the input string is not a [valid XSD float](https://www.w3.org/TR/xmlschema-2/#float),
 	/// an [`InvalidFloat`] error is returned.
 	new,
 
@@ -38,14 +38,18 @@ pub const POSITIVE_INFINITY: &Float = unsafe { Float::new_unchecked_from_slice(b
 pub const NEGATIVE_INFINITY: &Float = unsafe { Float::new_unchecked_from_slice(b"-INF") };
 
 impl Float {
+	/// Checks if this is `INF` or `-INF`, without converting it to `f32`.
 	pub fn is_infinite(&self) -> bool {
 		matches!(&self.0, b"INF" | b"-INF")
 	}
 
+	/// Checks if this is neither infinite nor `NaN`, without converting it to
+	/// `f32`.
 	pub fn is_finite(&self) -> bool {
 		!matches!(&self.0, b"INF" | b"-INF" | b"NaN")
 	}
 
+	/// Checks if this is `NaN`, without converting it to `f32`.
 	pub fn is_nan(&self) -> bool {
 		&self.0 == b"NaN"
 	}
@@ -249,7 +253,7 @@ fn check_normal<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 			},
 			State::NonEmptyInteger => match chars.next() {
 				Some(b'0'..=b'9') => State::Integer,
-				Some(b'.') => State::Decimal,
+				Some(b'.') => State::NonEmptyDecimal,
 				_ => break false,
 			},
 			State::Integer => match chars.next() {
@@ -414,4 +418,50 @@ mod tests {
 	fn format_01() {
 		assert_eq!(FloatBuf::from(1.0e10f32).to_string(), "1.0e10")
 	}
+
+	#[test]
+	fn parse_23() {
+		// Examples of XSD 1.0 §3.2.4.1, and special values.
+		for input in [
+			"-1E4",
+			"1267.43233E12",
+			"12.78e-2",
+			"12",
+			"-0",
+			"0",
+			"INF",
+			"-INF",
+			"NaN",
+			"-1.5E3",
+			"+1.5E3",
+			"-.5",
+		] {
+			assert!(Float::new(input).is_ok(), "{input}")
+		}
+	}
+
+	#[test]
+	fn parse_24() {
+		for input in [
+			"", "inf", "-inf", "+INF", "nan", "NAN", "infinity", "Infinity", "+.", "-.", "-.e1",
+			"1e", "1.5E3 ", " 1",
+		] {
+			assert!(Float::new(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn special_values_01() {
+		for (input, nan, infinite) in [
+			("NaN", true, false),
+			("INF", false, true),
+			("-INF", false, true),
+			("-1.5E3", false, false),
+		] {
+			let f = Float::new(input).unwrap();
+			assert_eq!(f.is_nan(), nan, "{input}");
+			assert_eq!(f.is_infinite(), infinite, "{input}");
+			assert_eq!(f.is_finite(), !nan && !infinite, "{input}")
+		}
+	}
 }
@@ -18,7 +18,7 @@ lexical_form! {
 
 	/// Creates a new hexadecimal string from a string.
 	///
-	/// If the input string is ot a [valid XSD hexadecimal string](https://www.w3.org/TR/xmlschema-2/#hexBinary),
+	/// If the input string is not a [valid XSD hexadecimal string](https://www.w3.org/TR/xmlschema-2/#hexBinary),
 	/// an [`InvalidHexBinary`] error is returned.
 	new,
 
@@ -36,9 +36,16 @@ lexical_form! {
 }
 
 impl HexBinary {
+	/// Returns the digits of the canonical representation, in uppercase.
 	#[inline(always)]
-	fn as_canonical_str(&self) -> &str {
-		self.as_str()
+	fn canonical_bytes(&self) -> impl '_ + Iterator<Item = u8> {
+		self.0.iter().map(u8::to_ascii_uppercase)
+	}
+
+	/// Returns the canonical representation of this hexadecimal string,
+	/// using uppercase digits.
+	pub fn normalize(&self) -> String {
+		self.as_str().to_ascii_uppercase()
 	}
 
 	#[inline(always)]
@@ -49,7 +56,7 @@ impl HexBinary {
 
 impl PartialEq for HexBinary {
 	fn eq(&self, other: &Self) -> bool {
-		self.as_canonical_str() == other.as_canonical_str()
+		self.0.eq_ignore_ascii_case(&other.0)
 	}
 }
 
@@ -57,13 +64,15 @@ impl Eq for HexBinary {}
 
 impl Hash for HexBinary {
 	fn hash<H: Hasher>(&self, h: &mut H) {
-		self.as_canonical_str().hash(h)
+		for b in self.canonical_bytes() {
+			b.hash(h)
+		}
 	}
 }
 
 impl Ord for HexBinary {
 	fn cmp(&self, other: &Self) -> Ordering {
-		self.as_canonical_str().cmp(other.as_canonical_str())
+		self.canonical_bytes().cmp(other.canonical_bytes())
 	}
 }
 
@@ -92,30 +101,32 @@ impl Ord for HexBinaryBuf {
 }
 
 fn check_bytes(s: &[u8]) -> bool {
-	check(s.iter().copied())
+	s.len() % 2 == 0 && s.iter().all(u8::is_ascii_hexdigit)
 }
 
-fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
-	enum State {
-		Data,
-		Padding,
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in ["", "0a0B", "CAFE", "00ff"] {
+			assert!(HexBinary::new(input).is_ok(), "{input}")
+		}
 	}
 
-	let mut state = State::Data;
-
-	loop {
-		state = match state {
-			State::Data => match chars.next() {
-				Some(b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'/') => State::Data,
-				Some(b'=') => State::Padding,
-				None => break true,
-				_ => break false,
-			},
-			State::Padding => match chars.next() {
-				Some(b'=') => State::Padding,
-				None => break true,
-				_ => break false,
-			},
+	#[test]
+	fn parse_02() {
+		for input in ["0", "0g", "SGVsbG8=", "0a 0b"] {
+			assert!(HexBinary::new(input).is_err(), "{input}")
 		}
 	}
+
+	#[test]
+	fn normalize_01() {
+		let a = HexBinary::new("0a0B").unwrap();
+		assert_eq!(a.normalize(), "0A0B");
+		assert_eq!(a, HexBinary::new("0A0b").unwrap());
+		assert_eq!(a.value().to_string(), "0A0B")
+	}
 }
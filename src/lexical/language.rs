@@ -0,0 +1,75 @@
+use super::{lexical_form, NormalizedString, NormalizedStringBuf, Token, TokenBuf};
+use std::borrow::{Borrow, ToOwned};
+use std::fmt;
+
+lexical_form! {
+	/// Language identifier.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#language>
+	ty: Language,
+
+	/// Owned language identifier.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#language>
+	buffer: LanguageBuf,
+
+	/// Creates a new language identifier from a string.
+	///
+	/// If the input string is not a [valid XSD language identifier](https://www.w3.org/TR/xmlschema-2/#language),
+	/// an [`InvalidLanguage`] error is returned.
+	new,
+
+	/// Creates a new language identifier from a string without checking it.
+	///
+	/// # Safety
+	///
+	/// The input string must be a [valid XSD language identifier](https://www.w3.org/TR/xmlschema-2/#language).
+	new_unchecked,
+
+	value: crate::LanguageBuf,
+	error: InvalidLanguage,
+	as_ref: as_language,
+	parent_forms: {
+		as_token: Token, TokenBuf,
+		as_normalized_string: NormalizedString, NormalizedStringBuf
+	}
+}
+
+impl Language {
+	pub fn value(&self) -> LanguageBuf {
+		self.to_owned()
+	}
+}
+
+impl PartialEq for Language {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl Eq for Language {}
+
+/// Checks the `[a-zA-Z]{1,8}(-[a-zA-Z0-9]{1,8})*` pattern.
+fn check_bytes(s: &[u8]) -> bool {
+	let mut subtags = s.split(|c| *c == b'-');
+	let primary = subtags.next().unwrap();
+	(1..=8).contains(&primary.len())
+		&& primary.iter().all(u8::is_ascii_alphabetic)
+		&& subtags.all(|t| (1..=8).contains(&t.len()) && t.iter().all(u8::is_ascii_alphanumeric))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		Language::new("en").unwrap();
+		Language::new("en-US").unwrap();
+		Language::new("zh-Hant-2023").unwrap();
+		assert!(Language::new("").is_err());
+		assert!(Language::new("1en").is_err());
+		assert!(Language::new("en-").is_err());
+		assert!(Language::new("abcdefghi").is_err())
+	}
+}
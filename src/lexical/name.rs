@@ -0,0 +1,126 @@
+use super::{lexical_form, NormalizedString, NormalizedStringBuf, Token, TokenBuf};
+use std::borrow::{Borrow, ToOwned};
+use std::fmt;
+
+lexical_form! {
+	/// XML name.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#Name>
+	ty: Name,
+
+	/// Owned XML name.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#Name>
+	buffer: NameBuf,
+
+	/// Creates a new XML name from a string.
+	///
+	/// If the input string is not a [valid XSD name](https://www.w3.org/TR/xmlschema-2/#Name),
+	/// an [`InvalidName`] error is returned.
+	new,
+
+	/// Creates a new XML name from a string without checking it.
+	///
+	/// # Safety
+	///
+	/// The input string must be a [valid XSD name](https://www.w3.org/TR/xmlschema-2/#Name).
+	new_unchecked,
+
+	value: crate::NameBuf,
+	error: InvalidName,
+	as_ref: as_name,
+	parent_forms: {
+		as_token: Token, TokenBuf,
+		as_normalized_string: NormalizedString, NormalizedStringBuf
+	}
+}
+
+impl Name {
+	pub fn value(&self) -> NameBuf {
+		self.to_owned()
+	}
+}
+
+impl PartialEq for Name {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl Eq for Name {}
+
+/// Checks the XML 1.0 `NameStartChar` production.
+///
+/// See: <https://www.w3.org/TR/xml/#NT-NameStartChar>
+pub(crate) fn is_name_start_char(c: char) -> bool {
+	matches!(c,
+		':'
+		| 'A'..='Z'
+		| '_'
+		| 'a'..='z'
+		| '\u{c0}'..='\u{d6}'
+		| '\u{d8}'..='\u{f6}'
+		| '\u{f8}'..='\u{2ff}'
+		| '\u{370}'..='\u{37d}'
+		| '\u{37f}'..='\u{1fff}'
+		| '\u{200c}'..='\u{200d}'
+		| '\u{2070}'..='\u{218f}'
+		| '\u{2c00}'..='\u{2fef}'
+		| '\u{3001}'..='\u{d7ff}'
+		| '\u{f900}'..='\u{fdcf}'
+		| '\u{fdf0}'..='\u{fffd}'
+		| '\u{10000}'..='\u{effff}'
+	)
+}
+
+/// Checks the XML 1.0 `NameChar` production.
+///
+/// See: <https://www.w3.org/TR/xml/#NT-NameChar>
+pub(crate) fn is_name_char(c: char) -> bool {
+	is_name_start_char(c)
+		|| matches!(c,
+			'-'
+			| '.'
+			| '0'..='9'
+			| '\u{b7}'
+			| '\u{300}'..='\u{36f}'
+			| '\u{203f}'..='\u{2040}'
+		)
+}
+
+fn check_bytes(s: &[u8]) -> bool {
+	match std::str::from_utf8(s) {
+		Ok(s) => {
+			let mut chars = s.chars();
+			chars.next().map_or(false, is_name_start_char) && chars.all(is_name_char)
+		}
+		Err(_) => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in [
+			"foo",
+			"_foo-bar.1",
+			"xsd:string",
+			":",
+			"été",
+			"名前",
+			"a\u{300}",
+		] {
+			assert!(Name::new(input).is_ok(), "{input}")
+		}
+	}
+
+	#[test]
+	fn parse_02() {
+		for input in ["", "1foo", "-foo", "foo bar", "\u{300}a", "foo\u{d7}"] {
+			assert!(Name::new(input).is_err(), "{input}")
+		}
+	}
+}
@@ -0,0 +1,93 @@
+use super::{
+	is_name_char, is_name_start_char, lexical_form, Name, NameBuf, NormalizedString,
+	NormalizedStringBuf, Token, TokenBuf,
+};
+use std::borrow::{Borrow, ToOwned};
+use std::fmt;
+
+lexical_form! {
+	/// Non-colonized XML name.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#NCName>
+	ty: NCName,
+
+	/// Owned non-colonized XML name.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#NCName>
+	buffer: NCNameBuf,
+
+	/// Creates a new non-colonized XML name from a string.
+	///
+	/// If the input string is not a [valid XSD NCName](https://www.w3.org/TR/xmlschema-2/#NCName),
+	/// an [`InvalidNCName`] error is returned.
+	new,
+
+	/// Creates a new non-colonized XML name from a string without checking it.
+	///
+	/// # Safety
+	///
+	/// The input string must be a [valid XSD NCName](https://www.w3.org/TR/xmlschema-2/#NCName).
+	new_unchecked,
+
+	value: crate::NCNameBuf,
+	error: InvalidNCName,
+	as_ref: as_nc_name,
+	parent_forms: {
+		as_name: Name, NameBuf,
+		as_token: Token, TokenBuf,
+		as_normalized_string: NormalizedString, NormalizedStringBuf
+	}
+}
+
+impl NCName {
+	pub fn value(&self) -> NCNameBuf {
+		self.to_owned()
+	}
+}
+
+impl PartialEq for NCName {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl Eq for NCName {}
+
+fn check_bytes(s: &[u8]) -> bool {
+	match std::str::from_utf8(s) {
+		Ok(s) => {
+			let mut chars = s.chars();
+			chars
+				.next()
+				.map_or(false, |c| c != ':' && is_name_start_char(c))
+				&& chars.all(|c| c != ':' && is_name_char(c))
+		}
+		Err(_) => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in ["foo", "_foo-bar.1", "été", "名前"] {
+			assert!(NCName::new(input).is_ok(), "{input}")
+		}
+	}
+
+	#[test]
+	fn parse_02() {
+		for input in ["", "xsd:string", ":foo", "foo:", "1foo", "foo bar"] {
+			assert!(NCName::new(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn parent_01() {
+		let n = NCName::new("foo").unwrap();
+		assert_eq!(n.as_name().as_str(), "foo");
+		assert!(<&NCName>::try_from(Name::new("xsd:foo").unwrap()).is_err())
+	}
+}
@@ -0,0 +1,79 @@
+use super::{is_name_char, lexical_form, NormalizedString, NormalizedStringBuf, Token, TokenBuf};
+use std::borrow::{Borrow, ToOwned};
+use std::fmt;
+
+lexical_form! {
+	/// XML name token.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#NMTOKEN>
+	ty: NMToken,
+
+	/// Owned XML name token.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#NMTOKEN>
+	buffer: NMTokenBuf,
+
+	/// Creates a new XML name token from a string.
+	///
+	/// If the input string is not a [valid XSD NMTOKEN](https://www.w3.org/TR/xmlschema-2/#NMTOKEN),
+	/// an [`InvalidNMToken`] error is returned.
+	new,
+
+	/// Creates a new XML name token from a string without checking it.
+	///
+	/// # Safety
+	///
+	/// The input string must be a [valid XSD NMTOKEN](https://www.w3.org/TR/xmlschema-2/#NMTOKEN).
+	new_unchecked,
+
+	value: crate::NMTokenBuf,
+	error: InvalidNMToken,
+	as_ref: as_nmtoken,
+	parent_forms: {
+		as_token: Token, TokenBuf,
+		as_normalized_string: NormalizedString, NormalizedStringBuf
+	}
+}
+
+impl NMToken {
+	pub fn value(&self) -> NMTokenBuf {
+		self.to_owned()
+	}
+}
+
+impl PartialEq for NMToken {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl Eq for NMToken {}
+
+/// Checks the XML 1.0 `Nmtoken` production.
+///
+/// See: <https://www.w3.org/TR/xml/#NT-Nmtoken>
+fn check_bytes(s: &[u8]) -> bool {
+	match std::str::from_utf8(s) {
+		Ok(s) => !s.is_empty() && s.chars().all(is_name_char),
+		Err(_) => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in ["foo-bar.123", "123", "-foo", ".", "a:b", "\u{b7}\u{300}"] {
+			assert!(NMToken::new(input).is_ok(), "{input}")
+		}
+	}
+
+	#[test]
+	fn parse_02() {
+		for input in ["", "foo bar", "foo,bar", "foo\t"] {
+			assert!(NMToken::new(input).is_err(), "{input}")
+		}
+	}
+}
@@ -0,0 +1,65 @@
+use super::lexical_form;
+use std::borrow::{Borrow, ToOwned};
+use std::fmt;
+
+lexical_form! {
+	/// Normalized string.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#normalizedString>
+	ty: NormalizedString,
+
+	/// Owned normalized string.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#normalizedString>
+	buffer: NormalizedStringBuf,
+
+	/// Creates a new normalized string from a string.
+	///
+	/// If the input string is not a [valid XSD normalized string](https://www.w3.org/TR/xmlschema-2/#normalizedString),
+	/// an [`InvalidNormalizedString`] error is returned.
+	new,
+
+	/// Creates a new normalized string from a string without checking it.
+	///
+	/// # Safety
+	///
+	/// The input string must be a [valid XSD normalized string](https://www.w3.org/TR/xmlschema-2/#normalizedString).
+	new_unchecked,
+
+	value: crate::NormalizedStringBuf,
+	error: InvalidNormalizedString,
+	as_ref: as_normalized_string,
+	parent_forms: {}
+}
+
+impl NormalizedString {
+	pub fn value(&self) -> NormalizedStringBuf {
+		self.to_owned()
+	}
+}
+
+impl PartialEq for NormalizedString {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl Eq for NormalizedString {}
+
+fn check_bytes(s: &[u8]) -> bool {
+	std::str::from_utf8(s).is_ok() && !s.iter().any(|c| matches!(c, b'\t' | b'\n' | b'\r'))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		NormalizedString::new("").unwrap();
+		NormalizedString::new(" foo  bar ").unwrap();
+		assert!(NormalizedString::new("foo\tbar").is_err());
+		assert!(NormalizedString::new("foo\nbar").is_err());
+		assert!(NormalizedString::new("foo\r").is_err())
+	}
+}
@@ -0,0 +1,120 @@
+use chrono::NaiveTime;
+use static_regular_grammar::RegularGrammar;
+
+use super::{grammar_eq, Lexical, LexicalFormOf};
+
+/// Time.
+///
+/// ```abnf
+/// time = hour ":" minute ":" second ["." fraction] [timezone]
+///      / "24:00:00" ["." 1*"0"] [timezone]
+///
+/// hour = ("0" / "1") DIGIT
+///      / "2" ("0" / "1" / "2" / "3")
+///
+/// minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+///
+/// second = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+///
+/// fraction = 1*DIGIT
+///
+/// timezone = ("+" / "-") timezone-offset
+///          / %s"Z"
+///
+/// timezone-offset = ("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute
+///                 / "14:00"
+/// ```
+///
+#[derive(RegularGrammar, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[grammar(sized(TimeBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
+pub struct Time(str);
+
+grammar_eq!(Time, TimeBuf);
+
+impl Lexical for Time {
+	type Error = InvalidTime<String>;
+
+	fn parse(value: &str) -> Result<&Self, Self::Error> {
+		Self::new(value).map_err(|_| InvalidTime(value.to_owned()))
+	}
+}
+
+impl LexicalFormOf<crate::Time> for Time {
+	type ValueError = std::convert::Infallible;
+
+	fn try_as_value(&self) -> Result<crate::Time, Self::ValueError> {
+		Ok(self.value())
+	}
+}
+
+impl Time {
+	/// Returns the time value.
+	///
+	/// A time without time zone is assumed to be in UTC. `24:00:00` is
+	/// mapped to midnight (`00:00:00`), and fractional seconds are truncated
+	/// to the nanosecond.
+	pub fn value(&self) -> crate::Time {
+		let s = self.as_str();
+		let hour: u32 = s[0..2].parse().unwrap();
+		let minute = s[3..5].parse().unwrap();
+		let second = s[6..8].parse().unwrap();
+		let s = &s[8..];
+
+		let (nano_seconds, s) = match s.strip_prefix('.') {
+			Some(s) => {
+				let len = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+				let (fraction, rest) = s.split_at(len);
+				let nano_seconds = fraction
+					.bytes()
+					.chain(core::iter::repeat(b'0'))
+					.take(9)
+					.fold(0, |n, c| n * 10 + (c - b'0') as u32);
+				(nano_seconds, rest)
+			}
+			None => (0, s),
+		};
+
+		let time = if hour == 24 {
+			NaiveTime::MIN
+		} else {
+			NaiveTime::from_hms_nano_opt(hour, minute, second, nano_seconds).unwrap()
+		};
+
+		crate::Time::new(time, super::time_zone_or_utc(s))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in [
+			"12:00:00",
+			"23:59:59.999Z",
+			"00:00:00-05:00",
+			"24:00:00",
+			"24:00:00.000+01:00",
+			"12:00:00-14:00",
+			"12:00:00+13:59",
+		] {
+			assert!(Time::new(input).is_ok(), "{input}")
+		}
+	}
+
+	#[test]
+	fn parse_02() {
+		for input in [
+			"24:00:01",
+			"12:60:00",
+			"12:00",
+			"T12:00:00",
+			"12:00:00.",
+			"12:00:00+14:01",
+			"12:00:00-15:00",
+		] {
+			assert!(Time::new(input).is_err(), "{input}")
+		}
+	}
+}
@@ -0,0 +1,72 @@
+use super::{lexical_form, NormalizedString, NormalizedStringBuf};
+use std::borrow::{Borrow, ToOwned};
+use std::fmt;
+
+lexical_form! {
+	/// Token.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#token>
+	ty: Token,
+
+	/// Owned token.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#token>
+	buffer: TokenBuf,
+
+	/// Creates a new token from a string.
+	///
+	/// If the input string is not a [valid XSD token](https://www.w3.org/TR/xmlschema-2/#token),
+	/// an [`InvalidToken`] error is returned.
+	new,
+
+	/// Creates a new token from a string without checking it.
+	///
+	/// # Safety
+	///
+	/// The input string must be a [valid XSD token](https://www.w3.org/TR/xmlschema-2/#token).
+	new_unchecked,
+
+	value: crate::TokenBuf,
+	error: InvalidToken,
+	as_ref: as_token,
+	parent_forms: {
+		as_normalized_string: NormalizedString, NormalizedStringBuf
+	}
+}
+
+impl Token {
+	pub fn value(&self) -> TokenBuf {
+		self.to_owned()
+	}
+}
+
+impl PartialEq for Token {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl Eq for Token {}
+
+fn check_bytes(s: &[u8]) -> bool {
+	std::str::from_utf8(s).is_ok()
+		&& !s.iter().any(|c| matches!(c, b'\t' | b'\n' | b'\r'))
+		&& !s.starts_with(b" ")
+		&& !s.ends_with(b" ")
+		&& !s.windows(2).any(|w| w == b"  ")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		Token::new("").unwrap();
+		Token::new("foo bar").unwrap();
+		assert!(Token::new(" foo").is_err());
+		assert!(Token::new("foo ").is_err());
+		assert!(Token::new("foo  bar").is_err());
+		assert!(Token::new("foo\tbar").is_err())
+	}
+}
@@ -1,7 +1,18 @@
 //! This crate aims at providing safe representations
 //! of [XSD built-in data types](https://www.w3.org/TR/xmlschema-2/#built-in-datatypes).
 //! For now, only numeric types are implemented.
-use iref::Iri;
+//!
+//! # `no_std` support
+//!
+//! The `std` feature is enabled by default. Disabling it makes the crate
+//! `no_std` (it still requires `alloc`), in which case only the decimal and
+//! integer types (lexical and value) are available.
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use iref::{Iri, IriBuf};
+#[cfg(feature = "std")]
 use static_iref::iri;
 
 pub mod lexical;
@@ -11,6 +22,7 @@ use lexical::{Lexical, LexicalFormOf};
 pub use value::*;
 
 /// XSD primitive datatype.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PrimitiveDatatype {
 	String,
 	Boolean,
@@ -55,54 +67,177 @@ macro_rules! impl_from {
 	};
 }
 
+macro_rules! datatype_names {
+	{
+		$ty:ident {
+			$($pat:pat => $($name:literal)? $(($t:ident))?),*
+		}
+	} => {
+		impl $ty {
+			/// Returns the local name of the datatype in the XSD namespace
+			/// (e.g. `nonNegativeInteger`).
+			pub fn local_name(&self) -> &'static str {
+				match self {
+					$($pat => $($name)? $($t.local_name())?),*
+				}
+			}
+
+			/// Returns the `xs:` prefixed name of the datatype
+			/// (e.g. `xs:nonNegativeInteger`).
+			pub fn xml_type_name(&self) -> &'static str {
+				match self {
+					$($pat => $(concat!("xs:", $name))? $($t.xml_type_name())?),*
+				}
+			}
+
+			/// Returns the name of the datatype as it appears in a schema
+			/// document, using the standard `xs` prefix
+			/// (e.g. `xs:nonNegativeInteger`).
+			///
+			/// Same as [`Self::xml_type_name`].
+			#[inline(always)]
+			pub fn xsd_qualified_name(&self) -> &'static str {
+				self.xml_type_name()
+			}
+
+			/// Returns the `xs:` prefixed name of the datatype
+			/// (e.g. `xs:nonNegativeInteger`).
+			///
+			/// Same as [`Self::xml_type_name`].
+			#[inline(always)]
+			pub fn xs_prefix_name(&self) -> &'static str {
+				self.xml_type_name()
+			}
+
+			/// Returns the `xsd:` prefixed name of the datatype
+			/// (e.g. `xsd:nonNegativeInteger`).
+			pub fn xsd_prefix_name(&self) -> &'static str {
+				match self {
+					$($pat => $(concat!("xsd:", $name))? $($t.xsd_prefix_name())?),*
+				}
+			}
+		}
+
+		/// Displays the `xsd:` prefixed name of the datatype.
+		impl core::fmt::Display for $ty {
+			fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+				f.write_str(self.xsd_prefix_name())
+			}
+		}
+	};
+}
+
+/// Implements `serde::Serialize` for datatypes, using their IRI.
+#[cfg(all(feature = "std", feature = "serde"))]
+macro_rules! serialize_iri {
+	($($ty:ty),*) => {
+		$(
+			impl serde::Serialize for $ty {
+				fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+				where
+					S: serde::Serializer,
+				{
+					self.iri().as_str().serialize(serializer)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "std")]
 pub const XSD_DURATION: &Iri = iri!("http://www.w3.org/2001/XMLSchema#duration");
+#[cfg(feature = "std")]
 pub const XSD_DATE_TIME: &Iri = iri!("http://www.w3.org/2001/XMLSchema#dateTime");
+#[cfg(feature = "std")]
 pub const XSD_TIME: &Iri = iri!("http://www.w3.org/2001/XMLSchema#time");
+#[cfg(feature = "std")]
 pub const XSD_DATE: &Iri = iri!("http://www.w3.org/2001/XMLSchema#date");
+#[cfg(feature = "std")]
 pub const XSD_G_YEAR_MONTH: &Iri = iri!("http://www.w3.org/2001/XMLSchema#gYearMonth");
+#[cfg(feature = "std")]
 pub const XSD_G_YEAR: &Iri = iri!("http://www.w3.org/2001/XMLSchema#gYear");
+#[cfg(feature = "std")]
 pub const XSD_G_MONTH_DAY: &Iri = iri!("http://www.w3.org/2001/XMLSchema#gMonthDay");
+#[cfg(feature = "std")]
 pub const XSD_G_DAY: &Iri = iri!("http://www.w3.org/2001/XMLSchema#gDay");
+#[cfg(feature = "std")]
 pub const XSD_G_MONTH: &Iri = iri!("http://www.w3.org/2001/XMLSchema#gMonth");
+#[cfg(feature = "std")]
 pub const XSD_STRING: &Iri = iri!("http://www.w3.org/2001/XMLSchema#string");
+#[cfg(feature = "std")]
 pub const XSD_BOOLEAN: &Iri = iri!("http://www.w3.org/2001/XMLSchema#boolean");
+#[cfg(feature = "std")]
 pub const XSD_BASE64_BINARY: &Iri = iri!("http://www.w3.org/2001/XMLSchema#base64Binary");
+#[cfg(feature = "std")]
 pub const XSD_HEX_BINARY: &Iri = iri!("http://www.w3.org/2001/XMLSchema#hexBinary");
+#[cfg(feature = "std")]
 pub const XSD_FLOAT: &Iri = iri!("http://www.w3.org/2001/XMLSchema#float");
+#[cfg(feature = "std")]
 pub const XSD_DECIMAL: &Iri = iri!("http://www.w3.org/2001/XMLSchema#decimal");
+#[cfg(feature = "std")]
 pub const XSD_DOUBLE: &Iri = iri!("http://www.w3.org/2001/XMLSchema#double");
+#[cfg(feature = "std")]
 pub const XSD_ANY_URI: &Iri = iri!("http://www.w3.org/2001/XMLSchema#anyURI");
+#[cfg(feature = "std")]
 pub const XSD_Q_NAME: &Iri = iri!("http://www.w3.org/2001/XMLSchema#QName");
+#[cfg(feature = "std")]
 pub const XSD_NOTATION: &Iri = iri!("http://www.w3.org/2001/XMLSchema#NOTATION");
+#[cfg(feature = "std")]
 pub const XSD_NORMALIZED_STRING: &Iri = iri!("http://www.w3.org/2001/XMLSchema#normalizedString");
+#[cfg(feature = "std")]
 pub const XSD_TOKEN: &Iri = iri!("http://www.w3.org/2001/XMLSchema#token");
+#[cfg(feature = "std")]
 pub const XSD_LANGUAGE: &Iri = iri!("http://www.w3.org/2001/XMLSchema#language");
+#[cfg(feature = "std")]
 pub const XSD_NAME: &Iri = iri!("http://www.w3.org/2001/XMLSchema#Name");
+#[cfg(feature = "std")]
 pub const XSD_NMTOKEN: &Iri = iri!("http://www.w3.org/2001/XMLSchema#NMTOKEN");
+#[cfg(feature = "std")]
 pub const XSD_NC_NAME: &Iri = iri!("http://www.w3.org/2001/XMLSchema#NCName");
+#[cfg(feature = "std")]
 pub const XSD_NMTOKENS: &Iri = iri!("http://www.w3.org/2001/XMLSchema#NMTOKENS");
+#[cfg(feature = "std")]
 pub const XSD_ID: &Iri = iri!("http://www.w3.org/2001/XMLSchema#ID");
+#[cfg(feature = "std")]
 pub const XSD_IDREF: &Iri = iri!("http://www.w3.org/2001/XMLSchema#IDREF");
+#[cfg(feature = "std")]
 pub const XSD_ENTITY: &Iri = iri!("http://www.w3.org/2001/XMLSchema#ENTITY");
+#[cfg(feature = "std")]
 pub const XSD_IDREFS: &Iri = iri!("http://www.w3.org/2001/XMLSchema#IDREFS");
+#[cfg(feature = "std")]
 pub const XSD_ENTITIES: &Iri = iri!("http://www.w3.org/2001/XMLSchema#ENTITIES");
+#[cfg(feature = "std")]
 pub const XSD_INTEGER: &Iri = iri!("http://www.w3.org/2001/XMLSchema#integer");
+#[cfg(feature = "std")]
 pub const XSD_NON_POSITIVE_INTEGER: &Iri =
 	iri!("http://www.w3.org/2001/XMLSchema#nonPositiveInteger");
+#[cfg(feature = "std")]
 pub const XSD_NEGATIVE_INTEGER: &Iri = iri!("http://www.w3.org/2001/XMLSchema#negativeInteger");
+#[cfg(feature = "std")]
 pub const XSD_LONG: &Iri = iri!("http://www.w3.org/2001/XMLSchema#long");
+#[cfg(feature = "std")]
 pub const XSD_INT: &Iri = iri!("http://www.w3.org/2001/XMLSchema#int");
+#[cfg(feature = "std")]
 pub const XSD_SHORT: &Iri = iri!("http://www.w3.org/2001/XMLSchema#short");
+#[cfg(feature = "std")]
 pub const XSD_BYTE: &Iri = iri!("http://www.w3.org/2001/XMLSchema#byte");
+#[cfg(feature = "std")]
 pub const XSD_NON_NEGATIVE_INTEGER: &Iri =
 	iri!("http://www.w3.org/2001/XMLSchema#nonNegativeInteger");
+#[cfg(feature = "std")]
 pub const XSD_UNSIGNED_LONG: &Iri = iri!("http://www.w3.org/2001/XMLSchema#unsignedLong");
+#[cfg(feature = "std")]
 pub const XSD_UNSIGNED_INT: &Iri = iri!("http://www.w3.org/2001/XMLSchema#unsignedInt");
+#[cfg(feature = "std")]
 pub const XSD_UNSIGNED_SHORT: &Iri = iri!("http://www.w3.org/2001/XMLSchema#unsignedShort");
+#[cfg(feature = "std")]
 pub const XSD_UNSIGNED_BYTE: &Iri = iri!("http://www.w3.org/2001/XMLSchema#unsignedByte");
+#[cfg(feature = "std")]
 pub const XSD_POSITIVE_INTEGER: &Iri = iri!("http://www.w3.org/2001/XMLSchema#positiveInteger");
 
 /// XSD datatype.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(not(feature = "std"), derive(Debug))]
 pub enum Datatype {
 	String(Option<StringDatatype>),
 	Boolean,
@@ -123,12 +258,12 @@ pub enum Datatype {
 	AnyUri,
 	QName,
 	Notation,
+	List(ListDatatype),
 }
 
+#[cfg(feature = "std")]
 impl Datatype {
-	#[allow(clippy::if_same_then_else)] // until TODOs are resolved.
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		// TODO built-in types derived by list (NMTOKENS, IDREFS, ENTITIES).
 		if iri == XSD_DURATION {
 			Some(Self::Duration)
 		} else if iri == XSD_DATE_TIME {
@@ -186,7 +321,7 @@ impl Datatype {
 				NormalizedStringDatatype::Token(Some(TokenDatatype::NMToken)),
 			)))))
 		} else if iri == XSD_NMTOKENS {
-			None // TODO
+			Some(Self::List(ListDatatype::NMTokens))
 		} else if iri == XSD_NC_NAME {
 			Some(Self::String(Some(StringDatatype::NormalizedString(Some(
 				NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(
@@ -212,9 +347,9 @@ impl Datatype {
 				)))),
 			)))))
 		} else if iri == XSD_IDREFS {
-			None // TODO
+			Some(Self::List(ListDatatype::IdRefs))
 		} else if iri == XSD_ENTITIES {
-			None // TODO
+			Some(Self::List(ListDatatype::Entities))
 		} else if iri == XSD_INTEGER {
 			Some(Self::Decimal(Some(DecimalDatatype::Integer(None))))
 		} else if iri == XSD_NON_POSITIVE_INTEGER {
@@ -319,13 +454,22 @@ impl Datatype {
 			Self::AnyUri => XSD_ANY_URI,
 			Self::QName => XSD_Q_NAME,
 			Self::Notation => XSD_NOTATION,
+			Self::List(t) => t.iri(),
 		}
 	}
 
+	/// Returns the datatype identified by the given IRI string, if any.
+	///
+	/// Returns `None` if `iri` is not a valid IRI, or not a known XSD
+	/// datatype IRI.
+	pub fn from_str_iri(iri: &str) -> Option<Self> {
+		Iri::new(iri).ok().and_then(Self::from_iri)
+	}
+
 	pub fn parse(&self, value: &str) -> Result<Value, ParseError> {
 		match self {
 			Self::String(None) => Ok(Value::String(value.to_owned())),
-			Self::String(Some(_t)) => todo!(),
+			Self::String(Some(t)) => t.parse(value),
 			Self::Boolean => ParseRdf::parse_rdf(value)
 				.map(Value::Boolean)
 				.map_err(|_| ParseError),
@@ -339,17 +483,23 @@ impl Datatype {
 			Self::Double => ParseRdf::parse_rdf(value)
 				.map(Value::Double)
 				.map_err(|_| ParseError),
-			Self::Duration => todo!(),
+			Self::Duration => ParseRdf::parse_rdf(value)
+				.map(Value::Duration)
+				.map_err(|_| ParseError),
 			Self::DateTime => ParseRdf::parse_rdf(value)
 				.map(Value::DateTime)
 				.map_err(|_| ParseError),
-			Self::Time => todo!(),
-			Self::Date => todo!(),
-			Self::GYearMonth => todo!(),
-			Self::GYear => todo!(),
-			Self::GMonthDay => todo!(),
-			Self::GDay => todo!(),
-			Self::GMonth => todo!(),
+			Self::Time => ParseRdf::parse_rdf(value)
+				.map(Value::Time)
+				.map_err(|_| ParseError),
+			Self::Date => ParseRdf::parse_rdf(value)
+				.map(Value::Date)
+				.map_err(|_| ParseError),
+			Self::GYearMonth => value.parse().map(Value::GYearMonth).map_err(|_| ParseError),
+			Self::GYear => value.parse().map(Value::GYear).map_err(|_| ParseError),
+			Self::GMonthDay => value.parse().map(Value::GMonthDay).map_err(|_| ParseError),
+			Self::GDay => value.parse().map(Value::GDay).map_err(|_| ParseError),
+			Self::GMonth => value.parse().map(Value::GMonth).map_err(|_| ParseError),
 			Self::HexBinary => ParseRdf::parse_rdf(value)
 				.map(Value::HexBinary)
 				.map_err(|_| ParseError),
@@ -359,20 +509,698 @@ impl Datatype {
 			Self::AnyUri => ParseRdf::parse_rdf(value)
 				.map(Value::AnyUri)
 				.map_err(|_| ParseError),
-			Self::QName => todo!(),
-			Self::Notation => todo!(),
+			Self::QName => value.parse().map(Value::QName).map_err(|_| ParseError),
+			Self::Notation => Notation::parse_unresolved(value).map(Value::Notation),
+			Self::List(t) => t.parse(value),
+		}
+	}
+
+	/// Parses a value of this datatype, returning a detailed error on
+	/// failure.
+	///
+	/// This is the same as [`Self::parse`], but the error carries the
+	/// datatype, the input and the reason why the input was rejected.
+	pub fn try_parse_value(&self, value: &str) -> Result<Value, TypedParseError> {
+		self.parse(value).map_err(|_| TypedParseError {
+			datatype: *self,
+			input: value.to_owned(),
+			reason: self.parse_error_reason(value),
+		})
+	}
+
+	/// Finds out why `value` is not a valid value of this datatype.
+	fn parse_error_reason(&self, value: &str) -> ParseErrorReason {
+		match self {
+			Self::Decimal(Some(DecimalDatatype::Integer(Some(_)))) => {
+				match lexical::Integer::new(value) {
+					Ok(i) => {
+						let i = i.value();
+						let min = self.min_inclusive_integer();
+						let max = self.max_inclusive_integer();
+						if min.as_ref().map_or(false, |min| i < *min)
+							|| max.as_ref().map_or(false, |max| i > *max)
+						{
+							ParseErrorReason::OutOfRange {
+								min: min.map(|min| min.to_string()),
+								max: max.map(|max| max.to_string()),
+							}
+						} else {
+							ParseErrorReason::invalid_lexical_form(self)
+						}
+					}
+					Err(_) => ParseErrorReason::invalid_lexical_form(self),
+				}
+			}
+			Self::Date if lexical::Date::new(value).is_ok() => {
+				ParseErrorReason::invalid_date_component(value)
+			}
+			Self::DateTime if lexical::DateTime::new(value).is_ok() => {
+				let (date, time) = value.split_once('T').unwrap();
+				if lexical::Date::new(date).unwrap().value().is_err() {
+					ParseErrorReason::invalid_date_component(date)
+				} else {
+					ParseErrorReason::InvalidComponent {
+						component: "time",
+						value: time.to_owned(),
+					}
+				}
+			}
+			_ => ParseErrorReason::invalid_lexical_form(self),
+		}
+	}
+}
+
+datatype_names!(Datatype {
+	Self::String(None) => "string",
+	Self::String(Some(t)) => (t),
+	Self::Boolean => "boolean",
+	Self::Decimal(None) => "decimal",
+	Self::Decimal(Some(t)) => (t),
+	Self::Float => "float",
+	Self::Double => "double",
+	Self::Duration => "duration",
+	Self::DateTime => "dateTime",
+	Self::Time => "time",
+	Self::Date => "date",
+	Self::GYearMonth => "gYearMonth",
+	Self::GYear => "gYear",
+	Self::GMonthDay => "gMonthDay",
+	Self::GDay => "gDay",
+	Self::GMonth => "gMonth",
+	Self::HexBinary => "hexBinary",
+	Self::Base64Binary => "base64Binary",
+	Self::AnyUri => "anyURI",
+	Self::QName => "QName",
+	Self::Notation => "NOTATION",
+	Self::List(t) => (t)
+});
+
+impl Datatype {
+	const ALL: [Self; 44] = [
+		Self::Duration,
+		Self::DateTime,
+		Self::Time,
+		Self::Date,
+		Self::GYearMonth,
+		Self::GYear,
+		Self::GMonthDay,
+		Self::GDay,
+		Self::GMonth,
+		Self::String(None),
+		Self::Boolean,
+		Self::Base64Binary,
+		Self::HexBinary,
+		Self::Float,
+		Self::Decimal(None),
+		Self::Double,
+		Self::AnyUri,
+		Self::QName,
+		Self::Notation,
+		Self::String(Some(StringDatatype::NormalizedString(None))),
+		Self::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(None),
+		)))),
+		Self::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Language)),
+		)))),
+		Self::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Name(None))),
+		)))),
+		Self::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::NMToken)),
+		)))),
+		Self::List(ListDatatype::NMTokens),
+		Self::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+				None,
+			))))),
+		)))),
+		Self::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+				Some(NCNameDatatype::Id),
+			))))),
+		)))),
+		Self::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+				Some(NCNameDatatype::IdRef),
+			))))),
+		)))),
+		Self::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+				Some(NCNameDatatype::Entity),
+			))))),
+		)))),
+		Self::List(ListDatatype::IdRefs),
+		Self::List(ListDatatype::Entities),
+		Self::Decimal(Some(DecimalDatatype::Integer(None))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(
+			IntegerDatatype::NonPositiveInteger(None),
+		)))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(
+			IntegerDatatype::NonPositiveInteger(Some(NonPositiveIntegerDatatype::NegativeInteger)),
+		)))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+			None,
+		))))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+			Some(LongDatatype::Int(None)),
+		))))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+			Some(LongDatatype::Int(Some(IntDatatype::Short(None)))),
+		))))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+			Some(LongDatatype::Int(Some(IntDatatype::Short(Some(
+				ShortDatatype::Byte,
+			))))),
+		))))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(
+			IntegerDatatype::NonNegativeInteger(None),
+		)))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(
+			IntegerDatatype::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(
+				None,
+			))),
+		)))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(
+			IntegerDatatype::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(
+				Some(UnsignedLongDatatype::UnsignedInt(None)),
+			))),
+		)))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(
+			IntegerDatatype::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(
+				Some(UnsignedLongDatatype::UnsignedInt(Some(
+					UnsignedIntDatatype::UnsignedShort(None),
+				))),
+			))),
+		)))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(
+			IntegerDatatype::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(
+				Some(UnsignedLongDatatype::UnsignedInt(Some(
+					UnsignedIntDatatype::UnsignedShort(Some(UnsignedShortDatatype::UnsignedByte)),
+				))),
+			))),
+		)))),
+		Self::Decimal(Some(DecimalDatatype::Integer(Some(
+			IntegerDatatype::NonNegativeInteger(Some(NonNegativeIntegerDatatype::PositiveInteger)),
+		)))),
+	];
+
+	const PRIMITIVES: [Self; 19] = [
+		Self::String(None),
+		Self::Boolean,
+		Self::Decimal(None),
+		Self::Float,
+		Self::Double,
+		Self::Duration,
+		Self::DateTime,
+		Self::Time,
+		Self::Date,
+		Self::GYearMonth,
+		Self::GYear,
+		Self::GMonthDay,
+		Self::GDay,
+		Self::GMonth,
+		Self::HexBinary,
+		Self::Base64Binary,
+		Self::AnyUri,
+		Self::QName,
+		Self::Notation,
+	];
+
+	/// Returns all the built-in datatypes supported by this crate (all the
+	/// datatypes with an IRI constant, such as [`XSD_STRING`]).
+	pub fn all() -> &'static [Self] {
+		&Self::ALL
+	}
+
+	/// Returns the 19 primitive datatypes.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema-2/#built-in-primitive-datatypes>
+	pub fn primitives() -> &'static [Self] {
+		&Self::PRIMITIVES
+	}
+
+	/// Checks if this is a numeric datatype, derived from `xsd:decimal`,
+	/// `xsd:float` or `xsd:double`.
+	pub fn is_numeric(&self) -> bool {
+		matches!(self, Self::Decimal(_) | Self::Float | Self::Double)
+	}
+
+	/// Checks if this is `xsd:string` or any datatype derived from it.
+	pub fn is_string_derived(&self) -> bool {
+		matches!(self, Self::String(_))
+	}
+
+	/// Checks if this is a date, time or duration datatype, including the
+	/// `xsd:gYear` family.
+	pub fn is_date_or_time(&self) -> bool {
+		matches!(
+			self,
+			Self::Duration
+				| Self::DateTime
+				| Self::Time | Self::Date
+				| Self::GYearMonth
+				| Self::GYear
+				| Self::GMonthDay
+				| Self::GDay | Self::GMonth
+		)
+	}
+
+	/// Checks if this is a binary datatype (`xsd:hexBinary` or
+	/// `xsd:base64Binary`).
+	pub fn is_binary(&self) -> bool {
+		matches!(self, Self::HexBinary | Self::Base64Binary)
+	}
+
+	/// Checks if this is a datatype derived by list (e.g. `xsd:NMTOKENS`).
+	pub fn is_list_type(&self) -> bool {
+		matches!(self, Self::List(_))
+	}
+
+	/// Checks if this is an atomic datatype, meaning it is neither derived
+	/// by list nor by union.
+	pub fn is_atomic_type(&self) -> bool {
+		!self.is_list_type() && !self.is_union_type()
+	}
+
+	/// Checks if this is a datatype derived by union.
+	///
+	/// There are no built-in union datatypes, so this always returns
+	/// `false`.
+	pub fn is_union_type(&self) -> bool {
+		false
+	}
+
+	/// Returns this datatype as a list datatype, if it is one.
+	pub fn as_list_type(&self) -> Option<&ListDatatype> {
+		match self {
+			Self::List(t) => Some(t),
+			_ => None,
+		}
+	}
+
+	/// Returns the item datatype of this list datatype, or `None` if this
+	/// is not a list datatype.
+	pub fn item_type(&self) -> Option<&Datatype> {
+		self.as_list_type().map(ListDatatype::item_type)
+	}
+
+	/// Returns the datatype this datatype is derived from by restriction.
+	///
+	/// Returns `None` for primitive datatypes, since `xsd:anySimpleType` is
+	/// not represented.
+	pub fn parent(&self) -> Option<Self> {
+		match self {
+			Self::String(Some(t)) => Some(Self::String(t.parent())),
+			Self::Decimal(Some(t)) => Some(Self::Decimal(t.parent())),
+			_ => None,
+		}
+	}
+
+	/// Checks if this datatype is `other` or is derived from it by
+	/// restriction.
+	pub fn is_derived_from(&self, other: &Self) -> bool {
+		self.common_ancestor(other) == Some(*other)
+	}
+
+	/// Returns the number of restriction steps between this datatype and
+	/// its primitive datatype.
+	fn depth(&self) -> usize {
+		let mut depth = 0;
+		let mut t = *self;
+		while let Some(parent) = t.parent() {
+			depth += 1;
+			t = parent
+		}
+
+		depth
+	}
+
+	/// Returns the most specific datatype both `self` and `other` are
+	/// derived from (or equal to), if any.
+	///
+	/// For instance the common ancestor of `xsd:byte` and `xsd:short` is
+	/// `xsd:short`, and the common ancestor of `xsd:byte` and
+	/// `xsd:unsignedByte` is `xsd:integer`. Returns `None` if the datatypes
+	/// do not share the same primitive datatype.
+	pub fn common_ancestor(&self, other: &Self) -> Option<Self> {
+		let mut a = *self;
+		let mut b = *other;
+		let mut a_depth = a.depth();
+		let mut b_depth = b.depth();
+
+		while a_depth > b_depth {
+			a = a.parent()?;
+			a_depth -= 1
+		}
+
+		while b_depth > a_depth {
+			b = b.parent()?;
+			b_depth -= 1
+		}
+
+		while a != b {
+			a = a.parent()?;
+			b = b.parent()?
+		}
+
+		Some(a)
+	}
+
+	/// Returns the smallest integer of this datatype value space, if this is
+	/// an integer datatype bounded from below.
+	pub fn min_inclusive_integer(&self) -> Option<Integer> {
+		match self {
+			Self::Decimal(Some(DecimalDatatype::Integer(Some(t)))) => t.min_inclusive_integer(),
+			_ => None,
+		}
+	}
+
+	/// Returns the largest integer of this datatype value space, if this is
+	/// an integer datatype bounded from above.
+	pub fn max_inclusive_integer(&self) -> Option<Integer> {
+		match self {
+			Self::Decimal(Some(DecimalDatatype::Integer(Some(t)))) => t.max_inclusive_integer(),
+			_ => None,
 		}
 	}
+
+	/// Returns the smallest value of this datatype value space, if it is
+	/// bounded from below.
+	///
+	/// Only integer datatypes have such inherent bound.
+	#[cfg(feature = "std")]
+	pub fn min_value(&self) -> Option<Value> {
+		self.min_inclusive_integer().map(|i| {
+			self.parse(i.lexical_representation().as_str())
+				.expect("datatype bounds are in the datatype value space")
+		})
+	}
+
+	/// Returns the largest value of this datatype value space, if it is
+	/// bounded from above.
+	///
+	/// Only integer datatypes have such inherent bound.
+	#[cfg(feature = "std")]
+	pub fn max_value(&self) -> Option<Value> {
+		self.max_inclusive_integer().map(|i| {
+			self.parse(i.lexical_representation().as_str())
+				.expect("datatype bounds are in the datatype value space")
+		})
+	}
 }
 
+/// Error returned when parsing an invalid value.
+///
+/// See [`TypedParseError`] for a detailed version of this error.
+#[derive(Debug)]
+#[cfg_attr(feature = "std", derive(thiserror::Error), error("XSD parse error"))]
 pub struct ParseError;
 
+/// Detailed error returned by [`Datatype::try_parse_value`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid `{}` value `{input}`: {reason}", datatype.xs_prefix_name())]
+pub struct TypedParseError {
+	/// Datatype of the expected value.
+	pub datatype: Datatype,
+
+	/// Rejected input.
+	pub input: String,
+
+	/// Reason why the input was rejected.
+	pub reason: ParseErrorReason,
+}
+
+/// Reason why a value could not be parsed.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseErrorReason {
+	/// The input is not in the lexical space of the datatype.
+	#[error("{0}")]
+	InvalidLexicalForm(String),
+
+	/// The input is a valid integer, but outside of the datatype bounds.
+	///
+	/// A `None` bound means that the datatype is not bounded on this side.
+	#[error(
+		"value out of range [{}, {}]",
+		.min.as_deref().unwrap_or("-INF"),
+		.max.as_deref().unwrap_or("INF")
+	)]
+	OutOfRange {
+		min: Option<String>,
+		max: Option<String>,
+	},
+
+	/// The input is well-formed, but one of its components is invalid
+	/// (e.g. the day of `2023-02-30`).
+	#[error("invalid {component} `{value}`")]
+	InvalidComponent {
+		component: &'static str,
+		value: String,
+	},
+}
+
+#[cfg(feature = "std")]
+impl ParseErrorReason {
+	fn invalid_lexical_form(datatype: &Datatype) -> Self {
+		Self::InvalidLexicalForm(format!(
+			"not a valid `{}` lexical form",
+			datatype.xs_prefix_name()
+		))
+	}
+
+	/// Finds out which component of a lexically valid date (without time
+	/// zone) is invalid.
+	fn invalid_date_component(date: &str) -> Self {
+		let (sign, s) = match date.strip_prefix('-') {
+			Some(s) => ("-", s),
+			None => ("", date),
+		};
+
+		let (year, rest) = s.split_once('-').unwrap();
+		let year_exists = format!("{sign}{year}")
+			.parse()
+			.map_or(false, |y| Date::from_ymd(y, 1, 1).is_ok());
+
+		if year_exists {
+			Self::InvalidComponent {
+				component: "day",
+				value: rest[3..5].to_owned(),
+			}
+		} else {
+			Self::InvalidComponent {
+				component: "year",
+				value: format!("{sign}{year}"),
+			}
+		}
+	}
+}
+
+/// Error returned when converting an IRI (or IRI string) that does not
+/// identify any known XSD datatype.
+#[cfg(feature = "std")]
+#[derive(Debug, thiserror::Error)]
+#[error("unknown datatype `{0}`")]
+pub struct UnknownDatatype(pub String);
+
+#[cfg(feature = "std")]
+impl<'a> TryFrom<&'a str> for Datatype {
+	type Error = UnknownDatatype;
+
+	fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+		Self::from_str_iri(value).ok_or_else(|| UnknownDatatype(value.to_owned()))
+	}
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for Datatype {
+	type Error = UnknownDatatype;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		Self::from_str_iri(&value).ok_or(UnknownDatatype(value))
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a> TryFrom<&'a Iri> for Datatype {
+	type Error = UnknownDatatype;
+
+	fn try_from(value: &'a Iri) -> Result<Self, Self::Error> {
+		Self::from_iri(value).ok_or_else(|| UnknownDatatype(value.as_str().to_owned()))
+	}
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<IriBuf> for Datatype {
+	type Error = UnknownDatatype;
+
+	fn try_from(value: IriBuf) -> Result<Self, Self::Error> {
+		Self::from_iri(&value).ok_or_else(|| UnknownDatatype(value.into_string()))
+	}
+}
+
+#[cfg(feature = "std")]
+impl From<Datatype> for &'static Iri {
+	fn from(value: Datatype) -> Self {
+		value.iri()
+	}
+}
+
+#[cfg(feature = "std")]
+impl From<Datatype> for IriBuf {
+	fn from(value: Datatype) -> Self {
+		value.iri().to_owned()
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::str::FromStr for Datatype {
+	type Err = UnknownDatatype;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.try_into()
+	}
+}
+
+/// Shows the datatype IRI.
+#[cfg(feature = "std")]
+impl core::fmt::Debug for Datatype {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "<{}>", self.iri())
+	}
+}
+
+#[cfg(feature = "std")]
 impl AsRef<Iri> for Datatype {
 	fn as_ref(&self) -> &Iri {
 		self.iri()
 	}
 }
 
+#[cfg(all(feature = "std", feature = "serde"))]
+serialize_iri!(
+	Datatype,
+	StringDatatype,
+	NormalizedStringDatatype,
+	TokenDatatype,
+	NameDatatype,
+	NCNameDatatype,
+	DecimalDatatype,
+	IntegerDatatype,
+	NonPositiveIntegerDatatype,
+	LongDatatype,
+	IntDatatype,
+	ShortDatatype,
+	NonNegativeIntegerDatatype,
+	UnsignedLongDatatype,
+	UnsignedIntDatatype,
+	UnsignedShortDatatype,
+	ListDatatype
+);
+
+/// Deserializes a datatype from its IRI.
+#[cfg(all(feature = "std", feature = "serde"))]
+impl<'de> serde::Deserialize<'de> for Datatype {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct Visitor;
+
+		impl<'de> serde::de::Visitor<'de> for Visitor {
+			type Value = Datatype;
+
+			fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+				formatter.write_str("an XSD datatype IRI")
+			}
+
+			fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				v.parse().map_err(|e| E::custom(e))
+			}
+		}
+
+		deserializer.deserialize_str(Visitor)
+	}
+}
+
+/// Implements `serde::Deserialize` for sub-datatypes, resolving their IRI
+/// into a [`Datatype`] first.
+#[cfg(all(feature = "std", feature = "serde"))]
+macro_rules! deserialize_iri {
+	($($ty:ident: $pattern:pat => $value:expr),*) => {
+		$(
+			impl<'de> serde::Deserialize<'de> for $ty {
+				fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+				where
+					D: serde::Deserializer<'de>,
+				{
+					match <Datatype as serde::Deserialize>::deserialize(deserializer)? {
+						$pattern => Ok($value),
+						other => Err(serde::de::Error::custom(format!(
+							"`{}` is not a `{}`",
+							other.iri(),
+							stringify!($ty)
+						))),
+					}
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+deserialize_iri!(
+	StringDatatype: Datatype::String(Some(t)) => t,
+	NormalizedStringDatatype: Datatype::String(Some(StringDatatype::NormalizedString(Some(t)))) => t,
+	TokenDatatype: Datatype::String(Some(StringDatatype::NormalizedString(Some(
+		NormalizedStringDatatype::Token(Some(t)),
+	)))) => t,
+	NameDatatype: Datatype::String(Some(StringDatatype::NormalizedString(Some(
+		NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(t)))),
+	)))) => t,
+	NCNameDatatype: Datatype::String(Some(StringDatatype::NormalizedString(Some(
+		NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+			Some(t),
+		))))),
+	)))) => t,
+	DecimalDatatype: Datatype::Decimal(Some(t)) => t,
+	IntegerDatatype: Datatype::Decimal(Some(DecimalDatatype::Integer(Some(t)))) => t,
+	NonPositiveIntegerDatatype: Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+		IntegerDatatype::NonPositiveInteger(Some(t)),
+	)))) => t,
+	LongDatatype: Datatype::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+		Some(t),
+	))))) => t,
+	IntDatatype: Datatype::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+		Some(LongDatatype::Int(Some(t))),
+	))))) => t,
+	ShortDatatype: Datatype::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+		Some(LongDatatype::Int(Some(IntDatatype::Short(Some(t))))),
+	))))) => t,
+	NonNegativeIntegerDatatype: Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+		IntegerDatatype::NonNegativeInteger(Some(t)),
+	)))) => t,
+	UnsignedLongDatatype: Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+		IntegerDatatype::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(Some(t)))),
+	)))) => t,
+	UnsignedIntDatatype: Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+		IntegerDatatype::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(Some(
+			UnsignedLongDatatype::UnsignedInt(Some(t)),
+		)))),
+	)))) => t,
+	UnsignedShortDatatype: Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+		IntegerDatatype::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(Some(
+			UnsignedLongDatatype::UnsignedInt(Some(UnsignedIntDatatype::UnsignedShort(Some(t)))),
+		)))),
+	)))) => t,
+	ListDatatype: Datatype::List(t) => t
+);
+
 impl_from!(Datatype {
 	ty: StringDatatype => Self::String(ty),
 	ty: DecimalDatatype => Self::Decimal(ty),
@@ -408,10 +1236,23 @@ pub enum ParseRdfError<L, V> {
 }
 
 /// Datatype derived from `xsd:string`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum StringDatatype {
 	NormalizedString(Option<NormalizedStringDatatype>),
 }
 
+impl StringDatatype {
+	/// Returns the parent datatype, or `None` for `xsd:normalizedString`
+	/// whose parent, `xsd:string`, is not a `StringDatatype`.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::NormalizedString(None) => None,
+			Self::NormalizedString(Some(t)) => Some(Self::NormalizedString(t.parent())),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl StringDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -419,12 +1260,39 @@ impl StringDatatype {
 			Self::NormalizedString(Some(t)) => t.iri(),
 		}
 	}
+
+	pub fn parse(&self, value: &str) -> Result<Value, ParseError> {
+		match self {
+			Self::NormalizedString(None) => NormalizedStringBuf::parse_rdf(value)
+				.map(|_| Value::String(value.to_owned()))
+				.map_err(|_| ParseError),
+			Self::NormalizedString(Some(t)) => t.parse(value),
+		}
+	}
 }
 
+datatype_names!(StringDatatype {
+	Self::NormalizedString(None) => "normalizedString",
+	Self::NormalizedString(Some(t)) => (t)
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NormalizedStringDatatype {
 	Token(Option<TokenDatatype>),
 }
 
+impl NormalizedStringDatatype {
+	/// Returns the parent datatype, or `None` for `xsd:token` which is
+	/// directly derived from `xsd:normalizedString`.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::Token(None) => None,
+			Self::Token(Some(t)) => Some(Self::Token(t.parent())),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl NormalizedStringDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -432,14 +1300,44 @@ impl NormalizedStringDatatype {
 			Self::Token(Some(t)) => t.iri(),
 		}
 	}
+
+	pub fn parse(&self, value: &str) -> Result<Value, ParseError> {
+		match self {
+			Self::Token(None) => TokenBuf::parse_rdf(value)
+				.map(|_| Value::String(value.to_owned()))
+				.map_err(|_| ParseError),
+			Self::Token(Some(t)) => t.parse(value),
+		}
+	}
 }
 
+datatype_names!(NormalizedStringDatatype {
+	Self::Token(None) => "token",
+	Self::Token(Some(t)) => (t)
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TokenDatatype {
 	Language,
 	NMToken,
 	Name(Option<NameDatatype>),
 }
 
+impl TokenDatatype {
+	/// Returns the parent datatype among the `xsd:token` subtypes.
+	///
+	/// `xsd:language`, `xsd:NMTOKEN` and `xsd:Name` are directly derived from
+	/// `xsd:token`, so they have no such parent.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::Language | Self::NMToken => None,
+			Self::Name(None) => None,
+			Self::Name(Some(t)) => Some(Self::Name(t.parent())),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl TokenDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -449,12 +1347,48 @@ impl TokenDatatype {
 			Self::Name(Some(t)) => t.iri(),
 		}
 	}
+
+	pub fn parse(&self, value: &str) -> Result<Value, ParseError> {
+		match self {
+			Self::Language => LanguageBuf::parse_rdf(value)
+				.map(|_| Value::String(value.to_owned()))
+				.map_err(|_| ParseError),
+			Self::NMToken => NMTokenBuf::parse_rdf(value)
+				.map(|_| Value::String(value.to_owned()))
+				.map_err(|_| ParseError),
+			Self::Name(None) => NameBuf::parse_rdf(value)
+				.map(|_| Value::String(value.to_owned()))
+				.map_err(|_| ParseError),
+			Self::Name(Some(t)) => t.parse(value),
+		}
+	}
 }
 
+datatype_names!(TokenDatatype {
+	Self::Language => "language",
+	Self::NMToken => "NMTOKEN",
+	Self::Name(None) => "Name",
+	Self::Name(Some(t)) => (t)
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NameDatatype {
 	NCName(Option<NCNameDatatype>),
 }
 
+impl NameDatatype {
+	/// Returns `xsd:NCName` for its subtypes (`xsd:ID`, `xsd:IDREF` and
+	/// `xsd:ENTITY`), and `None` for `xsd:NCName` itself, which is derived
+	/// from `xsd:Name`.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::NCName(None) => None,
+			Self::NCName(Some(_)) => Some(Self::NCName(None)),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl NameDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -462,14 +1396,30 @@ impl NameDatatype {
 			Self::NCName(Some(t)) => t.iri(),
 		}
 	}
+
+	pub fn parse(&self, value: &str) -> Result<Value, ParseError> {
+		match self {
+			Self::NCName(None) => NCNameBuf::parse_rdf(value)
+				.map(|_| Value::String(value.to_owned()))
+				.map_err(|_| ParseError),
+			Self::NCName(Some(t)) => t.parse(value),
+		}
+	}
 }
 
+datatype_names!(NameDatatype {
+	Self::NCName(None) => "NCName",
+	Self::NCName(Some(t)) => (t)
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NCNameDatatype {
 	Id,
 	IdRef,
 	Entity,
 }
 
+#[cfg(feature = "std")]
 impl NCNameDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -478,13 +1428,44 @@ impl NCNameDatatype {
 			Self::Entity => XSD_ENTITY,
 		}
 	}
+
+	pub fn parse(&self, value: &str) -> Result<Value, ParseError> {
+		let result = match self {
+			Self::Id => Id::parse_rdf(value).map(|_| ()),
+			Self::IdRef => IdRef::parse_rdf(value).map(|_| ()),
+			Self::Entity => Entity::parse_rdf(value).map(|_| ()),
+		};
+
+		result
+			.map(|_| Value::String(value.to_owned()))
+			.map_err(|_| ParseError)
+	}
 }
 
+datatype_names!(NCNameDatatype {
+	Self::Id => "ID",
+	Self::IdRef => "IDREF",
+	Self::Entity => "ENTITY"
+});
+
 /// Datatype derived from `xsd:decimal`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DecimalDatatype {
 	Integer(Option<IntegerDatatype>),
 }
 
+impl DecimalDatatype {
+	/// Returns the parent datatype among the `xsd:integer` family, or `None`
+	/// for `xsd:integer`, whose parent is `xsd:decimal`.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::Integer(None) => None,
+			Self::Integer(Some(t)) => Some(Self::Integer(t.parent())),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl DecimalDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -503,6 +1484,11 @@ impl DecimalDatatype {
 	}
 }
 
+datatype_names!(DecimalDatatype {
+	Self::Integer(None) => "integer",
+	Self::Integer(Some(t)) => (t)
+});
+
 impl_from!(DecimalDatatype {
 	ty: IntegerDatatype => Self::Integer(ty),
 	ty: NonPositiveIntegerDatatype => Self::Integer(ty.into()),
@@ -515,12 +1501,33 @@ impl_from!(DecimalDatatype {
 	ty: UnsignedShortDatatype => Self::Integer(ty.into())
 });
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IntegerDatatype {
 	NonPositiveInteger(Option<NonPositiveIntegerDatatype>),
 	Long(Option<LongDatatype>),
 	NonNegativeInteger(Option<NonNegativeIntegerDatatype>),
 }
 
+impl IntegerDatatype {
+	/// Returns the parent datatype within the same branch of the
+	/// `xsd:integer` hierarchy.
+	///
+	/// The roots of each branch (`xsd:nonPositiveInteger`, `xsd:long` and
+	/// `xsd:nonNegativeInteger`) are derived from `xsd:integer`, which is not
+	/// an `IntegerDatatype`, so `None` is returned for them.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::NonPositiveInteger(None) => None,
+			Self::NonPositiveInteger(Some(_)) => Some(Self::NonPositiveInteger(None)),
+			Self::Long(None) => None,
+			Self::Long(Some(t)) => Some(Self::Long(t.parent())),
+			Self::NonNegativeInteger(None) => None,
+			Self::NonNegativeInteger(Some(t)) => Some(Self::NonNegativeInteger(t.parent())),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl IntegerDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -551,6 +1558,15 @@ impl IntegerDatatype {
 	}
 }
 
+datatype_names!(IntegerDatatype {
+	Self::NonPositiveInteger(None) => "nonPositiveInteger",
+	Self::NonPositiveInteger(Some(t)) => (t),
+	Self::Long(None) => "long",
+	Self::Long(Some(t)) => (t),
+	Self::NonNegativeInteger(None) => "nonNegativeInteger",
+	Self::NonNegativeInteger(Some(t)) => (t)
+});
+
 impl_from!(IntegerDatatype {
 	ty: NonPositiveIntegerDatatype => Self::NonPositiveInteger(ty),
 	ty: LongDatatype => Self::Long(ty),
@@ -562,10 +1578,69 @@ impl_from!(IntegerDatatype {
 	ty: UnsignedShortDatatype => Self::NonNegativeInteger(ty.into())
 });
 
+impl IntegerDatatype {
+	/// Returns the smallest integer of this datatype value space, if it is
+	/// bounded from below.
+	pub fn min_inclusive_integer(&self) -> Option<Integer> {
+		match self {
+			Self::NonPositiveInteger(_) => None,
+			Self::Long(None) => Some(i64::MIN.into()),
+			Self::Long(Some(LongDatatype::Int(None))) => Some(i32::MIN.into()),
+			Self::Long(Some(LongDatatype::Int(Some(IntDatatype::Short(None))))) => {
+				Some(i16::MIN.into())
+			}
+			Self::Long(Some(LongDatatype::Int(Some(IntDatatype::Short(Some(
+				ShortDatatype::Byte,
+			)))))) => Some(i8::MIN.into()),
+			Self::NonNegativeInteger(Some(NonNegativeIntegerDatatype::PositiveInteger)) => {
+				Some(Integer::one())
+			}
+			Self::NonNegativeInteger(_) => Some(Integer::zero()),
+		}
+	}
+
+	/// Returns the largest integer of this datatype value space, if it is
+	/// bounded from above.
+	pub fn max_inclusive_integer(&self) -> Option<Integer> {
+		match self {
+			Self::NonPositiveInteger(None) => Some(Integer::zero()),
+			Self::NonPositiveInteger(Some(NonPositiveIntegerDatatype::NegativeInteger)) => {
+				Some(Integer::minus_one())
+			}
+			Self::Long(None) => Some(i64::MAX.into()),
+			Self::Long(Some(LongDatatype::Int(None))) => Some(i32::MAX.into()),
+			Self::Long(Some(LongDatatype::Int(Some(IntDatatype::Short(None))))) => {
+				Some(i16::MAX.into())
+			}
+			Self::Long(Some(LongDatatype::Int(Some(IntDatatype::Short(Some(
+				ShortDatatype::Byte,
+			)))))) => Some(i8::MAX.into()),
+			Self::NonNegativeInteger(None)
+			| Self::NonNegativeInteger(Some(NonNegativeIntegerDatatype::PositiveInteger)) => None,
+			Self::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(None))) => {
+				Some(u64::MAX.into())
+			}
+			Self::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(Some(
+				UnsignedLongDatatype::UnsignedInt(None),
+			)))) => Some(u32::MAX.into()),
+			Self::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(Some(
+				UnsignedLongDatatype::UnsignedInt(Some(UnsignedIntDatatype::UnsignedShort(None))),
+			)))) => Some(u16::MAX.into()),
+			Self::NonNegativeInteger(Some(NonNegativeIntegerDatatype::UnsignedLong(Some(
+				UnsignedLongDatatype::UnsignedInt(Some(UnsignedIntDatatype::UnsignedShort(Some(
+					UnsignedShortDatatype::UnsignedByte,
+				)))),
+			)))) => Some(u8::MAX.into()),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NonPositiveIntegerDatatype {
 	NegativeInteger,
 }
 
+#[cfg(feature = "std")]
 impl NonPositiveIntegerDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -582,10 +1657,27 @@ impl NonPositiveIntegerDatatype {
 	}
 }
 
+datatype_names!(NonPositiveIntegerDatatype {
+	Self::NegativeInteger => "negativeInteger"
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LongDatatype {
 	Int(Option<IntDatatype>),
 }
 
+impl LongDatatype {
+	/// Returns the parent datatype of `xsd:short` and `xsd:byte`, or `None`
+	/// for `xsd:int`, which is derived from `xsd:long`.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::Int(None) => None,
+			Self::Int(Some(t)) => Some(Self::Int(t.parent())),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl LongDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -604,15 +1696,33 @@ impl LongDatatype {
 	}
 }
 
+datatype_names!(LongDatatype {
+	Self::Int(None) => "int",
+	Self::Int(Some(t)) => (t)
+});
+
 impl_from!(LongDatatype {
 	ty: IntDatatype => Self::Int(ty),
 	ty: ShortDatatype => Self::Int(ty.into())
 });
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IntDatatype {
 	Short(Option<ShortDatatype>),
 }
 
+impl IntDatatype {
+	/// Returns `xsd:short` for `xsd:byte`, or `None` for `xsd:short`, which
+	/// is derived from `xsd:int`.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::Short(None) => None,
+			Self::Short(Some(_)) => Some(Self::Short(None)),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl IntDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -631,14 +1741,21 @@ impl IntDatatype {
 	}
 }
 
+datatype_names!(IntDatatype {
+	Self::Short(None) => "short",
+	Self::Short(Some(t)) => (t)
+});
+
 impl_from!(IntDatatype {
 	ty: ShortDatatype => Self::Short(ty)
 });
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ShortDatatype {
 	Byte,
 }
 
+#[cfg(feature = "std")]
 impl ShortDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -655,11 +1772,32 @@ impl ShortDatatype {
 	}
 }
 
+datatype_names!(ShortDatatype {
+	Self::Byte => "byte"
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NonNegativeIntegerDatatype {
 	UnsignedLong(Option<UnsignedLongDatatype>),
 	PositiveInteger,
 }
 
+impl NonNegativeIntegerDatatype {
+	/// Returns the parent datatype among the unsigned integer datatypes.
+	///
+	/// `xsd:unsignedLong` and `xsd:positiveInteger` are derived from
+	/// `xsd:nonNegativeInteger`, which is not a `NonNegativeIntegerDatatype`,
+	/// so `None` is returned for them.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::UnsignedLong(None) => None,
+			Self::UnsignedLong(Some(t)) => Some(Self::UnsignedLong(t.parent())),
+			Self::PositiveInteger => None,
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl NonNegativeIntegerDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -682,16 +1820,36 @@ impl NonNegativeIntegerDatatype {
 	}
 }
 
+datatype_names!(NonNegativeIntegerDatatype {
+	Self::UnsignedLong(None) => "unsignedLong",
+	Self::UnsignedLong(Some(t)) => (t),
+	Self::PositiveInteger => "positiveInteger"
+});
+
 impl_from!(NonNegativeIntegerDatatype {
 	ty: UnsignedLongDatatype => Self::UnsignedLong(ty),
 	ty: UnsignedIntDatatype => Self::UnsignedLong(ty.into()),
 	ty: UnsignedShortDatatype => Self::UnsignedLong(ty.into())
 });
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UnsignedLongDatatype {
 	UnsignedInt(Option<UnsignedIntDatatype>),
 }
 
+impl UnsignedLongDatatype {
+	/// Returns the parent datatype of `xsd:unsignedShort` and
+	/// `xsd:unsignedByte`, or `None` for `xsd:unsignedInt`, which is derived
+	/// from `xsd:unsignedLong`.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::UnsignedInt(None) => None,
+			Self::UnsignedInt(Some(t)) => Some(Self::UnsignedInt(t.parent())),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl UnsignedLongDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -710,15 +1868,33 @@ impl UnsignedLongDatatype {
 	}
 }
 
+datatype_names!(UnsignedLongDatatype {
+	Self::UnsignedInt(None) => "unsignedInt",
+	Self::UnsignedInt(Some(t)) => (t)
+});
+
 impl_from!(UnsignedLongDatatype {
 	ty: UnsignedIntDatatype => Self::UnsignedInt(ty),
 	ty: UnsignedShortDatatype => Self::UnsignedInt(ty.into())
 });
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UnsignedIntDatatype {
 	UnsignedShort(Option<UnsignedShortDatatype>),
 }
 
+impl UnsignedIntDatatype {
+	/// Returns `xsd:unsignedShort` for `xsd:unsignedByte`, or `None` for
+	/// `xsd:unsignedShort`, which is derived from `xsd:unsignedInt`.
+	fn parent(&self) -> Option<Self> {
+		match self {
+			Self::UnsignedShort(None) => None,
+			Self::UnsignedShort(Some(_)) => Some(Self::UnsignedShort(None)),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
 impl UnsignedIntDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -737,14 +1913,21 @@ impl UnsignedIntDatatype {
 	}
 }
 
+datatype_names!(UnsignedIntDatatype {
+	Self::UnsignedShort(None) => "unsignedShort",
+	Self::UnsignedShort(Some(t)) => (t)
+});
+
 impl_from!(UnsignedIntDatatype {
 	ty: UnsignedShortDatatype => Self::UnsignedShort(ty)
 });
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UnsignedShortDatatype {
 	UnsignedByte,
 }
 
+#[cfg(feature = "std")]
 impl UnsignedShortDatatype {
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -760,3 +1943,967 @@ impl UnsignedShortDatatype {
 		}
 	}
 }
+
+datatype_names!(UnsignedShortDatatype {
+	Self::UnsignedByte => "unsignedByte"
+});
+
+/// Built-in datatype derived by list.
+///
+/// See: <https://www.w3.org/TR/xmlschema-2/#list-datatypes>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListDatatype {
+	NMTokens,
+	IdRefs,
+	Entities,
+}
+
+impl ListDatatype {
+	const NMTOKEN: Datatype = Datatype::String(Some(StringDatatype::NormalizedString(Some(
+		NormalizedStringDatatype::Token(Some(TokenDatatype::NMToken)),
+	))));
+
+	const IDREF: Datatype = Datatype::String(Some(StringDatatype::NormalizedString(Some(
+		NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+			Some(NCNameDatatype::IdRef),
+		))))),
+	))));
+
+	const ENTITY: Datatype = Datatype::String(Some(StringDatatype::NormalizedString(Some(
+		NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+			Some(NCNameDatatype::Entity),
+		))))),
+	))));
+
+	/// Returns the datatype of the list items.
+	pub fn item_type(&self) -> &'static Datatype {
+		match self {
+			Self::NMTokens => &Self::NMTOKEN,
+			Self::IdRefs => &Self::IDREF,
+			Self::Entities => &Self::ENTITY,
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl ListDatatype {
+	pub fn iri(&self) -> &'static Iri {
+		match self {
+			Self::NMTokens => XSD_NMTOKENS,
+			Self::IdRefs => XSD_IDREFS,
+			Self::Entities => XSD_ENTITIES,
+		}
+	}
+
+	/// Parses a whitespace-separated list of items.
+	///
+	/// Built-in list datatypes have a `minLength` of 1, so the empty list
+	/// is rejected.
+	///
+	/// The resulting [`Value::List`] can be converted into the matching
+	/// [`NonEmptyList`] type, such as [`NMTokens`].
+	pub fn parse(&self, value: &str) -> Result<Value, ParseError> {
+		let item_type = self.item_type();
+		let items = value
+			.split_ascii_whitespace()
+			.map(|item| item_type.parse(item))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		if items.is_empty() {
+			Err(ParseError)
+		} else {
+			Ok(Value::List(ListValue::new_unchecked(*self, items)))
+		}
+	}
+}
+
+datatype_names!(ListDatatype {
+	Self::NMTokens => "NMTOKENS",
+	Self::IdRefs => "IDREFS",
+	Self::Entities => "ENTITIES"
+});
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[cfg(feature = "std")]
+	const XSD_IRIS: [&Iri; 44] = [
+		XSD_DURATION,
+		XSD_DATE_TIME,
+		XSD_TIME,
+		XSD_DATE,
+		XSD_G_YEAR_MONTH,
+		XSD_G_YEAR,
+		XSD_G_MONTH_DAY,
+		XSD_G_DAY,
+		XSD_G_MONTH,
+		XSD_STRING,
+		XSD_BOOLEAN,
+		XSD_BASE64_BINARY,
+		XSD_HEX_BINARY,
+		XSD_FLOAT,
+		XSD_DECIMAL,
+		XSD_DOUBLE,
+		XSD_ANY_URI,
+		XSD_Q_NAME,
+		XSD_NOTATION,
+		XSD_NORMALIZED_STRING,
+		XSD_TOKEN,
+		XSD_LANGUAGE,
+		XSD_NAME,
+		XSD_NMTOKEN,
+		XSD_NC_NAME,
+		XSD_NMTOKENS,
+		XSD_ID,
+		XSD_IDREF,
+		XSD_ENTITY,
+		XSD_IDREFS,
+		XSD_ENTITIES,
+		XSD_INTEGER,
+		XSD_NON_POSITIVE_INTEGER,
+		XSD_NEGATIVE_INTEGER,
+		XSD_LONG,
+		XSD_INT,
+		XSD_SHORT,
+		XSD_BYTE,
+		XSD_NON_NEGATIVE_INTEGER,
+		XSD_UNSIGNED_LONG,
+		XSD_UNSIGNED_INT,
+		XSD_UNSIGNED_SHORT,
+		XSD_UNSIGNED_BYTE,
+		XSD_POSITIVE_INTEGER,
+	];
+
+	#[test]
+	fn names_01() {
+		let t: Datatype = IntegerDatatype::NonNegativeInteger(None).into();
+		assert_eq!(t.local_name(), "nonNegativeInteger");
+		assert_eq!(t.xml_type_name(), "xs:nonNegativeInteger");
+		assert_eq!(t.xsd_prefix_name(), "xsd:nonNegativeInteger");
+		assert_eq!(Datatype::DateTime.xml_type_name(), "xs:dateTime");
+		assert_eq!(
+			Datatype::from(DecimalDatatype::Integer(None)).xml_type_name(),
+			"xs:integer"
+		);
+		assert_eq!(
+			Datatype::from(ShortDatatype::Byte).xsd_prefix_name(),
+			"xsd:byte"
+		);
+		assert_eq!(t.xsd_qualified_name(), "xs:nonNegativeInteger");
+		assert_eq!(t.xs_prefix_name(), "xs:nonNegativeInteger");
+		assert_eq!(ShortDatatype::Byte.xsd_qualified_name(), "xs:byte");
+		assert_eq!(
+			UnsignedIntDatatype::UnsignedShort(None).xs_prefix_name(),
+			"xs:unsignedShort"
+		);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn names_02() {
+		for iri in [
+			XSD_STRING,
+			XSD_TOKEN,
+			XSD_ID,
+			XSD_DECIMAL,
+			XSD_INTEGER,
+			XSD_UNSIGNED_BYTE,
+			XSD_NEGATIVE_INTEGER,
+			XSD_G_MONTH_DAY,
+			XSD_ANY_URI,
+		] {
+			let t = Datatype::from_iri(iri).unwrap();
+			assert_eq!(
+				iri.as_str(),
+				format!("http://www.w3.org/2001/XMLSchema#{}", t.local_name())
+			)
+		}
+	}
+
+	#[test]
+	fn bounds_01() {
+		let byte = Datatype::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+			Some(LongDatatype::Int(Some(IntDatatype::Short(Some(
+				ShortDatatype::Byte,
+			))))),
+		)))));
+		assert_eq!(byte.min_inclusive_integer(), Some(Integer::from(-128i64)));
+		assert_eq!(byte.max_inclusive_integer(), Some(Integer::from(127i64)));
+
+		let unsigned_byte: Datatype = UnsignedShortDatatype::UnsignedByte.into();
+		assert_eq!(unsigned_byte.min_inclusive_integer(), Some(Integer::zero()));
+		assert_eq!(
+			unsigned_byte.max_inclusive_integer(),
+			Some(Integer::from(255i64))
+		);
+
+		let negative: Datatype = NonPositiveIntegerDatatype::NegativeInteger.into();
+		assert_eq!(negative.min_inclusive_integer(), None);
+		assert_eq!(negative.max_inclusive_integer(), Some(Integer::minus_one()));
+
+		let integer: Datatype = DecimalDatatype::Integer(None).into();
+		assert_eq!(integer.min_inclusive_integer(), None);
+		assert_eq!(integer.max_inclusive_integer(), None);
+		assert_eq!(Datatype::Float.max_inclusive_integer(), None);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn bounds_02() {
+		let byte: Datatype = ShortDatatype::Byte.into();
+		assert_eq!(byte.min_value(), Some(Value::Byte(-128i8)));
+		assert_eq!(byte.max_value(), Some(Value::Byte(127i8)));
+
+		let unsigned_long: Datatype = NonNegativeIntegerDatatype::UnsignedLong(None).into();
+		assert_eq!(unsigned_long.min_value(), Some(Value::UnsignedLong(0)));
+		assert_eq!(
+			unsigned_long.max_value(),
+			Some(Value::UnsignedLong(u64::MAX))
+		);
+
+		let positive: Datatype = NonNegativeIntegerDatatype::PositiveInteger.into();
+		assert_eq!(
+			positive.min_value().map(|v| v.to_string()),
+			Some("1".to_owned())
+		);
+		assert_eq!(positive.max_value(), None);
+		assert_eq!(Datatype::Decimal(None).min_value(), None);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn categories_01() {
+		for iri in XSD_IRIS {
+			let t = Datatype::from_iri(iri).unwrap();
+			let categories = [
+				t.is_numeric(),
+				t.is_string_derived(),
+				t.is_date_or_time(),
+				t.is_binary(),
+			];
+			let count = categories.into_iter().filter(|c| *c).count();
+
+			// `xsd:boolean`, `xsd:anyURI`, `xsd:QName`, `xsd:NOTATION` and
+			// list datatypes belong to none of the categories.
+			let expected = match t {
+				Datatype::Boolean
+				| Datatype::AnyUri
+				| Datatype::QName
+				| Datatype::Notation
+				| Datatype::List(_) => 0,
+				_ => 1,
+			};
+
+			assert_eq!(count, expected, "{iri}")
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn parse_date_time_01() {
+		assert_eq!(
+			Datatype::Date
+				.parse("2002-10-10Z")
+				.ok()
+				.unwrap()
+				.to_string(),
+			"2002-10-10Z"
+		);
+		assert_eq!(
+			Datatype::Time
+				.parse("13:20:00-05:00")
+				.ok()
+				.unwrap()
+				.to_string(),
+			"13:20:00-05:00"
+		);
+		assert!(Datatype::Date.parse("2002-10-10T13:20:00").is_err())
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn parse_duration_01() {
+		for (input, expected) in [("PT1.S", "PT1S"), ("PT.5S", "PT0.5S"), ("-P1M", "-P1M")] {
+			assert_eq!(
+				Datatype::Duration.parse(input).ok().unwrap().to_string(),
+				expected
+			)
+		}
+
+		for input in ["P2W", "PT1,5S", "P"] {
+			assert!(Datatype::Duration.parse(input).is_err(), "{input}")
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn parse_q_name_01() {
+		assert_eq!(
+			Datatype::Notation
+				.parse("img:png")
+				.ok()
+				.unwrap()
+				.to_string(),
+			"img:png"
+		);
+		assert_eq!(
+			Datatype::QName.parse("foo").ok().unwrap().to_string(),
+			"foo"
+		);
+		assert!(Datatype::QName.parse("a:b:c").is_err())
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn parse_01() {
+		for (iri, input) in [
+			(XSD_ID, "foo"),
+			(XSD_LANGUAGE, "en-US"),
+			(XSD_NMTOKEN, "-12"),
+			(XSD_DURATION, "P1Y2M3DT4H5M6.5S"),
+			(XSD_G_YEAR_MONTH, "2002-10Z"),
+			(XSD_G_YEAR, "-0045"),
+			(XSD_G_MONTH_DAY, "--02-29"),
+			(XSD_G_DAY, "---01"),
+			(XSD_G_MONTH, "--12Z"),
+		] {
+			let t = Datatype::from_iri(iri).unwrap();
+			assert_eq!(t.parse(input).ok().unwrap().to_string(), input)
+		}
+
+		for (iri, input) in [
+			(XSD_ID, "a:b"),
+			(XSD_NMTOKEN, "a b"),
+			(XSD_DURATION, "P2W"),
+			(XSD_G_MONTH_DAY, "--02-30"),
+			(XSD_G_DAY, "---32"),
+			(XSD_G_MONTH, "--13"),
+		] {
+			let t = Datatype::from_iri(iri).unwrap();
+			assert!(t.parse(input).is_err(), "{input}")
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn parse_02() {
+		let positive: Datatype = NonNegativeIntegerDatatype::PositiveInteger.into();
+		let negative: Datatype = NonPositiveIntegerDatatype::NegativeInteger.into();
+		for input in ["7", "+7", "007", "+0010"] {
+			assert!(positive.parse(input).is_ok(), "{input}")
+		}
+
+		for input in ["-7", "-007", "-0010"] {
+			assert!(negative.parse(input).is_ok(), "{input}")
+		}
+
+		assert_eq!(positive.parse("+007").ok().unwrap().to_string(), "7");
+		assert!(positive.parse("000").is_err());
+		assert!(negative.parse("-000").is_err())
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn try_parse_value_01() {
+		let t = Datatype::from_iri(XSD_BYTE).unwrap();
+		let e = t.try_parse_value("128").unwrap_err();
+		assert_eq!(e.datatype, t);
+		assert_eq!(e.input, "128");
+		assert_eq!(
+			e.reason,
+			ParseErrorReason::OutOfRange {
+				min: Some("-128".to_owned()),
+				max: Some("127".to_owned())
+			}
+		);
+		assert_eq!(
+			e.to_string(),
+			"invalid `xs:byte` value `128`: value out of range [-128, 127]"
+		);
+
+		let e = Datatype::from_iri(XSD_NON_NEGATIVE_INTEGER)
+			.unwrap()
+			.try_parse_value("-1")
+			.unwrap_err();
+		assert_eq!(
+			e.reason,
+			ParseErrorReason::OutOfRange {
+				min: Some("0".to_owned()),
+				max: None
+			}
+		);
+		assert_eq!(e.reason.to_string(), "value out of range [0, INF]");
+
+		let e = Datatype::from_iri(XSD_POSITIVE_INTEGER)
+			.unwrap()
+			.try_parse_value("0")
+			.unwrap_err();
+		assert_eq!(
+			e.reason,
+			ParseErrorReason::OutOfRange {
+				min: Some("1".to_owned()),
+				max: None
+			}
+		);
+
+		let e = Datatype::from_iri(XSD_NEGATIVE_INTEGER)
+			.unwrap()
+			.try_parse_value("0")
+			.unwrap_err();
+		assert_eq!(e.reason.to_string(), "value out of range [-INF, -1]");
+
+		assert!(matches!(
+			t.try_parse_value("1.5").unwrap_err().reason,
+			ParseErrorReason::InvalidLexicalForm(_)
+		));
+
+		assert_eq!(
+			Datatype::Date
+				.try_parse_value("2023-02-30")
+				.unwrap_err()
+				.reason,
+			ParseErrorReason::InvalidComponent {
+				component: "day",
+				value: "30".to_owned()
+			}
+		);
+
+		assert_eq!(
+			Datatype::Boolean
+				.try_parse_value("1")
+				.ok()
+				.unwrap()
+				.to_string(),
+			"true"
+		)
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn parse_error_01() {
+		fn parse_boolean(s: &str) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
+			Ok(Datatype::Boolean.parse(s)?)
+		}
+
+		assert!(parse_boolean("true").is_ok());
+		assert_eq!(
+			parse_boolean("yes").unwrap_err().to_string(),
+			"XSD parse error"
+		)
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn from_str_iri_01() {
+		const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+		let expected_datatypes = [
+			("duration", Datatype::Duration),
+			("dateTime", Datatype::DateTime),
+			("time", Datatype::Time),
+			("date", Datatype::Date),
+			("gYearMonth", Datatype::GYearMonth),
+			("gYear", Datatype::GYear),
+			("gMonthDay", Datatype::GMonthDay),
+			("gDay", Datatype::GDay),
+			("gMonth", Datatype::GMonth),
+			("string", Datatype::String(None)),
+			("boolean", Datatype::Boolean),
+			("base64Binary", Datatype::Base64Binary),
+			("hexBinary", Datatype::HexBinary),
+			("float", Datatype::Float),
+			("decimal", Datatype::Decimal(None)),
+			("double", Datatype::Double),
+			("anyURI", Datatype::AnyUri),
+			("QName", Datatype::QName),
+			("NOTATION", Datatype::Notation),
+			(
+				"normalizedString",
+				Datatype::String(Some(StringDatatype::NormalizedString(None))),
+			),
+			(
+				"token",
+				Datatype::String(Some(StringDatatype::NormalizedString(Some(
+					NormalizedStringDatatype::Token(None),
+				)))),
+			),
+			(
+				"language",
+				Datatype::String(Some(StringDatatype::NormalizedString(Some(
+					NormalizedStringDatatype::Token(Some(TokenDatatype::Language)),
+				)))),
+			),
+			(
+				"Name",
+				Datatype::String(Some(StringDatatype::NormalizedString(Some(
+					NormalizedStringDatatype::Token(Some(TokenDatatype::Name(None))),
+				)))),
+			),
+			(
+				"NMTOKEN",
+				Datatype::String(Some(StringDatatype::NormalizedString(Some(
+					NormalizedStringDatatype::Token(Some(TokenDatatype::NMToken)),
+				)))),
+			),
+			(
+				"NCName",
+				Datatype::String(Some(StringDatatype::NormalizedString(Some(
+					NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(
+						NameDatatype::NCName(None),
+					)))),
+				)))),
+			),
+			("NMTOKENS", Datatype::List(ListDatatype::NMTokens)),
+			(
+				"ID",
+				Datatype::String(Some(StringDatatype::NormalizedString(Some(
+					NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(
+						NameDatatype::NCName(Some(NCNameDatatype::Id)),
+					)))),
+				)))),
+			),
+			(
+				"IDREF",
+				Datatype::String(Some(StringDatatype::NormalizedString(Some(
+					NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(
+						NameDatatype::NCName(Some(NCNameDatatype::IdRef)),
+					)))),
+				)))),
+			),
+			(
+				"ENTITY",
+				Datatype::String(Some(StringDatatype::NormalizedString(Some(
+					NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(
+						NameDatatype::NCName(Some(NCNameDatatype::Entity)),
+					)))),
+				)))),
+			),
+			("IDREFS", Datatype::List(ListDatatype::IdRefs)),
+			("ENTITIES", Datatype::List(ListDatatype::Entities)),
+			(
+				"integer",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(None))),
+			),
+			(
+				"nonPositiveInteger",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+					IntegerDatatype::NonPositiveInteger(None),
+				)))),
+			),
+			(
+				"negativeInteger",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+					IntegerDatatype::NonPositiveInteger(Some(
+						NonPositiveIntegerDatatype::NegativeInteger,
+					)),
+				)))),
+			),
+			(
+				"long",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+					None,
+				))))),
+			),
+			(
+				"int",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+					Some(LongDatatype::Int(None)),
+				))))),
+			),
+			(
+				"short",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+					Some(LongDatatype::Int(Some(IntDatatype::Short(None)))),
+				))))),
+			),
+			(
+				"byte",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(IntegerDatatype::Long(
+					Some(LongDatatype::Int(Some(IntDatatype::Short(Some(
+						ShortDatatype::Byte,
+					))))),
+				))))),
+			),
+			(
+				"nonNegativeInteger",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+					IntegerDatatype::NonNegativeInteger(None),
+				)))),
+			),
+			(
+				"unsignedLong",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+					IntegerDatatype::NonNegativeInteger(Some(
+						NonNegativeIntegerDatatype::UnsignedLong(None),
+					)),
+				)))),
+			),
+			(
+				"unsignedInt",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+					IntegerDatatype::NonNegativeInteger(Some(
+						NonNegativeIntegerDatatype::UnsignedLong(Some(
+							UnsignedLongDatatype::UnsignedInt(None),
+						)),
+					)),
+				)))),
+			),
+			(
+				"unsignedShort",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+					IntegerDatatype::NonNegativeInteger(Some(
+						NonNegativeIntegerDatatype::UnsignedLong(Some(
+							UnsignedLongDatatype::UnsignedInt(Some(
+								UnsignedIntDatatype::UnsignedShort(None),
+							)),
+						)),
+					)),
+				)))),
+			),
+			(
+				"unsignedByte",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+					IntegerDatatype::NonNegativeInteger(Some(
+						NonNegativeIntegerDatatype::UnsignedLong(Some(
+							UnsignedLongDatatype::UnsignedInt(Some(
+								UnsignedIntDatatype::UnsignedShort(Some(
+									UnsignedShortDatatype::UnsignedByte,
+								)),
+							)),
+						)),
+					)),
+				)))),
+			),
+			(
+				"positiveInteger",
+				Datatype::Decimal(Some(DecimalDatatype::Integer(Some(
+					IntegerDatatype::NonNegativeInteger(Some(
+						NonNegativeIntegerDatatype::PositiveInteger,
+					)),
+				)))),
+			),
+		];
+
+		assert_eq!(expected_datatypes.len(), Datatype::all().len());
+		for (name, expected) in expected_datatypes {
+			let iri = format!("{XSD}{name}");
+			assert_eq!(Datatype::from_str_iri(&iri), Some(expected), "{iri}");
+			assert_eq!(Datatype::try_from(iri.as_str()).ok(), Some(expected));
+			assert_eq!(Datatype::try_from(iri.clone()).ok(), Some(expected));
+			assert_eq!(iri.parse::<Datatype>().ok(), Some(expected));
+			assert_eq!(expected.iri().as_str(), iri)
+		}
+
+		assert_eq!(
+			"http://www.w3.org/2001/XMLSchema#integer"
+				.parse::<Datatype>()
+				.unwrap(),
+			Datatype::Decimal(Some(DecimalDatatype::Integer(None)))
+		);
+		assert!("http://www.w3.org/2001/XMLSchema#foo"
+			.parse::<Datatype>()
+			.is_err());
+		assert!("not an iri".parse::<Datatype>().is_err());
+		assert_eq!(Datatype::from_str_iri("xsd:integer"), None)
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn common_ancestor_01() {
+		const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+		for (a, b, expected) in [
+			("byte", "short", Some("short")),
+			("byte", "unsignedByte", Some("integer")),
+			("byte", "byte", Some("byte")),
+			("int", "long", Some("long")),
+			("byte", "negativeInteger", Some("integer")),
+			(
+				"negativeInteger",
+				"nonPositiveInteger",
+				Some("nonPositiveInteger"),
+			),
+			(
+				"positiveInteger",
+				"unsignedByte",
+				Some("nonNegativeInteger"),
+			),
+			("unsignedShort", "unsignedLong", Some("unsignedLong")),
+			("unsignedInt", "positiveInteger", Some("nonNegativeInteger")),
+			("integer", "decimal", Some("decimal")),
+			("unsignedByte", "decimal", Some("decimal")),
+			("string", "integer", None),
+			("float", "double", None),
+			("decimal", "float", None),
+			("token", "normalizedString", Some("normalizedString")),
+			("language", "NMTOKEN", Some("token")),
+			("ID", "IDREF", Some("NCName")),
+			("ENTITY", "Name", Some("Name")),
+			("ID", "string", Some("string")),
+			("date", "dateTime", None),
+		] {
+			let a = Datatype::from_str_iri(&format!("{XSD}{a}")).unwrap();
+			let b = Datatype::from_str_iri(&format!("{XSD}{b}")).unwrap();
+			let expected = expected.map(|e| Datatype::from_str_iri(&format!("{XSD}{e}")).unwrap());
+			assert_eq!(a.common_ancestor(&b), expected, "{a:?} {b:?}");
+			assert_eq!(b.common_ancestor(&a), expected, "{b:?} {a:?}")
+		}
+	}
+
+	#[test]
+	fn parent_01() {
+		let mut t: Datatype = ShortDatatype::Byte.into();
+		let mut names = alloc::vec::Vec::new();
+		while let Some(parent) = t.parent() {
+			names.push(parent.local_name());
+			t = parent
+		}
+
+		assert_eq!(names, ["short", "int", "long", "integer", "decimal"])
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn display_01() {
+		for iri in XSD_IRIS {
+			let t = Datatype::from_iri(iri).unwrap();
+			let (_, local_name) = iri.as_str().split_once('#').unwrap();
+			assert_eq!(t.to_string(), format!("xsd:{local_name}"));
+			assert_eq!(format!("{t:?}"), format!("<{iri}>"))
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn display_02() {
+		assert_eq!(
+			StringDatatype::NormalizedString(None).to_string(),
+			"xsd:normalizedString"
+		);
+		assert_eq!(TokenDatatype::NMToken.to_string(), "xsd:NMTOKEN");
+		assert_eq!(NCNameDatatype::IdRef.to_string(), "xsd:IDREF");
+		assert_eq!(DecimalDatatype::Integer(None).to_string(), "xsd:integer");
+		assert_eq!(
+			IntegerDatatype::NonNegativeInteger(Some(NonNegativeIntegerDatatype::PositiveInteger))
+				.to_string(),
+			"xsd:positiveInteger"
+		);
+		assert_eq!(ShortDatatype::Byte.to_string(), "xsd:byte");
+		assert_eq!(
+			UnsignedShortDatatype::UnsignedByte.to_string(),
+			"xsd:unsignedByte"
+		);
+		assert_eq!(
+			format!("value has type {}", Datatype::Boolean),
+			"value has type xsd:boolean"
+		)
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn list_type_01() {
+		for (iri, item_iri) in [
+			(XSD_NMTOKENS, XSD_NMTOKEN),
+			(XSD_IDREFS, XSD_IDREF),
+			(XSD_ENTITIES, XSD_ENTITY),
+		] {
+			let t = Datatype::from_iri(iri).unwrap();
+			assert!(t.is_list_type());
+			assert!(!t.is_atomic_type());
+			assert!(!t.is_union_type());
+			assert_eq!(t.iri(), iri);
+			assert_eq!(t.as_list_type().unwrap().item_type().iri(), item_iri);
+			assert_eq!(t.item_type().unwrap().iri(), item_iri)
+		}
+
+		let t = Datatype::from_iri(XSD_NMTOKEN).unwrap();
+		assert!(!t.is_list_type());
+		assert!(t.is_atomic_type());
+		assert!(!t.is_union_type());
+		assert_eq!(t.as_list_type(), None);
+		assert_eq!(t.item_type(), None)
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn parse_list_01() {
+		let t = Datatype::List(ListDatatype::NMTokens);
+		let value = t.parse(" a  b:c\tdef ").ok().unwrap();
+		assert_eq!(value.as_list().unwrap().len(), 3);
+		assert_eq!(value.to_string(), "a b:c def");
+		assert!(t.parse("").is_err());
+		assert!(t.parse("a ^").is_err());
+		assert!(Datatype::List(ListDatatype::IdRefs).parse("a b:c").is_err());
+		assert!(Datatype::List(ListDatatype::Entities).parse("a b").is_ok())
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_01() {
+		for iri in XSD_IRIS {
+			let t = Datatype::from_iri(iri).unwrap();
+			let json = serde_json::to_string(&t).unwrap();
+			assert_eq!(json, format!("\"{iri}\""));
+			assert_eq!(serde_json::from_str::<Datatype>(&json).unwrap(), t)
+		}
+
+		assert!(serde_json::from_str::<Datatype>("\"http://example.com/\"").is_err());
+		assert!(serde_json::from_str::<Datatype>("42").is_err())
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_02() {
+		fn assert_iri<T>(t: T, iri: &Iri)
+		where
+			T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + core::fmt::Debug,
+		{
+			let json = serde_json::to_string(&t).unwrap();
+			assert_eq!(json, format!("\"{iri}\""));
+			assert_eq!(serde_json::from_str::<T>(&json).unwrap(), t)
+		}
+
+		assert_iri(
+			StringDatatype::NormalizedString(None),
+			XSD_NORMALIZED_STRING,
+		);
+		assert_iri(NormalizedStringDatatype::Token(None), XSD_TOKEN);
+		assert_iri(TokenDatatype::Language, XSD_LANGUAGE);
+		assert_iri(TokenDatatype::NMToken, XSD_NMTOKEN);
+		assert_iri(TokenDatatype::Name(None), XSD_NAME);
+		assert_iri(NameDatatype::NCName(None), XSD_NC_NAME);
+		assert_iri(NCNameDatatype::Id, XSD_ID);
+		assert_iri(NCNameDatatype::IdRef, XSD_IDREF);
+		assert_iri(NCNameDatatype::Entity, XSD_ENTITY);
+		assert_iri(DecimalDatatype::Integer(None), XSD_INTEGER);
+		assert_iri(
+			IntegerDatatype::NonPositiveInteger(None),
+			XSD_NON_POSITIVE_INTEGER,
+		);
+		assert_iri(IntegerDatatype::Long(None), XSD_LONG);
+		assert_iri(
+			IntegerDatatype::NonNegativeInteger(None),
+			XSD_NON_NEGATIVE_INTEGER,
+		);
+		assert_iri(
+			NonPositiveIntegerDatatype::NegativeInteger,
+			XSD_NEGATIVE_INTEGER,
+		);
+		assert_iri(LongDatatype::Int(None), XSD_INT);
+		assert_iri(IntDatatype::Short(None), XSD_SHORT);
+		assert_iri(ShortDatatype::Byte, XSD_BYTE);
+		assert_iri(
+			NonNegativeIntegerDatatype::UnsignedLong(None),
+			XSD_UNSIGNED_LONG,
+		);
+		assert_iri(
+			NonNegativeIntegerDatatype::PositiveInteger,
+			XSD_POSITIVE_INTEGER,
+		);
+		assert_iri(UnsignedLongDatatype::UnsignedInt(None), XSD_UNSIGNED_INT);
+		assert_iri(UnsignedIntDatatype::UnsignedShort(None), XSD_UNSIGNED_SHORT);
+		assert_iri(UnsignedShortDatatype::UnsignedByte, XSD_UNSIGNED_BYTE);
+		assert_iri(ListDatatype::NMTokens, XSD_NMTOKENS);
+		assert_iri(ListDatatype::IdRefs, XSD_IDREFS);
+		assert_iri(ListDatatype::Entities, XSD_ENTITIES);
+
+		assert!(serde_json::from_str::<IntegerDatatype>(&format!("\"{XSD_STRING}\"")).is_err());
+		assert!(serde_json::from_str::<IntegerDatatype>(&format!("\"{XSD_INTEGER}\"")).is_err());
+		assert!(serde_json::from_str::<ListDatatype>(&format!("\"{XSD_NMTOKEN}\"")).is_err())
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_03() {
+		fn roundtrip<T>(json: &str) -> usize
+		where
+			T: serde::Serialize + serde::de::DeserializeOwned,
+		{
+			match serde_json::from_str::<T>(json) {
+				Ok(t) => {
+					assert_eq!(serde_json::to_string(&t).unwrap(), json);
+					1
+				}
+				Err(_) => 0,
+			}
+		}
+
+		// Every datatype but the primitive ones is a variant of (at least)
+		// one sub-datatype enum.
+		for t in Datatype::all() {
+			let json = serde_json::to_string(t).unwrap();
+			let count = roundtrip::<StringDatatype>(&json)
+				+ roundtrip::<NormalizedStringDatatype>(&json)
+				+ roundtrip::<TokenDatatype>(&json)
+				+ roundtrip::<NameDatatype>(&json)
+				+ roundtrip::<NCNameDatatype>(&json)
+				+ roundtrip::<DecimalDatatype>(&json)
+				+ roundtrip::<IntegerDatatype>(&json)
+				+ roundtrip::<NonPositiveIntegerDatatype>(&json)
+				+ roundtrip::<LongDatatype>(&json)
+				+ roundtrip::<IntDatatype>(&json)
+				+ roundtrip::<ShortDatatype>(&json)
+				+ roundtrip::<NonNegativeIntegerDatatype>(&json)
+				+ roundtrip::<UnsignedLongDatatype>(&json)
+				+ roundtrip::<UnsignedIntDatatype>(&json)
+				+ roundtrip::<UnsignedShortDatatype>(&json)
+				+ roundtrip::<ListDatatype>(&json);
+			assert_eq!(count > 0, !Datatype::primitives().contains(t), "{json}")
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn all_01() {
+		assert_eq!(Datatype::all().len(), XSD_IRIS.len());
+		let iris: std::collections::HashSet<_> =
+			Datatype::all().iter().map(Datatype::iri).collect();
+		assert_eq!(iris.len(), XSD_IRIS.len());
+
+		for iri in XSD_IRIS {
+			assert!(
+				Datatype::all().contains(&Datatype::from_iri(iri).unwrap()),
+				"{iri}"
+			)
+		}
+	}
+
+	#[test]
+	fn primitives_01() {
+		assert_eq!(Datatype::primitives().len(), 19);
+		for t in Datatype::primitives() {
+			assert!(Datatype::all().contains(t));
+			assert_eq!(t.parent(), None);
+			assert!(t.is_atomic_type())
+		}
+
+		let count = Datatype::all()
+			.iter()
+			.filter(|t| t.is_atomic_type() && t.parent().is_none())
+			.count();
+		assert_eq!(count, 19)
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn try_from_iri_01() {
+		for t in Datatype::all() {
+			let iri: &'static Iri = (*t).into();
+			assert_eq!(Datatype::try_from(iri).unwrap(), *t);
+			let iri: IriBuf = (*t).into();
+			assert_eq!(Datatype::try_from(iri.as_str()).unwrap(), *t);
+			assert_eq!(Datatype::try_from(iri).unwrap(), *t)
+		}
+
+		for iri in [
+			"http://www.w3.org/2001/XMLSchema#anySimpleType",
+			"http://www.w3.org/2001/XMLSchema#Integer",
+			"http://www.w3.org/2001/XMLSchema",
+			"http://example.com/#string",
+		] {
+			let e = Datatype::try_from(Iri::new(iri).unwrap()).unwrap_err();
+			assert_eq!(e.0, iri);
+			let e = Datatype::try_from(IriBuf::new(iri.to_owned()).unwrap()).unwrap_err();
+			assert_eq!(e.to_string(), format!("unknown datatype `{iri}`"))
+		}
+	}
+}
@@ -1,47 +1,100 @@
+#[cfg(feature = "std")]
 mod any_uri;
+#[cfg(feature = "std")]
 pub mod base64_binary;
+#[cfg(feature = "std")]
 mod boolean;
+#[cfg(feature = "std")]
 mod date;
+#[cfg(feature = "std")]
 mod date_time;
 mod decimal;
+#[cfg(feature = "std")]
 mod double;
+#[cfg(feature = "std")]
 mod duration;
+#[cfg(feature = "std")]
 mod float;
+#[cfg(feature = "std")]
 mod g_day;
+#[cfg(feature = "std")]
 mod g_month;
+#[cfg(feature = "std")]
 mod g_month_day;
+#[cfg(feature = "std")]
 mod g_year;
+#[cfg(feature = "std")]
 mod g_year_month;
+#[cfg(feature = "std")]
 pub mod hex_binary;
+#[cfg(feature = "std")]
+mod list;
+#[cfg(feature = "std")]
 mod notation;
+#[cfg(feature = "std")]
 mod q_name;
+#[cfg(feature = "std")]
 mod string;
+#[cfg(feature = "std")]
 mod time;
+#[cfg(feature = "std")]
+mod turtle;
 
-use std::fmt;
+#[cfg(feature = "std")]
+use num_bigint::BigInt;
+#[cfg(feature = "std")]
+use std::{
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+};
 
+#[cfg(feature = "std")]
 pub use any_uri::*;
+#[cfg(feature = "std")]
 pub use base64_binary::{Base64Binary, Base64BinaryBuf, InvalidBase64};
+#[cfg(feature = "std")]
 pub use boolean::*;
+#[cfg(feature = "std")]
 pub use date::*;
+#[cfg(feature = "std")]
 pub use date_time::*;
 pub use decimal::*;
+#[cfg(feature = "std")]
 pub use double::*;
+#[cfg(feature = "std")]
 pub use duration::*;
+#[cfg(feature = "std")]
 pub use float::*;
+#[cfg(feature = "std")]
 pub use g_day::*;
+#[cfg(feature = "std")]
 pub use g_month::*;
+#[cfg(feature = "std")]
 pub use g_month_day::*;
+#[cfg(feature = "std")]
 pub use g_year::*;
+#[cfg(feature = "std")]
 pub use g_year_month::*;
+#[cfg(feature = "std")]
 pub use hex_binary::{HexBinary, HexBinaryBuf, InvalidHex};
+#[cfg(feature = "std")]
+pub use list::*;
+#[cfg(feature = "std")]
 pub use notation::*;
+#[cfg(feature = "std")]
 pub use q_name::*;
+#[cfg(feature = "std")]
 pub use string::*;
+#[cfg(feature = "std")]
 pub use time::*;
+#[cfg(feature = "std")]
+pub use turtle::*;
 
+use crate::Datatype;
+#[cfg(feature = "std")]
 use crate::{
-	Datatype, DecimalDatatype, IntDatatype, IntegerDatatype, LongDatatype,
+	DecimalDatatype, IntDatatype, IntegerDatatype, ListDatatype, LongDatatype,
 	NonNegativeIntegerDatatype, NonPositiveIntegerDatatype, ShortDatatype, UnsignedIntDatatype,
 	UnsignedLongDatatype, UnsignedShortDatatype,
 };
@@ -49,8 +102,38 @@ use crate::{
 pub trait XsdDatatype {
 	/// Returns the XSD datatype that best describes the value.
 	fn type_(&self) -> Datatype;
+
+	/// Returns the IRI of the XSD datatype that best describes the value.
+	#[cfg(feature = "std")]
+	#[inline(always)]
+	fn type_iri(&self) -> &'static iref::Iri {
+		self.type_().iri()
+	}
+
+	/// Returns the local name of the XSD datatype that best describes the
+	/// value (e.g. `"integer"`).
+	#[inline(always)]
+	fn type_local_name(&self) -> &'static str {
+		self.type_().local_name()
+	}
+
+	/// Checks if the datatype of the value is `datatype` or is derived from
+	/// it.
+	///
+	/// For instance an `xsd:byte` value is an instance of `xsd:decimal`.
+	#[inline(always)]
+	fn is_instance_of(&self, datatype: &Datatype) -> bool {
+		self.type_().is_derived_from(datatype)
+	}
+
+	/// Checks if the datatype of the value is exactly `datatype`.
+	#[inline(always)]
+	fn is_exactly(&self, datatype: &Datatype) -> bool {
+		self.type_() == *datatype
+	}
 }
 
+#[cfg(feature = "std")]
 /// XSD datatype value.
 #[derive(Debug, Clone)]
 pub enum Value {
@@ -86,10 +169,12 @@ pub enum Value {
 	AnyUri(AnyUriBuf),
 	QName(QName),
 	Notation(Notation),
+	List(ListValue),
 }
 
+#[cfg(feature = "std")]
 impl Value {
-	pub fn as_value_ref(&self) -> ValueRef {
+	pub fn as_value_ref(&self) -> ValueRef<'_> {
 		match self {
 			Self::String(v) => ValueRef::String(v),
 			Self::Boolean(v) => ValueRef::Boolean(*v),
@@ -123,10 +208,78 @@ impl Value {
 			Self::AnyUri(v) => ValueRef::AnyUri(v),
 			Self::QName(v) => ValueRef::QName(v),
 			Self::Notation(v) => ValueRef::Notation(v),
+			Self::List(v) => ValueRef::List(v),
+		}
+	}
+
+	/// Returns the canonical lexical form of this value.
+	///
+	/// Unlike the `Display` implementation, decimal values are not printed
+	/// using the lexical form they were parsed from.
+	pub fn canonical_lexical_form(&self) -> String {
+		match self {
+			Self::Decimal(d) => decimal_lexical_representation(d.as_big_rational())
+				.unwrap()
+				.into_string(),
+			Self::List(l) => l
+				.iter()
+				.map(Self::canonical_lexical_form)
+				.collect::<Vec<_>>()
+				.join(" "),
+			other => other.to_string(),
+		}
+	}
+
+	/// Builds a list value from the given items, checking that they all
+	/// conform to the item datatype of `datatype`.
+	pub fn from_list(items: Vec<Value>, datatype: ListDatatype) -> Result<Self, ListTypeError> {
+		ListValue::new(datatype, items).map(Self::List)
+	}
+
+	/// Checks if this is a list value.
+	pub fn is_list(&self) -> bool {
+		matches!(self, Self::List(_))
+	}
+
+	/// Returns the items of this value, if it is a list.
+	pub fn as_list(&self) -> Option<&[Value]> {
+		match self {
+			Self::List(l) => Some(l.as_slice()),
+			_ => None,
+		}
+	}
+
+	/// Turns this value into a list of values.
+	///
+	/// If this is not a list value, a single item list is returned.
+	pub fn into_list(self) -> Vec<Value> {
+		match self {
+			Self::List(l) => l.into_vec(),
+			other => vec![other],
+		}
+	}
+
+	/// Converts any numeric value (decimal, integer, float or double) into a
+	/// decimal, allowing comparisons between values of different numeric
+	/// types.
+	///
+	/// Returns `None` if the value is not numeric, or is a NaN or infinite
+	/// float/double.
+	///
+	/// Float and double values are converted to the exact decimal value of
+	/// their binary representation, which may differ from their lexical
+	/// form: for instance `0.1` as a float is not equal to `0.1` as a
+	/// decimal.
+	pub fn to_comparable_decimal(&self) -> Option<Decimal> {
+		match self {
+			Self::Float(f) => Decimal::try_from(*f).ok(),
+			Self::Double(d) => Decimal::try_from(*d).ok(),
+			other => Decimal::try_from(other.clone()).ok(),
 		}
 	}
 }
 
+#[cfg(feature = "std")]
 impl XsdDatatype for Value {
 	fn type_(&self) -> Datatype {
 		match self {
@@ -212,10 +365,12 @@ impl XsdDatatype for Value {
 			Self::AnyUri(_) => Datatype::AnyUri,
 			Self::QName(_) => Datatype::QName,
 			Self::Notation(_) => Datatype::Notation,
+			Self::List(v) => v.type_(),
 		}
 	}
 }
 
+#[cfg(feature = "std")]
 impl fmt::Display for Value {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
@@ -251,16 +406,245 @@ impl fmt::Display for Value {
 			Self::AnyUri(v) => v.fmt(f),
 			Self::QName(v) => v.fmt(f),
 			Self::Notation(v) => v.fmt(f),
+			Self::List(v) => v.fmt(f),
 		}
 	}
 }
 
+/// Values are compared by datatype and canonical lexical form, consistently
+/// with the `Hash` implementation.
+#[cfg(feature = "std")]
+impl PartialEq for Value {
+	fn eq(&self, other: &Self) -> bool {
+		self.type_().iri() == other.type_().iri()
+			&& self.canonical_lexical_form() == other.canonical_lexical_form()
+	}
+}
+
+#[cfg(feature = "std")]
+impl Eq for Value {}
+
+/// Hashes the datatype IRI and the canonical lexical form of the value.
+#[cfg(feature = "std")]
+impl Hash for Value {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.type_().iri().hash(state);
+		self.canonical_lexical_form().hash(state)
+	}
+}
+
+#[cfg(feature = "std")]
 impl From<Value> for std::string::String {
 	fn from(value: Value) -> Self {
 		value.to_string()
 	}
 }
 
+/// Error returned when extracting a concrete value from a [`Value`] of an
+/// incompatible datatype.
+#[cfg(feature = "std")]
+#[derive(Debug, thiserror::Error)]
+#[error("unexpected value of type `{}`", .actual.iri())]
+pub struct WrongType {
+	/// Datatype of the value.
+	pub actual: Datatype,
+}
+
+#[cfg(feature = "std")]
+impl WrongType {
+	fn of(value: &Value) -> Self {
+		Self {
+			actual: value.type_(),
+		}
+	}
+}
+
+/// Error returned by [`xsd_compare_numeric`] when one of the compared values
+/// is not numeric.
+#[cfg(feature = "std")]
+#[derive(Debug, thiserror::Error)]
+#[error("cannot compare values of type `{}` and `{}`", .left.iri(), .right.iri())]
+pub struct IncomparableTypes {
+	/// Datatype of the left-hand value.
+	pub left: Datatype,
+
+	/// Datatype of the right-hand value.
+	pub right: Datatype,
+}
+
+/// Position of a numeric value on the extended real line.
+#[cfg(feature = "std")]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum NumericKey {
+	NegativeInfinity,
+	Finite(Decimal),
+	PositiveInfinity,
+}
+
+#[cfg(feature = "std")]
+impl NumericKey {
+	/// Returns the key of the given numeric value, or `None` if it is NaN.
+	fn of(value: &Value) -> Option<Self> {
+		let f = match value {
+			Value::Float(f) => f.into_f32() as f64,
+			Value::Double(d) => d.into_f64(),
+			other => return Some(Self::Finite(other.to_comparable_decimal().unwrap())),
+		};
+
+		if f.is_nan() {
+			None
+		} else if f == f64::INFINITY {
+			Some(Self::PositiveInfinity)
+		} else if f == f64::NEG_INFINITY {
+			Some(Self::NegativeInfinity)
+		} else {
+			Some(Self::Finite(value.to_comparable_decimal().unwrap()))
+		}
+	}
+}
+
+/// Compares two numeric values of any numeric datatype (decimal, integer
+/// and its sub-types, float and double).
+///
+/// Values are compared by their mathematical value, using the exact decimal
+/// value of floats and doubles (see [`Value::to_comparable_decimal`]).
+/// Infinities are smaller or greater than any finite number.
+///
+/// Returns `Ok(None)` if either value is NaN, and an [`IncomparableTypes`]
+/// error if either value is not numeric.
+#[cfg(feature = "std")]
+pub fn xsd_compare_numeric(a: &Value, b: &Value) -> Result<Option<Ordering>, IncomparableTypes> {
+	let is_numeric = |v: &Value| !v.is_list() && v.type_().is_numeric();
+	if is_numeric(a) && is_numeric(b) {
+		match (NumericKey::of(a), NumericKey::of(b)) {
+			(Some(a), Some(b)) => Ok(Some(a.cmp(&b))),
+			_ => Ok(None),
+		}
+	} else {
+		Err(IncomparableTypes {
+			left: a.type_(),
+			right: b.type_(),
+		})
+	}
+}
+
+/// Extracts any value of the `xsd:integer` family.
+#[cfg(feature = "std")]
+impl TryFrom<Value> for Integer {
+	type Error = WrongType;
+
+	fn try_from(value: Value) -> Result<Self, Self::Error> {
+		match value {
+			Value::Integer(v) => Ok(v),
+			Value::NonPositiveInteger(v) => Ok(BigInt::from(v).into()),
+			Value::NegativeInteger(v) => Ok(BigInt::from(v).into()),
+			Value::Long(v) => Ok(v.into()),
+			Value::Int(v) => Ok(v.into()),
+			Value::Short(v) => Ok(v.into()),
+			Value::Byte(v) => Ok(v.into()),
+			other => NonNegativeInteger::try_from(other).map(Into::into),
+		}
+	}
+}
+
+/// Extracts any value of the `xsd:nonNegativeInteger` family.
+#[cfg(feature = "std")]
+impl TryFrom<Value> for NonNegativeInteger {
+	type Error = WrongType;
+
+	fn try_from(value: Value) -> Result<Self, Self::Error> {
+		match value {
+			Value::NonNegativeInteger(v) => Ok(v),
+			Value::UnsignedLong(v) => Ok(v.into()),
+			Value::UnsignedInt(v) => Ok(v.into()),
+			Value::UnsignedShort(v) => Ok(v.into()),
+			Value::UnsignedByte(v) => Ok(v.into()),
+			Value::PositiveInteger(v) => Ok(unsafe {
+				// SAFETY: positive integers are non negative.
+				NonNegativeInteger::new_unchecked(v.into())
+			}),
+			other => Err(WrongType::of(&other)),
+		}
+	}
+}
+
+/// Extracts any value of the `xsd:decimal` family, including integers.
+#[cfg(feature = "std")]
+impl TryFrom<Value> for Decimal {
+	type Error = WrongType;
+
+	fn try_from(value: Value) -> Result<Self, Self::Error> {
+		match value {
+			Value::Decimal(v) => Ok(v),
+			other => Integer::try_from(other).map(Into::into),
+		}
+	}
+}
+
+macro_rules! try_from_value {
+	($($variant:ident: $ty:ty),*) => {
+		$(
+			#[cfg(feature = "std")]
+			impl TryFrom<Value> for $ty {
+				type Error = WrongType;
+
+				fn try_from(value: Value) -> Result<Self, Self::Error> {
+					match value {
+						Value::$variant(v) => Ok(v),
+						other => Err(WrongType::of(&other)),
+					}
+				}
+			}
+		)*
+	};
+}
+
+try_from_value! {
+	Boolean: bool,
+	Float: Float,
+	Double: Double,
+	AnyUri: AnyUriBuf,
+	HexBinary: HexBinaryBuf,
+	Base64Binary: Base64BinaryBuf
+}
+
+macro_rules! try_from_value_ref {
+	($($variant:ident: $ty:ty),*) => {
+		$(
+			/// Borrows the value if it is stored with this exact type.
+			///
+			/// Unlike the owned conversion, subtypes are rejected (e.g. a
+			/// [`Value::Long`] is not borrowed as an [`Integer`]): they are
+			/// stored using a different representation, so there is no
+			/// value of this type to borrow.
+			#[cfg(feature = "std")]
+			impl<'a> TryFrom<&'a Value> for &'a $ty {
+				type Error = WrongType;
+
+				fn try_from(value: &'a Value) -> Result<Self, Self::Error> {
+					match value {
+						Value::$variant(v) => Ok(v),
+						other => Err(WrongType::of(other)),
+					}
+				}
+			}
+		)*
+	};
+}
+
+try_from_value_ref! {
+	Decimal: Decimal,
+	Integer: Integer,
+	NonPositiveInteger: NonPositiveInteger,
+	NegativeInteger: NegativeInteger,
+	NonNegativeInteger: NonNegativeInteger,
+	PositiveInteger: PositiveInteger,
+	AnyUri: AnyUri,
+	HexBinary: HexBinary,
+	Base64Binary: Base64Binary
+}
+
+#[cfg(feature = "std")]
 /// XSD datatype value.
 #[derive(Debug, Clone, Copy)]
 pub enum ValueRef<'a> {
@@ -296,8 +680,10 @@ pub enum ValueRef<'a> {
 	AnyUri(&'a AnyUri),
 	QName(&'a QName),
 	Notation(&'a Notation),
+	List(&'a ListValue),
 }
 
+#[cfg(feature = "std")]
 impl<'a> ValueRef<'a> {
 	pub fn into_owned(self) -> Value {
 		match self {
@@ -333,10 +719,12 @@ impl<'a> ValueRef<'a> {
 			Self::AnyUri(v) => Value::AnyUri(v.to_owned()),
 			Self::QName(v) => Value::QName(v.to_owned()),
 			Self::Notation(v) => Value::Notation(v.to_owned()),
+			Self::List(v) => Value::List(v.to_owned()),
 		}
 	}
 }
 
+#[cfg(feature = "std")]
 impl<'a> XsdDatatype for ValueRef<'a> {
 	fn type_(&self) -> Datatype {
 		match self {
@@ -422,10 +810,12 @@ impl<'a> XsdDatatype for ValueRef<'a> {
 			Self::AnyUri(_) => Datatype::AnyUri,
 			Self::QName(_) => Datatype::QName,
 			Self::Notation(_) => Datatype::Notation,
+			Self::List(v) => v.type_(),
 		}
 	}
 }
 
+#[cfg(feature = "std")]
 impl<'a> fmt::Display for ValueRef<'a> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
@@ -461,17 +851,20 @@ impl<'a> fmt::Display for ValueRef<'a> {
 			Self::AnyUri(v) => v.fmt(f),
 			Self::QName(v) => v.fmt(f),
 			Self::Notation(v) => v.fmt(f),
+			Self::List(v) => v.fmt(f),
 		}
 	}
 }
 
+#[cfg(feature = "std")]
 pub enum CowValue<'a> {
 	Borrowed(ValueRef<'a>),
 	Owned(Value),
 }
 
+#[cfg(feature = "std")]
 impl<'a> CowValue<'a> {
-	pub fn as_value_ref(&self) -> ValueRef {
+	pub fn as_value_ref(&self) -> ValueRef<'_> {
 		match self {
 			Self::Borrowed(v) => *v,
 			Self::Owned(v) => v.as_value_ref(),
@@ -486,6 +879,7 @@ impl<'a> CowValue<'a> {
 	}
 }
 
+#[cfg(feature = "std")]
 impl<'a> XsdDatatype for CowValue<'a> {
 	fn type_(&self) -> Datatype {
 		match self {
@@ -494,3 +888,389 @@ impl<'a> XsdDatatype for CowValue<'a> {
 		}
 	}
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+	use super::*;
+	use std::collections::HashSet;
+
+	#[test]
+	#[allow(clippy::mutable_key_type)] // the decimal lexical cache is not hashed.
+	fn hash_01() {
+		let mut set = HashSet::new();
+		set.insert(Value::UnsignedByte(1));
+		set.insert(Value::Integer(Integer::from(1i64)));
+		set.insert(Value::Integer(Integer::from(1i64)));
+		assert_eq!(set.len(), 2);
+		assert!(set.contains(&Value::UnsignedByte(1)))
+	}
+
+	#[test]
+	fn eq_01() {
+		assert_eq!(
+			Value::Decimal("1.50".parse().unwrap()),
+			Value::Decimal("1.5".parse().unwrap())
+		);
+		assert_ne!(Value::UnsignedByte(1), Value::Byte(1))
+	}
+
+	#[test]
+	fn is_instance_of_01() {
+		let byte: Byte = 12;
+		let short = integer_datatype(IntegerDatatype::Long(Some(LongDatatype::Int(Some(
+			IntDatatype::Short(None),
+		)))));
+		assert!(byte.is_instance_of(&Datatype::Decimal(None)));
+		assert!(byte.is_instance_of(&short));
+		assert!(byte.is_instance_of(&byte.type_()));
+		assert!(!byte.is_instance_of(&Datatype::Float));
+		assert!(!byte.is_instance_of(&integer_datatype(IntegerDatatype::NonNegativeInteger(None))));
+
+		assert!(byte.is_exactly(&byte.type_()));
+		assert!(!byte.is_exactly(&short));
+		assert!(!byte.is_exactly(&Datatype::Decimal(None)))
+	}
+
+	fn integer_datatype(t: IntegerDatatype) -> Datatype {
+		Datatype::Decimal(Some(DecimalDatatype::Integer(Some(t))))
+	}
+
+	#[test]
+	fn try_from_integer_01() {
+		assert_eq!(
+			Integer::try_from(Value::Byte(-3)).unwrap(),
+			Integer::from(-3i64)
+		);
+		assert_eq!(
+			Integer::try_from(Value::UnsignedLong(u64::MAX)).unwrap(),
+			Integer::from(u64::MAX)
+		);
+		assert_eq!(
+			Integer::try_from(Value::PositiveInteger(
+				PositiveInteger::one() + PositiveInteger::one()
+			))
+			.unwrap(),
+			Integer::from(2i64)
+		);
+		assert_eq!(
+			Integer::try_from(Value::Float(1.0f32.into()))
+				.unwrap_err()
+				.actual,
+			Datatype::Float
+		)
+	}
+
+	#[test]
+	fn try_from_non_negative_integer_01() {
+		assert_eq!(
+			NonNegativeInteger::try_from(Value::UnsignedByte(7)).unwrap(),
+			NonNegativeInteger::from(7u8)
+		);
+		assert_eq!(
+			NonNegativeInteger::try_from(Value::PositiveInteger(PositiveInteger::one())).unwrap(),
+			NonNegativeInteger::one()
+		);
+		assert_eq!(
+			NonNegativeInteger::try_from(Value::Byte(7))
+				.unwrap_err()
+				.actual,
+			integer_datatype(IntegerDatatype::Long(Some(LongDatatype::Int(Some(
+				IntDatatype::Short(Some(ShortDatatype::Byte))
+			)))))
+		)
+	}
+
+	#[test]
+	fn try_from_decimal_01() {
+		assert_eq!(
+			Decimal::try_from(Value::Decimal("1.5".parse().unwrap())).unwrap(),
+			"1.5".parse::<Decimal>().unwrap()
+		);
+		assert_eq!(
+			Decimal::try_from(Value::Short(42)).unwrap(),
+			Decimal::from(Integer::from(42i64))
+		);
+		assert_eq!(
+			Decimal::try_from(Value::Boolean(true)).unwrap_err().actual,
+			Datatype::Boolean
+		)
+	}
+
+	#[test]
+	fn try_from_ref_02() {
+		let value = Value::Integer(Integer::from(12i64));
+		assert_eq!(<&Integer>::try_from(&value).unwrap(), &Integer::from(12i64));
+
+		let value = Value::Long(12);
+		assert_eq!(
+			<&Integer>::try_from(&value).unwrap_err().actual,
+			IntegerDatatype::Long(None).into()
+		);
+		assert_eq!(Integer::try_from(value).unwrap(), Integer::from(12i64))
+	}
+
+	#[test]
+	fn try_from_boolean_01() {
+		assert!(bool::try_from(Value::Boolean(true)).unwrap());
+		assert_eq!(
+			bool::try_from(Value::String("true".to_owned()))
+				.unwrap_err()
+				.actual,
+			Datatype::String(None)
+		)
+	}
+
+	#[test]
+	fn try_from_float_01() {
+		assert_eq!(
+			Float::try_from(Value::Float(1.5f32.into())).unwrap(),
+			Float::from(1.5f32)
+		);
+		assert_eq!(
+			Float::try_from(Value::Double(1.5f64.into()))
+				.unwrap_err()
+				.actual,
+			Datatype::Double
+		)
+	}
+
+	#[test]
+	fn try_from_double_01() {
+		assert_eq!(
+			Double::try_from(Value::Double(1.5f64.into())).unwrap(),
+			Double::from(1.5f64)
+		);
+		assert_eq!(
+			Double::try_from(Value::Float(1.5f32.into()))
+				.unwrap_err()
+				.actual,
+			Datatype::Float
+		)
+	}
+
+	#[test]
+	fn try_from_any_uri_01() {
+		let uri = AnyUriBuf::new(b"http://example.org/".to_vec()).unwrap();
+		assert_eq!(
+			AnyUriBuf::try_from(Value::AnyUri(uri.clone())).unwrap(),
+			uri
+		);
+		assert!(AnyUriBuf::try_from(Value::String(uri.to_string())).is_err())
+	}
+
+	#[test]
+	fn try_from_hex_binary_01() {
+		let bytes = HexBinaryBuf::from(vec![0xca, 0xfe]);
+		assert_eq!(
+			HexBinaryBuf::try_from(Value::HexBinary(bytes.clone()))
+				.unwrap()
+				.as_bytes(),
+			bytes.as_bytes()
+		);
+		assert_eq!(
+			HexBinaryBuf::try_from(Value::Base64Binary(Base64BinaryBuf::from(vec![0xca])))
+				.unwrap_err()
+				.actual,
+			Datatype::Base64Binary
+		)
+	}
+
+	#[test]
+	fn try_from_base64_binary_01() {
+		let bytes = Base64BinaryBuf::from(vec![0xca, 0xfe]);
+		assert_eq!(
+			Base64BinaryBuf::try_from(Value::Base64Binary(bytes.clone()))
+				.unwrap()
+				.as_bytes(),
+			bytes.as_bytes()
+		);
+		assert_eq!(
+			Base64BinaryBuf::try_from(Value::HexBinary(HexBinaryBuf::from(vec![0xca])))
+				.unwrap_err()
+				.actual,
+			Datatype::HexBinary
+		)
+	}
+
+	#[test]
+	fn try_from_ref_01() {
+		let value = Value::Integer(Integer::from(5i64));
+		let i: &Integer = (&value).try_into().unwrap();
+		assert_eq!(*i, Integer::from(5i64));
+		assert!(<&Decimal>::try_from(&value).is_err());
+
+		let value = Value::HexBinary(HexBinaryBuf::from(vec![1, 2]));
+		let h: &HexBinary = (&value).try_into().unwrap();
+		assert_eq!(h.as_bytes(), &[1, 2]);
+		assert!(<&Integer>::try_from(&value).is_err())
+	}
+
+	#[test]
+	fn type_iri_01() {
+		let value = Value::UnsignedByte(1);
+		assert_eq!(value.type_iri(), crate::XSD_UNSIGNED_BYTE);
+		assert_eq!(value.type_local_name(), "unsignedByte");
+		assert_eq!(true.type_iri(), crate::XSD_BOOLEAN);
+		assert_eq!(Integer::from(-1000i64).type_local_name(), "short")
+	}
+
+	#[test]
+	fn comparable_decimal_01() {
+		let five: Decimal = "5".parse().unwrap();
+		let four_and_half: Decimal = "4.5".parse().unwrap();
+		let byte = Value::Byte(5);
+
+		assert_eq!(byte.to_comparable_decimal(), Some(five.clone()));
+		assert_eq!(
+			byte.to_comparable_decimal(),
+			Value::Decimal(five).to_comparable_decimal()
+		);
+		assert!(
+			byte.to_comparable_decimal() > Value::Decimal(four_and_half).to_comparable_decimal()
+		);
+		assert!(
+			Value::Byte(-5).to_comparable_decimal()
+				< Value::Float(Float::from(-4.5)).to_comparable_decimal()
+		);
+		assert!(
+			Value::UnsignedLong(u64::MAX).to_comparable_decimal() > byte.to_comparable_decimal()
+		);
+		assert_eq!(
+			Value::Double(Double::from(5.0)).to_comparable_decimal(),
+			byte.to_comparable_decimal()
+		)
+	}
+
+	#[test]
+	fn compare_numeric_01() {
+		let byte = Value::Byte(5);
+		assert_eq!(
+			xsd_compare_numeric(&byte, &Value::Double(Double::from(5.0))).unwrap(),
+			Some(Ordering::Equal)
+		);
+		assert_eq!(
+			xsd_compare_numeric(&byte, &Value::Decimal("4.5".parse().unwrap())).unwrap(),
+			Some(Ordering::Greater)
+		);
+		assert_eq!(
+			xsd_compare_numeric(
+				&Value::Float(Float::from(-4.5)),
+				&Value::Integer(Integer::zero())
+			)
+			.unwrap(),
+			Some(Ordering::Less)
+		);
+		assert_eq!(
+			xsd_compare_numeric(
+				&Value::UnsignedLong(u64::MAX),
+				&Value::Double(Double::INFINITY)
+			)
+			.unwrap(),
+			Some(Ordering::Less)
+		);
+		assert_eq!(
+			xsd_compare_numeric(&Value::Float(Float::NEG_INFINITY), &Value::Long(i64::MIN))
+				.unwrap(),
+			Some(Ordering::Less)
+		);
+		assert_eq!(
+			xsd_compare_numeric(
+				&Value::Double(Double::INFINITY),
+				&Value::Float(Float::INFINITY)
+			)
+			.unwrap(),
+			Some(Ordering::Equal)
+		)
+	}
+
+	#[test]
+	fn compare_numeric_02() {
+		assert_eq!(
+			xsd_compare_numeric(&Value::Float(Float::NAN), &Value::Byte(5)).unwrap(),
+			None
+		);
+		assert_eq!(
+			xsd_compare_numeric(&Value::Double(Double::NAN), &Value::Double(Double::NAN)).unwrap(),
+			None
+		);
+		assert!(xsd_compare_numeric(&Value::String("5".to_owned()), &Value::Byte(5)).is_err());
+		assert!(xsd_compare_numeric(&Value::Byte(5), &Value::Boolean(true)).is_err());
+		let list =
+			Value::from_list(vec![Value::String("5".to_owned())], ListDatatype::NMTokens).unwrap();
+		assert!(xsd_compare_numeric(&list, &Value::Byte(5)).is_err())
+	}
+
+	#[test]
+	fn comparable_decimal_02() {
+		assert_eq!(Value::Float(Float::NAN).to_comparable_decimal(), None);
+		assert_eq!(
+			Value::Double(Double::INFINITY).to_comparable_decimal(),
+			None
+		);
+		assert_eq!(Value::Boolean(true).to_comparable_decimal(), None);
+		assert_eq!(Value::String("5".to_owned()).to_comparable_decimal(), None)
+	}
+
+	#[test]
+	fn list_01() {
+		let list = Value::from_list(
+			vec![
+				Value::String("1".to_owned()),
+				Value::String("-2".to_owned()),
+				Value::String("a.b".to_owned()),
+			],
+			ListDatatype::NMTokens,
+		)
+		.unwrap();
+
+		assert!(list.is_list());
+		assert_eq!(list.as_list().unwrap().len(), 3);
+		assert_eq!(list.type_(), Datatype::List(ListDatatype::NMTokens));
+		assert_eq!(list.to_string(), "1 -2 a.b");
+		assert_eq!(list.into_list().len(), 3)
+	}
+
+	#[test]
+	fn list_02() {
+		let err = Value::from_list(
+			vec![
+				Value::String("a".to_owned()),
+				Value::String("a b".to_owned()),
+			],
+			ListDatatype::NMTokens,
+		)
+		.unwrap_err();
+		assert_eq!(err.index, 1);
+
+		let err = Value::from_list(vec![Value::Byte(1)], ListDatatype::NMTokens).unwrap_err();
+		assert_eq!(err.index, 0);
+
+		let item = Value::String("a".to_owned());
+		let list = Value::from_list(vec![item.clone()], ListDatatype::NMTokens).unwrap();
+		assert!(Value::from_list(vec![list.clone()], ListDatatype::NMTokens).is_err());
+		assert_ne!(list, item);
+		assert_ne!(
+			list,
+			Value::from_list(vec![item], ListDatatype::IdRefs).unwrap()
+		)
+	}
+
+	#[test]
+	fn eq_g_month_day_01() {
+		let month = Datatype::GMonth.parse("--05").unwrap();
+		assert_eq!(month, Value::GMonth("--05".parse().unwrap()));
+		assert_ne!(month, Datatype::GMonth.parse("--06").unwrap());
+
+		let day = Datatype::GDay.parse("---05Z").unwrap();
+		assert_eq!(day, Value::GDay("---05Z".parse().unwrap()));
+		assert_ne!(day, Datatype::GDay.parse("---05").unwrap())
+	}
+
+	#[test]
+	fn list_03() {
+		let value = Value::Boolean(true);
+		assert!(!value.is_list());
+		assert_eq!(value.as_list(), None);
+		assert_eq!(value.clone().into_list(), vec![value])
+	}
+}
@@ -1,13 +1,41 @@
 use crate::{Datatype, ParseRdf, XsdDatatype};
 
+/// URI.
+///
+/// Implements [`Hash`](core::hash::Hash), so it can be used as a
+/// `HashMap`/`HashSet` key, and [`Ord`], so it can be used as a
+/// `BTreeMap`/`BTreeSet` key. URIs are ordered component by component
+/// (scheme, authority, path, query and fragment).
 pub type AnyUri = iref::Uri;
 
+/// Additional operations on [`AnyUri`] values.
+///
+/// Since [`AnyUri`] is an alias for [`iref::Uri`], these operations are
+/// provided through this trait.
+pub trait XsdAnyUri {
+	/// Checks if `prefix` is a prefix of this URI, byte by byte.
+	///
+	/// This is typically used to check if the URI belongs to a namespace
+	/// (e.g. `http://www.w3.org/2001/XMLSchema#`).
+	fn starts_with(&self, prefix: &AnyUri) -> bool;
+}
+
+impl XsdAnyUri for AnyUri {
+	fn starts_with(&self, prefix: &AnyUri) -> bool {
+		self.as_bytes().starts_with(prefix.as_bytes())
+	}
+}
+
 impl XsdDatatype for AnyUri {
 	fn type_(&self) -> Datatype {
 		Datatype::AnyUri
 	}
 }
 
+/// Owned URI.
+///
+/// Implements [`Hash`](core::hash::Hash) and [`Ord`], consistently with
+/// [`AnyUri`].
 pub type AnyUriBuf = iref::UriBuf;
 
 impl XsdDatatype for AnyUriBuf {
@@ -19,3 +47,49 @@ impl XsdDatatype for AnyUriBuf {
 impl ParseRdf for AnyUriBuf {
 	type LexicalForm = AnyUri;
 }
+
+#[cfg(test)]
+mod tests {
+	use std::collections::{BTreeSet, HashMap, HashSet};
+
+	use super::*;
+
+	#[test]
+	fn hash_01() {
+		let a = AnyUriBuf::new(b"http://example.com/a".to_vec()).unwrap();
+		let b = AnyUriBuf::new(b"http://example.com/b".to_vec()).unwrap();
+
+		let mut map = HashMap::new();
+		map.insert(a.clone(), 1);
+		map.insert(b.clone(), 2);
+		map.insert(a.clone(), 3);
+		assert_eq!(map.len(), 2);
+		assert_eq!(map.get(&a), Some(&3));
+		assert_eq!(map.get(a.as_uri()), Some(&3));
+
+		let set: HashSet<&AnyUri> = [a.as_uri(), b.as_uri(), a.as_uri()].into_iter().collect();
+		assert_eq!(set.len(), 2)
+	}
+
+	#[test]
+	fn ord_01() {
+		let a = AnyUriBuf::new(b"http://example.com/a".to_vec()).unwrap();
+		let b = AnyUriBuf::new(b"http://example.com/b".to_vec()).unwrap();
+		assert!(a < b);
+		assert!(a.as_uri() < b.as_uri());
+
+		let set: BTreeSet<AnyUriBuf> = [b.clone(), a.clone(), b.clone()].into_iter().collect();
+		assert!(set.into_iter().eq([a, b]))
+	}
+
+	#[test]
+	fn starts_with_01() {
+		let xsd = AnyUri::new(b"http://www.w3.org/2001/XMLSchema#").unwrap();
+		let string = AnyUriBuf::new(b"http://www.w3.org/2001/XMLSchema#string".to_vec()).unwrap();
+		let other = AnyUri::new(b"http://example.com/string").unwrap();
+		assert!(string.starts_with(xsd));
+		assert!(xsd.starts_with(xsd));
+		assert!(!other.starts_with(xsd));
+		assert!(!xsd.starts_with(&string))
+	}
+}
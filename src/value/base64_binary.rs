@@ -43,7 +43,7 @@ impl Base64BinaryBuf {
 		let mut padding = false;
 
 		for &c in input {
-			if c == 0x20 {
+			if lexical::is_whitespace(c) {
 				continue;
 			}
 
@@ -83,6 +83,26 @@ impl Base64BinaryBuf {
 	pub fn as_base64_binary_mut(&mut self) -> &mut Base64Binary {
 		Base64Binary::new_mut(&mut self.0)
 	}
+
+	/// Decodes URL-safe base64 (RFC 4648 §5), using `-` and `_` instead of
+	/// `+` and `/`. Padding is optional.
+	///
+	/// URL-safe base64 is **not** a valid XSD `base64Binary` lexical form:
+	/// use [`Self::decode`] to parse XSD data.
+	pub fn from_url_safe_base64(input: impl AsRef<[u8]>) -> Result<Self, InvalidBase64> {
+		let standard: Vec<u8> = input
+			.as_ref()
+			.iter()
+			.map(|&c| match c {
+				b'-' => Ok(b'+'),
+				b'_' => Ok(b'/'),
+				b'+' | b'/' => Err(InvalidBase64),
+				c => Ok(c),
+			})
+			.collect::<Result<_, _>>()?;
+
+		Self::decode(standard)
+	}
 }
 
 fn decode_char(c: u8) -> Result<u8, InvalidBase64> {
@@ -158,6 +178,14 @@ impl ParseRdf for Base64BinaryBuf {
 	type LexicalForm = lexical::Base64Binary;
 }
 
+/// Decodes the lexical form.
+impl From<lexical::Base64BinaryBuf> for Base64BinaryBuf {
+	#[inline(always)]
+	fn from(value: lexical::Base64BinaryBuf) -> Self {
+		value.value()
+	}
+}
+
 impl LexicalFormOf<Base64BinaryBuf> for lexical::Base64Binary {
 	type ValueError = std::convert::Infallible;
 
@@ -178,7 +206,7 @@ impl Base64Binary {
 		unsafe { std::mem::transmute(bytes) }
 	}
 
-	pub fn chars(&self) -> Chars {
+	pub fn chars(&self) -> Chars<'_> {
 		Chars {
 			offset: 0,
 			rest: 0,
@@ -190,6 +218,21 @@ impl Base64Binary {
 	pub fn as_bytes(&self) -> &[u8] {
 		&self.0
 	}
+
+	/// Encodes the bytes using URL-safe base64 (RFC 4648 §5), with `-` and
+	/// `_` instead of `+` and `/`.
+	///
+	/// The result is **not** a valid XSD `base64Binary` lexical form: use the
+	/// `Display` implementation for that.
+	pub fn to_url_safe_base64(&self) -> String {
+		self.chars()
+			.map(|c| match c {
+				'+' => '-',
+				'/' => '_',
+				c => c,
+			})
+			.collect()
+	}
 }
 
 impl<'a> From<&'a [u8]> for &'a Base64Binary {
@@ -284,7 +327,7 @@ impl XsdDatatype for Base64Binary {
 mod tests {
 	use super::*;
 
-	const TESTS: [(&'static [u8], &'static str); 9] = [
+	const TESTS: [(&[u8], &str); 9] = [
 		(b"M", "TQ=="),
 		(b"Ma", "TWE="),
 		(b"Man", "TWFu"),
@@ -314,4 +357,29 @@ mod tests {
 			assert_eq!(output.as_bytes(), expected)
 		}
 	}
+
+	#[test]
+	fn whitespace_01() {
+		let value = Base64BinaryBuf::parse_rdf("SGVs\n\tbG8=").ok().unwrap();
+		assert_eq!(value.as_bytes(), b"Hello");
+		assert_eq!(value.to_string(), "SGVsbG8=")
+	}
+
+	#[test]
+	fn url_safe_01() {
+		let value = Base64BinaryBuf::from_bytes(vec![0xfb, 0xff, 0xbf]);
+		assert_eq!(value.to_string(), "+/+/");
+		assert_eq!(value.to_url_safe_base64(), "-_-_");
+		assert_eq!(
+			Base64BinaryBuf::from_url_safe_base64("-_-_").unwrap(),
+			value
+		);
+		assert!(Base64BinaryBuf::from_url_safe_base64("+/+/").is_err());
+		assert!(Base64BinaryBuf::decode("-_-_").is_err());
+
+		for (expected, input) in TESTS {
+			let output = Base64BinaryBuf::from_url_safe_base64(input).unwrap();
+			assert_eq!(output.as_bytes(), expected)
+		}
+	}
 }
@@ -5,6 +5,34 @@ use crate::{
 
 pub type Boolean = bool;
 
+/// XSD lexical conversions for [`Boolean`] values.
+///
+/// Since [`Boolean`] is an alias for `bool`, these conversions are provided
+/// through this trait, allowing `Boolean::from_xsd_str("1")`.
+pub trait XsdBoolean: Sized {
+	/// Parses a boolean from its XSD lexical form, one of `true`, `false`,
+	/// `1` or `0`.
+	fn from_xsd_str(s: &str) -> Result<Self, lexical::InvalidBoolean>;
+
+	/// Returns the canonical XSD lexical form of this boolean, `true` or
+	/// `false`.
+	fn to_xsd_str(self) -> &'static str;
+}
+
+impl XsdBoolean for Boolean {
+	fn from_xsd_str(s: &str) -> Result<Self, lexical::InvalidBoolean> {
+		lexical::Boolean::new(s).map(lexical::Boolean::value)
+	}
+
+	fn to_xsd_str(self) -> &'static str {
+		if self {
+			"true"
+		} else {
+			"false"
+		}
+	}
+}
+
 impl XsdDatatype for Boolean {
 	fn type_(&self) -> Datatype {
 		Datatype::Boolean
@@ -22,3 +50,26 @@ impl LexicalFormOf<Boolean> for lexical::Boolean {
 impl ParseRdf for Boolean {
 	type LexicalForm = lexical::Boolean;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn xsd_str_01() {
+		for (input, expected) in [("true", true), ("1", true), ("false", false), ("0", false)] {
+			assert_eq!(Boolean::from_xsd_str(input).unwrap(), expected);
+			assert_eq!(
+				Boolean::from_xsd_str(Boolean::to_xsd_str(expected)).unwrap(),
+				expected
+			)
+		}
+
+		for input in ["True", "FALSE", "yes", "", " 1"] {
+			assert!(Boolean::from_xsd_str(input).is_err(), "{input}")
+		}
+
+		assert_eq!(true.to_xsd_str(), "true");
+		assert_eq!(false.to_xsd_str(), "false")
+	}
+}
@@ -1,9 +1,105 @@
-use chrono::{FixedOffset, NaiveDate};
+use chrono::{Datelike, FixedOffset, NaiveDate};
 
-use crate::{Datatype, XsdDatatype};
-use core::fmt;
+use crate::{lexical, Datatype, Duration, ParseRdf, XsdDatatype};
+use core::{
+	fmt,
+	ops::{Add, Sub},
+	str::FromStr,
+};
 
-#[derive(Debug, Clone, Copy)]
+use super::duration::{NANOSECONDS_PER_SECOND, SECONDS_PER_DAY};
+
+pub(crate) use crate::lexical::{parse_time_zone, parse_two_digits, parse_year};
+
+/// Day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+	Monday,
+	Tuesday,
+	Wednesday,
+	Thursday,
+	Friday,
+	Saturday,
+	Sunday,
+}
+
+impl From<chrono::Weekday> for Weekday {
+	fn from(value: chrono::Weekday) -> Self {
+		match value {
+			chrono::Weekday::Mon => Self::Monday,
+			chrono::Weekday::Tue => Self::Tuesday,
+			chrono::Weekday::Wed => Self::Wednesday,
+			chrono::Weekday::Thu => Self::Thursday,
+			chrono::Weekday::Fri => Self::Friday,
+			chrono::Weekday::Sat => Self::Saturday,
+			chrono::Weekday::Sun => Self::Sunday,
+		}
+	}
+}
+
+/// Checks if the given year is a leap year in the proleptic Gregorian
+/// calendar.
+///
+/// Year `0` (1 BCE) is a leap year.
+pub(crate) fn is_leap_year(year: i32) -> bool {
+	year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in the given month of the given year, or
+/// `None` if the month is not between 1 and 12.
+pub(crate) fn days_in_month(year: i32, month: u8) -> Option<u8> {
+	match month {
+		2 => {
+			if is_leap_year(year) {
+				Some(29)
+			} else {
+				Some(28)
+			}
+		}
+		4 | 6 | 9 | 11 => Some(30),
+		1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+		_ => None,
+	}
+}
+
+/// Error raised when the result of a date arithmetic operation is out of
+/// the supported range.
+#[derive(Debug, thiserror::Error)]
+#[error("date out of range")]
+pub struct DateArithmeticError;
+
+/// Error raised when trying to build a date that does not exist.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid date")]
+pub struct InvalidDate;
+
+/// Formats a year with at least four digits, as required by the XSD lexical
+/// spaces.
+pub(crate) fn fmt_year(year: i32, f: &mut fmt::Formatter) -> fmt::Result {
+	if year < 0 {
+		write!(f, "-{:04}", year.unsigned_abs())
+	} else {
+		write!(f, "{year:04}")
+	}
+}
+
+/// Formats a time zone offset following the XSD lexical spaces.
+pub(crate) fn fmt_time_zone(offset: &FixedOffset, f: &mut fmt::Formatter) -> fmt::Result {
+	let seconds = offset.local_minus_utc();
+	if seconds == 0 {
+		f.write_str("Z")
+	} else {
+		let sign = if seconds < 0 { '-' } else { '+' };
+		let minutes = seconds.unsigned_abs() / 60;
+		write!(f, "{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+	}
+}
+
+fn utc() -> FixedOffset {
+	FixedOffset::east_opt(0).unwrap()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Date {
 	pub date: NaiveDate,
 	pub offset: FixedOffset,
@@ -13,6 +109,262 @@ impl Date {
 	pub fn new(date: NaiveDate, offset: FixedOffset) -> Self {
 		Self { date, offset }
 	}
+
+	/// Creates a new UTC date from its year, month (`1..=12`) and day
+	/// (`1..=31`).
+	pub fn from_ymd(year: i32, month: u8, day: u8) -> Result<Self, InvalidDate> {
+		NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+			.map(|date| Self::new(date, utc()))
+			.ok_or(InvalidDate)
+	}
+
+	/// Creates a new UTC date from its year and day of the year, starting
+	/// from 1.
+	pub fn from_day_of_year(year: i32, day: u16) -> Result<Self, InvalidDate> {
+		NaiveDate::from_yo_opt(year, day as u32)
+			.map(|date| Self::new(date, utc()))
+			.ok_or(InvalidDate)
+	}
+
+	/// Creates a new UTC date from its Julian Day Number.
+	///
+	/// # Panics
+	///
+	/// Panics if the resulting date is out of the range supported by
+	/// [`NaiveDate`].
+	pub fn from_julian_day(jdn: i64) -> Self {
+		Self::checked_from_julian_day(jdn).expect("Julian Day Number out of range")
+	}
+
+	/// Creates a new UTC date from its Julian Day Number, or returns `None`
+	/// if the resulting date is out of the range supported by [`NaiveDate`].
+	fn checked_from_julian_day(jdn: i64) -> Option<Self> {
+		// Richards' algorithm, using floor divisions to support negative years.
+		// Only `a` and `b` depend on the magnitude of `jdn`: `c` is a day
+		// within a 400 years cycle, so the following terms cannot overflow.
+		let a = jdn.checked_add(32044)?;
+		let b = a.checked_mul(4)?.checked_add(3)?.div_euclid(146097);
+		let c = a.checked_sub(b.checked_mul(146097)?.div_euclid(4))?;
+		let d = (4 * c + 3).div_euclid(1461);
+		let e = c - (1461 * d).div_euclid(4);
+		let m = (5 * e + 2).div_euclid(153);
+
+		let day = e - (153 * m + 2).div_euclid(5) + 1;
+		let month = m + 3 - 12 * m.div_euclid(10);
+		let year = b
+			.checked_mul(100)?
+			.checked_add(d - 4800 + m.div_euclid(10))?;
+
+		let date = NaiveDate::from_ymd_opt(year.try_into().ok()?, month as u32, day as u32)?;
+		Some(Self::new(date, utc()))
+	}
+
+	/// Returns the day of the week of this date.
+	pub fn day_of_week(&self) -> Weekday {
+		self.date.weekday().into()
+	}
+
+	/// Returns the day of the year, starting from 1.
+	pub fn day_of_year(&self) -> u16 {
+		self.date.ordinal() as u16
+	}
+
+	/// Checks if the year of this date is a leap year.
+	pub fn is_leap_year(&self) -> bool {
+		is_leap_year(self.date.year())
+	}
+
+	/// Returns the number of days in the month of this date.
+	pub fn days_in_month(&self) -> u8 {
+		days_in_month(self.date.year(), self.date.month() as u8).expect("valid month")
+	}
+
+	/// Adds the given duration to this date.
+	///
+	/// The year-month part of the duration is added first, pinning the day
+	/// to the last day of the resulting month if necessary. The day-time part
+	/// is then added as if this date was starting at midnight, ignoring the
+	/// remaining fraction of day.
+	///
+	/// # Panics
+	///
+	/// Panics if the resulting date is out of the range supported by
+	/// [`NaiveDate`].
+	pub fn add_duration(&self, d: &Duration) -> Self {
+		self.checked_add_duration(d).expect("date out of range")
+	}
+
+	/// Adds the given duration to this date, or returns an error if the
+	/// resulting date is out of the range supported by [`NaiveDate`].
+	///
+	/// See [`Self::add_duration`].
+	pub fn checked_add_duration(&self, d: &Duration) -> Result<Self, DateArithmeticError> {
+		let nano_seconds_per_day = SECONDS_PER_DAY as i128 * NANOSECONDS_PER_SECOND as i128;
+		let days = d.signed_nano_seconds().div_euclid(nano_seconds_per_day);
+		self.checked_add_months(d.signed_months())
+			.and_then(|date| date.checked_add_days(days.try_into().ok()?))
+			.ok_or(DateArithmeticError)
+	}
+
+	fn checked_add_months(&self, months: i64) -> Option<Self> {
+		let months =
+			(self.date.year() as i64 * 12 + self.date.month0() as i64).checked_add(months)?;
+		let year = i32::try_from(months.div_euclid(12)).ok()?;
+		let month = months.rem_euclid(12) as u8 + 1;
+		let day = (self.date.day() as u8).min(days_in_month(year, month)?);
+
+		let date = NaiveDate::from_ymd_opt(year, month as u32, day as u32)?;
+		Some(Self::new(date, self.offset))
+	}
+
+	fn checked_add_days(&self, days: i64) -> Option<Self> {
+		let jdn = self.to_julian_day().checked_add(days)?;
+		let date = Self::checked_from_julian_day(jdn)?.date;
+		Some(Self::new(date, self.offset))
+	}
+
+	fn add_days(&self, days: i64) -> Self {
+		self.checked_add_days(days).expect("date out of range")
+	}
+
+	/// Returns the next calendar day, with the same time zone.
+	///
+	/// # Panics
+	///
+	/// Panics if the resulting date is out of the range supported by
+	/// [`NaiveDate`].
+	pub fn successor(&self) -> Self {
+		self.add_days(1)
+	}
+
+	/// Returns the previous calendar day, with the same time zone.
+	///
+	/// # Panics
+	///
+	/// Panics if the resulting date is out of the range supported by
+	/// [`NaiveDate`].
+	pub fn predecessor(&self) -> Self {
+		self.add_days(-1)
+	}
+
+	/// Returns the signed number of days from `other` to `self`.
+	///
+	/// Time zones are ignored.
+	pub fn days_since(&self, other: &Self) -> i64 {
+		self.to_julian_day() - other.to_julian_day()
+	}
+
+	/// Returns the Julian Day Number of this date.
+	pub fn to_julian_day(&self) -> i64 {
+		let year = self.date.year() as i64;
+		let month = self.date.month() as i64;
+		let day = self.date.day() as i64;
+
+		let a = (14 - month) / 12;
+		let y = year + 4800 - a;
+		let m = month + 12 * a - 3;
+
+		day + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)
+			- 32045
+	}
+
+	/// Parses a date in the ISO 8601 extended format, which is the XSD
+	/// lexical form (e.g. `2002-10-10` or `2002-10-10+05:00`).
+	///
+	/// Same as [`FromStr`].
+	pub fn parse_iso8601_extended(s: &str) -> Result<Self, InvalidDate> {
+		s.parse()
+	}
+
+	/// Parses a UTC date in the ISO 8601 basic format `YYYYMMDD`
+	/// (e.g. `20021010`).
+	///
+	/// This is not an XSD lexical form.
+	pub fn parse_iso8601_basic(s: &str) -> Result<Self, InvalidDate> {
+		if s.len() != 8 || !s.bytes().all(|c| c.is_ascii_digit()) {
+			return Err(InvalidDate);
+		}
+
+		let year = s[..4].parse().map_err(|_| InvalidDate)?;
+		let (month, rest) = parse_two_digits(&s[4..], 1..=12)?;
+		let (day, _) = parse_two_digits(rest, 1..=31)?;
+		Self::from_ymd(year, month, day)
+	}
+
+	/// Formats this date in the ISO 8601 basic format `YYYYMMDD`
+	/// (e.g. `20021010`), ignoring the time zone.
+	///
+	/// Years outside of `0000..=9999` use the ISO 8601 expanded
+	/// representation, with an explicit sign (e.g. `-00011225`).
+	///
+	/// This is not an XSD lexical form, see [`fmt::Display`] for the
+	/// canonical representation.
+	pub fn to_iso8601_basic(&self) -> String {
+		let year = self.date.year();
+		if (0..=9999).contains(&year) {
+			format!("{year:04}{:02}{:02}", self.date.month(), self.date.day())
+		} else {
+			format!("{year:+05}{:02}{:02}", self.date.month(), self.date.day())
+		}
+	}
+}
+
+/// Adds a number of days.
+///
+/// # Panics
+///
+/// Panics if the resulting date is out of the range supported by
+/// [`NaiveDate`].
+impl Add<i64> for Date {
+	type Output = Self;
+
+	fn add(self, days: i64) -> Self {
+		self.add_days(days)
+	}
+}
+
+/// Subtracts a number of days.
+///
+/// # Panics
+///
+/// Panics if the resulting date is out of the range supported by
+/// [`NaiveDate`].
+impl Sub<i64> for Date {
+	type Output = Self;
+
+	fn sub(self, days: i64) -> Self {
+		self.add_days(-days)
+	}
+}
+
+/// Adds a duration following [`Date::add_duration`], failing if the
+/// resulting date is out of range.
+impl Add<Duration> for Date {
+	type Output = Result<Self, DateArithmeticError>;
+
+	fn add(self, d: Duration) -> Self::Output {
+		self.checked_add_duration(&d)
+	}
+}
+
+/// Subtracts a duration, adding its negation following
+/// [`Date::add_duration`], failing if the resulting date is out of range.
+impl Sub<Duration> for Date {
+	type Output = Result<Self, DateArithmeticError>;
+
+	fn sub(self, d: Duration) -> Self::Output {
+		self.checked_add_duration(&-d)
+	}
+}
+
+/// Returns the signed number of days between two dates, ignoring time
+/// zones.
+impl Sub for Date {
+	type Output = i64;
+
+	fn sub(self, other: Self) -> i64 {
+		self.days_since(&other)
+	}
 }
 
 impl XsdDatatype for Date {
@@ -21,8 +373,252 @@ impl XsdDatatype for Date {
 	}
 }
 
+impl ParseRdf for Date {
+	type LexicalForm = lexical::Date;
+}
+
+impl FromStr for Date {
+	type Err = InvalidDate;
+
+	/// Parses a `date` lexical form, such as `2002-10-10` or
+	/// `2002-10-10+05:00`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		lexical::Date::new(s).map_err(|_| InvalidDate)?.value()
+	}
+}
+
 impl fmt::Display for Date {
-	fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		unimplemented!()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt_year(self.date.year(), f)?;
+		write!(f, "-{:02}-{:02}", self.date.month(), self.date.day())?;
+		fmt_time_zone(&self.offset, f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn date(year: i32, month: u32, day: u32) -> Date {
+		Date::new(NaiveDate::from_ymd_opt(year, month, day).unwrap(), utc())
+	}
+
+	#[test]
+	fn leap_year_01() {
+		let d = date(2000, 2, 29);
+		assert!(d.is_leap_year());
+		assert_eq!(d.days_in_month(), 29);
+		assert_eq!(d.day_of_year(), 60);
+		assert_eq!(d.day_of_week(), Weekday::Tuesday);
+	}
+
+	#[test]
+	fn leap_year_02() {
+		let d = date(1900, 2, 28);
+		assert!(!d.is_leap_year());
+		assert_eq!(d.days_in_month(), 28);
+		assert_eq!(d.day_of_year(), 59);
+		assert_eq!(d.day_of_week(), Weekday::Wednesday);
+	}
+
+	#[test]
+	fn leap_year_03() {
+		let d = date(0, 12, 31);
+		assert!(d.is_leap_year());
+		assert_eq!(d.day_of_year(), 366);
+		assert_eq!(d.day_of_week(), Weekday::Sunday);
+	}
+
+	#[test]
+	fn negative_year_01() {
+		let d = date(-1, 3, 1);
+		assert!(!d.is_leap_year());
+		assert_eq!(d.day_of_year(), 60);
+		assert_eq!(d.day_of_week(), Weekday::Monday);
+	}
+
+	#[test]
+	fn negative_year_02() {
+		let d = date(-4, 2, 1);
+		assert!(d.is_leap_year());
+		assert_eq!(d.days_in_month(), 29);
+	}
+
+	#[test]
+	fn day_of_year_01() {
+		assert_eq!(
+			Date::from_day_of_year(2000, 60).unwrap(),
+			Date::from_ymd(2000, 2, 29).unwrap()
+		);
+		assert_eq!(
+			Date::from_day_of_year(2001, 365).unwrap(),
+			Date::from_ymd(2001, 12, 31).unwrap()
+		);
+	}
+
+	#[test]
+	fn day_of_year_02() {
+		assert!(Date::from_day_of_year(2001, 366).is_err());
+		assert!(Date::from_day_of_year(2000, 0).is_err());
+	}
+
+	#[test]
+	fn days_in_month_01() {
+		assert_eq!(days_in_month(2024, 2), Some(29));
+		assert_eq!(days_in_month(2023, 2), Some(28));
+		assert_eq!(days_in_month(2023, 12), Some(31));
+		assert_eq!(days_in_month(2023, 0), None);
+		assert_eq!(days_in_month(2023, 13), None)
+	}
+
+	#[test]
+	fn julian_day_01() {
+		let d = Date::from_ymd(2000, 1, 1).unwrap();
+		assert_eq!(d.to_julian_day(), 2451545);
+		assert_eq!(Date::from_julian_day(d.to_julian_day()), d)
+	}
+
+	#[test]
+	fn julian_day_02() {
+		for (year, month, day) in [(1, 1, 1), (0, 2, 29), (-1, 12, 31), (-4713, 11, 24)] {
+			let d = Date::from_ymd(year, month, day).unwrap();
+			assert_eq!(Date::from_julian_day(d.to_julian_day()), d)
+		}
+
+		assert_eq!(Date::from_ymd(-4713, 11, 24).unwrap().to_julian_day(), 0);
+		assert_eq!(Date::from_ymd(1, 1, 1).unwrap().to_julian_day(), 1721426);
+	}
+
+	#[test]
+	fn julian_day_03() {
+		for jdn in [
+			i64::MIN,
+			i64::MIN / 4,
+			-i64::MAX / 4,
+			i64::MAX / 4,
+			i64::MAX,
+		] {
+			assert_eq!(Date::checked_from_julian_day(jdn), None, "{jdn}")
+		}
+	}
+
+	#[test]
+	fn format_01() {
+		assert_eq!(date(2002, 10, 10).to_string(), "2002-10-10Z");
+		assert_eq!(
+			Date::new(
+				NaiveDate::from_ymd_opt(-45, 3, 1).unwrap(),
+				FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap()
+			)
+			.to_string(),
+			"-0045-03-01+05:30"
+		)
+	}
+
+	#[test]
+	fn add_duration_01() {
+		let d = Date::from_ymd(2000, 1, 31).unwrap();
+		assert_eq!(
+			d.add_duration(&Duration::new(false, 1, 0, 0)),
+			Date::from_ymd(2000, 2, 29).unwrap()
+		);
+		assert_eq!(
+			d.add_duration(&Duration::new(false, 13, 86400, 0)),
+			Date::from_ymd(2001, 3, 1).unwrap()
+		);
+	}
+
+	#[test]
+	fn add_duration_02() {
+		let d = Date::from_ymd(2000, 1, 1).unwrap();
+		assert_eq!(
+			d.add_duration(&Duration::new(true, 0, 3600, 0)),
+			Date::from_ymd(1999, 12, 31).unwrap()
+		);
+		assert_eq!(
+			d.add_duration(&Duration::new(true, 24, 0, 0)),
+			Date::from_ymd(1998, 1, 1).unwrap()
+		);
+	}
+
+	#[test]
+	fn parse_rdf_01() {
+		for (input, expected) in [
+			("2002-10-10", "2002-10-10Z"),
+			("2002-10-10-05:00", "2002-10-10-05:00"),
+			("-0045-01-01+01:00", "-0045-01-01+01:00"),
+		] {
+			assert_eq!(Date::parse_rdf(input).ok().unwrap().to_string(), expected)
+		}
+
+		assert!(Date::parse_rdf("2023-02-29").is_err());
+		assert!(Date::parse_rdf("2023-02-2").is_err())
+	}
+
+	#[test]
+	fn successor_01() {
+		let d = Date::from_ymd(2000, 2, 28).unwrap();
+		assert_eq!(d.successor(), Date::from_ymd(2000, 2, 29).unwrap());
+		assert_eq!(
+			d.successor().successor(),
+			Date::from_ymd(2000, 3, 1).unwrap()
+		);
+		assert_eq!(
+			Date::from_ymd(1900, 3, 1).unwrap().predecessor(),
+			Date::from_ymd(1900, 2, 28).unwrap()
+		);
+		assert_eq!(
+			Date::from_ymd(1999, 12, 31).unwrap().successor(),
+			Date::from_ymd(2000, 1, 1).unwrap()
+		)
+	}
+
+	#[test]
+	fn days_01() {
+		let a = Date::from_ymd(2000, 1, 1).unwrap();
+		let b = Date::from_ymd(2001, 1, 1).unwrap();
+		assert_eq!(b.days_since(&a), 366);
+		assert_eq!(a - b, -366);
+		assert_eq!(a + 366, b);
+		assert_eq!(b - 366, a);
+		assert_eq!(a + -1, Date::from_ymd(1999, 12, 31).unwrap())
+	}
+
+	#[test]
+	fn add_duration_03() {
+		let d = Date::from_ymd(2023, 1, 31).unwrap();
+		assert_eq!(
+			(d + Duration::from_months(1)).unwrap(),
+			Date::from_ymd(2023, 2, 28).unwrap()
+		);
+		assert_eq!(
+			(d + Duration::builder().months(1).days(1).build()).unwrap(),
+			Date::from_ymd(2023, 3, 1).unwrap()
+		);
+		assert_eq!(
+			(d - Duration::from_days(31)).unwrap(),
+			Date::from_ymd(2022, 12, 31).unwrap()
+		);
+		assert!((d + Duration::from_years(1_000_000)).is_err());
+		assert!((d - Duration::from_days(i64::MAX / SECONDS_PER_DAY as i64)).is_err())
+	}
+
+	#[test]
+	fn iso8601_01() {
+		let d = Date::parse_iso8601_basic("20240229").unwrap();
+		assert_eq!(d, date(2024, 2, 29));
+		assert_eq!(d.to_iso8601_basic(), "20240229");
+		assert_eq!(date(-1, 12, 25).to_iso8601_basic(), "-00011225");
+		assert_eq!(date(10000, 1, 1).to_iso8601_basic(), "+100000101");
+
+		for input in ["20230229", "2024-02-29", "2024022", "+2024022", "20241301"] {
+			assert!(Date::parse_iso8601_basic(input).is_err(), "{input}")
+		}
+
+		let d = Date::parse_iso8601_extended("2002-10-10+05:00").unwrap();
+		assert_eq!(d.to_string(), "2002-10-10+05:00");
+		assert_eq!(d.to_iso8601_basic(), "20021010");
+		assert!(Date::parse_iso8601_extended("20021010").is_err());
+		assert!("2002-02-30".parse::<Date>().is_err())
 	}
 }
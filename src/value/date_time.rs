@@ -1,7 +1,20 @@
 use chrono::{FixedOffset, Utc};
 use std::{fmt, str::FromStr};
 
-use crate::{Datatype, ParseRdf, XsdDatatype};
+use crate::{Datatype, Date, Duration, ParseRdf, Time, XsdDatatype};
+
+use super::{date::DateArithmeticError, duration::SECONDS_PER_DAY};
+
+/// Error raised when a time zone offset is outside of the
+/// `[-840, 840]` minutes range (`-14:00` to `+14:00`) allowed by XSD.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid time zone offset: {0} minutes")]
+pub struct InvalidTimezoneOffset(pub i16);
+
+/// Error raised when parsing an invalid RFC 3339 date and time.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid RFC 3339 date and time: {0}")]
+pub struct ParseRfc3339Error(#[from] pub chrono::format::ParseError);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DateTime(chrono::DateTime<FixedOffset>);
@@ -10,6 +23,86 @@ impl DateTime {
 	pub fn into_string(self) -> String {
 		self.0.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
 	}
+
+	/// Parses an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339)
+	/// date and time, such as `2002-10-10T12:00:00+05:30`.
+	///
+	/// Unlike the XSD lexical form, RFC 3339 requires a time zone and does
+	/// not allow the `24:00:00` end of day form.
+	pub fn parse_rfc3339(s: &str) -> Result<Self, ParseRfc3339Error> {
+		Ok(Self(chrono::DateTime::parse_from_rfc3339(s)?))
+	}
+
+	/// Formats this date and time as an
+	/// [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) timestamp.
+	///
+	/// Values always have a time zone, so the result is always a valid
+	/// RFC 3339 timestamp, which is also a valid `xsd:dateTime` lexical
+	/// form.
+	pub fn to_rfc3339(&self) -> String {
+		self.0.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+	}
+
+	/// Returns the same instant expressed in the given time zone, with
+	/// `offset_minutes` the offset from UTC in minutes.
+	///
+	/// The date and time components are shifted accordingly: `12:00:00Z`
+	/// in the `+330` time zone is `17:30:00+05:30`.
+	pub fn with_timezone(&self, offset_minutes: i16) -> Result<Self, InvalidTimezoneOffset> {
+		if !(-840..=840).contains(&offset_minutes) {
+			return Err(InvalidTimezoneOffset(offset_minutes));
+		}
+
+		let offset = FixedOffset::east_opt(offset_minutes as i32 * 60).unwrap();
+		Ok(Self(self.0.with_timezone(&offset)))
+	}
+
+	/// Returns the same instant expressed in UTC.
+	///
+	/// Values always have a time zone (a date and time parsed without time
+	/// zone is assumed to be in UTC), so this never fails.
+	pub fn to_utc(&self) -> Self {
+		Self(self.0.with_timezone(&FixedOffset::east_opt(0).unwrap()))
+	}
+
+	/// Adds the given duration to this date and time.
+	///
+	/// # Panics
+	///
+	/// Panics if the resulting date is out of the range supported by
+	/// [`chrono`].
+	pub fn add_duration(&self, d: &Duration) -> Self {
+		self.checked_add_duration(d)
+			.expect("date and time out of range")
+	}
+
+	/// Adds the given duration to this date and time, or returns an error if
+	/// the resulting date is out of the range supported by [`chrono`].
+	///
+	/// See [`Self::add_duration`].
+	pub fn checked_add_duration(&self, d: &Duration) -> Result<Self, DateArithmeticError> {
+		let offset = *self.0.offset();
+		let date = Date::new(self.0.date_naive(), offset).checked_add_duration(&Duration::new(
+			d.is_negative(),
+			d.months(),
+			0,
+			0,
+		))?;
+		let (time, days) = Time::new(self.0.time(), offset).add_duration(d);
+		let date = date.checked_add_duration(&Duration::new(
+			days < 0,
+			0,
+			days.unsigned_abs() * SECONDS_PER_DAY,
+			0,
+		))?;
+
+		date.date
+			.and_time(time.time)
+			.and_local_timezone(offset)
+			.single()
+			.map(Self)
+			.ok_or(DateArithmeticError)
+	}
 }
 
 impl XsdDatatype for DateTime {
@@ -96,3 +189,81 @@ impl<'de> serde::Deserialize<'de> for DateTime {
 		deserializer.deserialize_str(Visitor)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn add_duration_01() {
+		let dt: DateTime = "2000-01-31T23:00:00+02:00".parse().unwrap();
+		assert_eq!(
+			dt.add_duration(&Duration::new(false, 1, 7200, 0)),
+			"2000-03-01T01:00:00+02:00".parse().unwrap()
+		)
+	}
+
+	#[test]
+	fn add_duration_02() {
+		let dt: DateTime = "2000-03-01T01:00:00Z".parse().unwrap();
+		assert_eq!(
+			dt.add_duration(&Duration::new(true, 0, 7200, 0)),
+			"2000-02-29T23:00:00Z".parse().unwrap()
+		)
+	}
+
+	#[test]
+	fn checked_add_duration_01() {
+		let dt: DateTime = "2000-01-31T23:00:00Z".parse().unwrap();
+		assert_eq!(
+			dt.checked_add_duration(&Duration::new(false, 1, 7200, 0))
+				.unwrap(),
+			"2000-03-01T01:00:00Z".parse().unwrap()
+		);
+		assert!(dt
+			.checked_add_duration(&Duration::from_years(300000))
+			.is_err());
+		assert!(dt
+			.checked_add_duration(&-Duration::from_years(300000))
+			.is_err())
+	}
+
+	#[test]
+	fn with_timezone_01() {
+		let dt: DateTime = "2002-10-10T12:00:00Z".parse().unwrap();
+		let shifted = dt.with_timezone(330).unwrap();
+		assert_eq!(shifted.to_string(), "2002-10-10T17:30:00+05:30");
+		assert_eq!(shifted, dt);
+		assert_eq!(
+			dt.with_timezone(-840).unwrap().to_string(),
+			"2002-10-09T22:00:00-14:00"
+		);
+		assert!(dt.with_timezone(841).is_err());
+		assert_eq!(shifted.to_utc().to_string(), "2002-10-10T12:00:00Z")
+	}
+
+	#[test]
+	fn rfc3339_01() {
+		for (input, expected) in [
+			("2002-10-10T12:00:00Z", "2002-10-10T12:00:00Z"),
+			("2002-10-10T12:00:00+00:00", "2002-10-10T12:00:00Z"),
+			("2002-10-10T12:00:00+05:30", "2002-10-10T12:00:00+05:30"),
+			(
+				"2002-10-10T12:00:00.250-08:00",
+				"2002-10-10T12:00:00.250-08:00",
+			),
+		] {
+			let dt = DateTime::parse_rfc3339(input).unwrap();
+			assert_eq!(dt.to_rfc3339(), expected)
+		}
+
+		for input in [
+			"2002-10-10T12:00:00",
+			"2002-10-10T24:00:00Z",
+			"2002-10-10",
+			"2002-10-10T12:00Z",
+		] {
+			assert!(DateTime::parse_rfc3339(input).is_err(), "{input}")
+		}
+	}
+}
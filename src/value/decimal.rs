@@ -1,21 +1,26 @@
-use std::fmt;
-use std::hash::Hash;
-use std::ops::Deref;
-use std::str::FromStr;
-use std::{borrow::Borrow, collections::HashSet};
+use alloc::{
+	borrow::ToOwned,
+	collections::{BTreeMap, BTreeSet},
+	format,
+	string::{String, ToString},
+};
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::Hash;
+use core::iter::{Product, Sum};
+use core::ops::Deref;
+use core::str::FromStr;
 
-use lazy_static::lazy_static;
 use num_bigint::BigInt;
 use num_rational::BigRational;
 use num_traits::{Signed, Zero};
 use once_cell::unsync::OnceCell;
 
 use crate::lexical::LexicalFormOf;
-use crate::{
-	lexical, Datatype, DecimalDatatype, Double, Float, IntDatatype, IntegerDatatype, LongDatatype,
-	NonNegativeIntegerDatatype, NonPositiveIntegerDatatype, ParseRdf, ShortDatatype,
-	UnsignedIntDatatype, UnsignedLongDatatype, UnsignedShortDatatype, XsdDatatype,
-};
+use crate::{lexical, Datatype, DecimalDatatype, ParseRdf, XsdDatatype};
+
+#[cfg(feature = "std")]
+use crate::{Double, Float};
 
 pub use num_bigint::Sign;
 
@@ -23,26 +28,6 @@ mod integer;
 
 pub use integer::*;
 
-lazy_static! {
-	static ref I64_MIN: BigInt = i64::MIN.into();
-	static ref I64_MIN_RATIO: BigRational = I64_MIN.clone().into();
-	static ref I32_MIN: BigInt = i32::MIN.into();
-	static ref I32_MIN_RATIO: BigRational = I32_MIN.clone().into();
-	static ref I16_MIN: BigInt = i16::MIN.into();
-	static ref I16_MIN_RATIO: BigRational = I16_MIN.clone().into();
-	static ref I8_MIN: BigInt = i8::MIN.into();
-	static ref I8_MIN_RATIO: BigRational = I8_MIN.clone().into();
-	static ref U64_MAX: BigInt = u64::MAX.into();
-	static ref U64_MAX_RATIO: BigRational = U64_MAX.clone().into();
-	static ref U32_MAX: BigInt = u32::MAX.into();
-	static ref U32_MAX_RATIO: BigRational = U32_MAX.clone().into();
-	static ref U16_MAX: BigInt = u16::MAX.into();
-	static ref U16_MAX_RATIO: BigRational = U16_MAX.clone().into();
-	static ref U8_MAX: BigInt = u8::MAX.into();
-	static ref U8_MAX_RATIO: BigRational = U8_MAX.clone().into();
-	static ref TEN: BigInt = 10u32.into();
-}
-
 /// Decimal number.
 ///
 /// Internally a decimal number is represented as a `BigRational` with a finite
@@ -53,6 +38,11 @@ pub struct Decimal {
 	lexical: OnceCell<lexical::DecimalBuf>,
 }
 
+/// Numeric equality, following the XSD value space: `2.0` equals `2`, and
+/// `-0.0` equals `0`.
+///
+/// The underlying `BigRational` is always kept in reduced form, so comparing
+/// it structurally is the same as comparing the numeric values.
 impl PartialEq for Decimal {
 	fn eq(&self, other: &Self) -> bool {
 		self.data.eq(&other.data)
@@ -62,23 +52,63 @@ impl PartialEq for Decimal {
 impl Eq for Decimal {}
 
 impl PartialOrd for Decimal {
-	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-		Some(self.data.cmp(other))
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		Some(self.cmp(other))
 	}
 }
 
 impl Ord for Decimal {
-	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		self.data.cmp(&other.data)
 	}
 }
 
+impl Decimal {
+	/// Compares this decimal with the given integer, exactly.
+	fn cmp_integer(&self, other: &Integer) -> core::cmp::Ordering {
+		let other: &BigInt = other.as_ref();
+		// The denominator of a `BigRational` is always positive.
+		self.data.numer().cmp(&(other * self.data.denom()))
+	}
+}
+
+impl PartialEq<Integer> for Decimal {
+	fn eq(&self, other: &Integer) -> bool {
+		self.cmp_integer(other).is_eq()
+	}
+}
+
+impl PartialEq<Decimal> for Integer {
+	fn eq(&self, other: &Decimal) -> bool {
+		other.cmp_integer(self).is_eq()
+	}
+}
+
+impl PartialOrd<Integer> for Decimal {
+	fn partial_cmp(&self, other: &Integer) -> Option<core::cmp::Ordering> {
+		Some(self.cmp_integer(other))
+	}
+}
+
+impl PartialOrd<Decimal> for Integer {
+	fn partial_cmp(&self, other: &Decimal) -> Option<core::cmp::Ordering> {
+		Some(other.cmp_integer(self).reverse())
+	}
+}
+
 impl Hash for Decimal {
-	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
 		self.data.hash(state)
 	}
 }
 
+impl Default for Decimal {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::zero()
+	}
+}
+
 impl fmt::Debug for Decimal {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(f, "Decimal({:?})", self.data)
@@ -92,7 +122,7 @@ impl fmt::Debug for Decimal {
 /// more efficient.
 #[derive(Default)]
 pub struct DecimalCheck {
-	set: HashSet<BigInt>,
+	set: BTreeSet<BigInt>,
 }
 
 impl DecimalCheck {
@@ -105,10 +135,11 @@ impl DecimalCheck {
 			r.numer().clone()
 		};
 
+		let ten = BigInt::from(10u32);
 		rem %= r.denom();
 		while !rem.is_zero() && !self.set.contains(&rem) {
 			self.set.insert(rem.clone());
-			rem = (rem * TEN.clone()) % r.denom();
+			rem = (rem * &ten) % r.denom();
 		}
 
 		rem.is_zero()
@@ -125,10 +156,10 @@ pub fn is_decimal(r: &BigRational) -> bool {
 /// Returns the decimal lexical representation of the given rational number, if
 /// any.
 pub fn decimal_lexical_representation(r: &BigRational) -> Option<lexical::DecimalBuf> {
-	use std::fmt::Write;
+	use core::fmt::Write;
 
 	let mut fraction = String::new();
-	let mut map = std::collections::HashMap::new();
+	let mut map = BTreeMap::new();
 
 	let mut rem = if r.is_negative() {
 		-r.numer()
@@ -136,10 +167,11 @@ pub fn decimal_lexical_representation(r: &BigRational) -> Option<lexical::Decima
 		r.numer().clone()
 	};
 
+	let ten = BigInt::from(10u32);
 	rem %= r.denom();
 	while !rem.is_zero() && !map.contains_key(&rem) {
 		map.insert(rem.clone(), fraction.len());
-		rem *= TEN.clone();
+		rem *= &ten;
 		fraction.push_str(&(rem.clone() / r.denom()).to_string());
 		rem %= r.denom();
 	}
@@ -150,7 +182,7 @@ pub fn decimal_lexical_representation(r: &BigRational) -> Option<lexical::Decima
 		String::new()
 	};
 
-	output.push_str(&(r.numer() / r.denom()).to_string());
+	output.push_str(&(r.numer() / r.denom()).abs().to_string());
 
 	if rem.is_zero() {
 		if !fraction.is_empty() {
@@ -204,35 +236,243 @@ impl Decimal {
 		self.data.is_negative()
 	}
 
-	pub fn decimal_type(&self) -> Option<DecimalDatatype> {
-		if self.data.is_integer() {
-			if self.data >= BigRational::zero() {
-				if self.data > BigRational::zero() {
-					if self.data <= *U8_MAX_RATIO {
-						Some(UnsignedShortDatatype::UnsignedByte.into())
-					} else if self.data <= *U16_MAX_RATIO {
-						Some(UnsignedIntDatatype::UnsignedShort(None).into())
-					} else if self.data <= *U32_MAX_RATIO {
-						Some(UnsignedLongDatatype::UnsignedInt(None).into())
-					} else if self.data <= *U64_MAX_RATIO {
-						Some(NonNegativeIntegerDatatype::UnsignedLong(None).into())
-					} else {
-						Some(NonNegativeIntegerDatatype::PositiveInteger.into())
-					}
+	/// Converts a `f64` into the decimal with the shortest representation
+	/// that parses back into the same `f64`.
+	///
+	/// Unlike the exact binary value of `f`, this does not carry
+	/// representation noise: `0.1f64` gives `0.1`, not
+	/// `0.1000000000000000055511151231257827021181583404541015625`.
+	/// Returns `None` if `f` is NaN or infinite.
+	pub fn from_f64_exact(f: f64) -> Option<Self> {
+		if f.is_finite() {
+			// The `Display` implementation of floats prints the shortest
+			// representation that round-trips, without exponent.
+			Some(f.to_string().parse().unwrap())
+		} else {
+			None
+		}
+	}
+
+	/// Converts a `f32` into the decimal with the shortest representation
+	/// that parses back into the same `f32`.
+	///
+	/// See [`Self::from_f64_exact`].
+	pub fn from_f32_exact(f: f32) -> Option<Self> {
+		if f.is_finite() {
+			Some(f.to_string().parse().unwrap())
+		} else {
+			None
+		}
+	}
+
+	/// Creates a decimal number equal to `value × 10^-scale`.
+	///
+	/// A negative scale appends zeros to the integer part: `with_scale(12, -2)`
+	/// is `1200`.
+	pub fn with_scale(value: Integer, scale: i32) -> Self {
+		let n: BigInt = value.into();
+		let factor = num_traits::pow(BigInt::from(10u32), scale.unsigned_abs() as usize);
+		let data = if scale < 0 {
+			BigRational::from(n * factor)
+		} else {
+			BigRational::new(n, factor)
+		};
+
+		unsafe {
+			// Safe because the denominator is a power of ten.
+			Self::new_unchecked(data)
+		}
+	}
+
+	/// Returns the unscaled value and scale of this number, such that
+	/// `self = unscaled × 10^-scale`, with no trailing zeros in the unscaled
+	/// value.
+	fn unscaled_and_scale(&self) -> (BigInt, i32) {
+		let ten = BigInt::from(10u32);
+
+		let mut scale = 0i32;
+		let mut r = self.data.clone();
+		while !r.is_integer() {
+			// Terminates since the number has a finite decimal
+			// representation.
+			r *= &ten;
+			scale += 1
+		}
+
+		let mut unscaled = r.to_integer();
+
+		if !unscaled.is_zero() {
+			while (&unscaled % &ten).is_zero() {
+				unscaled /= &ten;
+				scale -= 1
+			}
+		}
+
+		(unscaled, scale)
+	}
+
+	/// Returns the coefficient of this number, without trailing zeros.
+	///
+	/// See [`Self::scale`].
+	pub fn unscaled_value(&self) -> Integer {
+		self.unscaled_and_scale().0.into()
+	}
+
+	/// Returns the scale of this number, such that
+	/// `self = self.unscaled_value() × 10^-self.scale()`.
+	///
+	/// This is the number of digits after the decimal point, or minus the
+	/// number of trailing zeros of the integer part if there is no fractional
+	/// part (`1.25` has scale `2`, `1200` has scale `-2`). Zero has scale `0`.
+	pub fn scale(&self) -> i32 {
+		self.unscaled_and_scale().1
+	}
+
+	/// Returns the number of significant digits of this number, which is the
+	/// number of digits of [`Self::unscaled_value`].
+	///
+	/// Zero has a precision of `1`.
+	pub fn precision(&self) -> u32 {
+		let (unscaled, _) = self.unscaled_and_scale();
+		unscaled.magnitude().to_string().len() as u32
+	}
+
+	/// Largest absolute exponent accepted by [`Self::from_str_scientific`].
+	///
+	/// This is large enough for any finite `f64` value.
+	pub const MAX_SCIENTIFIC_EXPONENT: u32 = 1000;
+
+	/// Parses a decimal number written in scientific notation, such as
+	/// `1.23e4` or `2.5E-2`.
+	///
+	/// This is **not** an XSD parsing path: the lexical space of `xsd:decimal`
+	/// has no exponent. The mantissa must be a valid XSD decimal and the
+	/// exponent, if any, an optionally signed sequence of digits. The result
+	/// is a regular decimal number, so `"1.23e4"` gives the same value as
+	/// `"12300"`.
+	///
+	/// The absolute value of the exponent cannot exceed
+	/// [`Self::MAX_SCIENTIFIC_EXPONENT`], so that untrusted inputs cannot
+	/// trigger arbitrarily large computations.
+	pub fn from_str_scientific(s: &str) -> Result<Self, lexical::InvalidDecimal> {
+		match s.find(['e', 'E']) {
+			Some(i) => {
+				let mantissa: Self = s[..i].parse()?;
+				let exponent = &s[(i + 1)..];
+				let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+				if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+					return Err(lexical::InvalidDecimal);
+				}
+
+				let e: i32 = exponent.parse().map_err(|_| lexical::InvalidDecimal)?;
+				if e.unsigned_abs() > Self::MAX_SCIENTIFIC_EXPONENT {
+					return Err(lexical::InvalidDecimal);
+				}
+
+				let scale = num_traits::pow(BigInt::from(10u32), e.unsigned_abs() as usize);
+				let data = if e < 0 {
+					mantissa.data / scale
 				} else {
-					Some(UnsignedShortDatatype::UnsignedByte.into())
+					mantissa.data * scale
+				};
+
+				unsafe {
+					// Safe because decimal numbers are closed under
+					// multiplication and division by a power of ten.
+					Ok(Self::new_unchecked(data))
 				}
-			} else if self.data >= *I8_MIN_RATIO {
-				Some(ShortDatatype::Byte.into())
-			} else if self.data >= *I16_MIN_RATIO {
-				Some(IntDatatype::Short(None).into())
-			} else if self.data >= *I32_MIN_RATIO {
-				Some(LongDatatype::Int(None).into())
-			} else if self.data >= *I64_MIN_RATIO {
-				Some(IntegerDatatype::Long(None).into())
-			} else {
-				Some(NonPositiveIntegerDatatype::NegativeInteger.into())
 			}
+			None => s.parse(),
+		}
+	}
+
+	/// Converts a `f64` into a decimal by going through its scientific
+	/// notation.
+	///
+	/// This is **not** an XSD conversion: it formats `f` with the shortest
+	/// mantissa that round-trips (e.g. `1.5e3`) and parses it back with
+	/// [`Self::from_str_scientific`]. The result is the same value as
+	/// [`Self::from_f64_exact`]. Returns `None` if `f` is NaN or infinite.
+	pub fn from_f64_scientific(f: f64) -> Option<Self> {
+		if f.is_finite() {
+			Some(Self::from_str_scientific(&format!("{f:e}")).unwrap())
+		} else {
+			None
+		}
+	}
+
+	/// Returns `-1`, `0` or `1` depending on the sign of this number.
+	pub fn signum(&self) -> Self {
+		unsafe {
+			// Safe because -1, 0 and 1 are decimal numbers.
+			Self::new_unchecked(self.data.signum())
+		}
+	}
+
+	/// Adds two decimal numbers.
+	///
+	/// Decimal numbers have an arbitrary precision, so this never fails and
+	/// always returns `Some`. It is provided for consistency with
+	/// [`Self::checked_div`].
+	pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		unsafe {
+			// Safe because decimal numbers are closed under addition.
+			Some(Self::new_unchecked(&self.data + &rhs.data))
+		}
+	}
+
+	/// Subtracts two decimal numbers.
+	///
+	/// This never fails and always returns `Some`. See [`Self::checked_add`].
+	pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		unsafe {
+			// Safe because decimal numbers are closed under subtraction.
+			Some(Self::new_unchecked(&self.data - &rhs.data))
+		}
+	}
+
+	/// Multiplies two decimal numbers.
+	///
+	/// This never fails and always returns `Some`. See [`Self::checked_add`].
+	pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+		unsafe {
+			// Safe because decimal numbers are closed under multiplication.
+			Some(Self::new_unchecked(&self.data * &rhs.data))
+		}
+	}
+
+	/// Divides two decimal numbers.
+	///
+	/// Returns `None` if `rhs` is zero, or if the quotient has no finite
+	/// decimal representation (e.g. `1 / 3`).
+	pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+		if rhs.is_zero() {
+			None
+		} else {
+			(&self.data / &rhs.data).try_into().ok()
+		}
+	}
+
+	/// Adds two decimal numbers.
+	///
+	/// Since decimal numbers are not bounded, there is no maximum to saturate
+	/// at and this is the same as an unchecked addition.
+	pub fn saturating_add(&self, rhs: &Self) -> Self {
+		self.checked_add(rhs).unwrap()
+	}
+
+	/// Subtracts two decimal numbers.
+	///
+	/// See [`Self::saturating_add`].
+	pub fn saturating_sub(&self, rhs: &Self) -> Self {
+		self.checked_sub(rhs).unwrap()
+	}
+
+	pub fn decimal_type(&self) -> Option<DecimalDatatype> {
+		if self.data.is_integer() {
+			Integer::from_bigint_ref(self.data.numer())
+				.integer_type()
+				.map(Into::into)
 		} else {
 			None
 		}
@@ -245,6 +485,42 @@ impl Decimal {
 	}
 }
 
+impl Sum for Decimal {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		unsafe {
+			// Safe because decimal numbers are closed under addition.
+			Self::new_unchecked(iter.map(|d| d.data).sum())
+		}
+	}
+}
+
+impl<'a> Sum<&'a Decimal> for Decimal {
+	fn sum<I: Iterator<Item = &'a Decimal>>(iter: I) -> Self {
+		unsafe {
+			// Safe because decimal numbers are closed under addition.
+			Self::new_unchecked(iter.map(|d| &d.data).sum())
+		}
+	}
+}
+
+impl Product for Decimal {
+	fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+		unsafe {
+			// Safe because decimal numbers are closed under multiplication.
+			Self::new_unchecked(iter.map(|d| d.data).product())
+		}
+	}
+}
+
+impl<'a> Product<&'a Decimal> for Decimal {
+	fn product<I: Iterator<Item = &'a Decimal>>(iter: I) -> Self {
+		unsafe {
+			// Safe because decimal numbers are closed under multiplication.
+			Self::new_unchecked(iter.map(|d| &d.data).product())
+		}
+	}
+}
+
 impl fmt::Display for Decimal {
 	#[inline(always)]
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -262,14 +538,20 @@ impl<'a> From<&'a lexical::Decimal> for Decimal {
 impl From<lexical::DecimalBuf> for Decimal {
 	#[inline(always)]
 	fn from(value: lexical::DecimalBuf) -> Self {
-		let numer: BigInt = value.integer_part().as_str().parse().unwrap();
 		let data = match value.fractional_part() {
 			Some(fract) => {
-				let f = BigRational::new(1.into(), fract.as_str().len().into());
-				let fract: BigRational = fract.as_str().parse().unwrap();
-				BigRational::from(numer) + fract * f
+				let mut digits = value.integer_part().as_str().to_owned();
+				digits.push_str(fract.as_str());
+				let numer: BigInt = digits.parse().unwrap();
+				let denom = num_traits::pow(BigInt::from(10u32), fract.as_str().len());
+				BigRational::new(numer, denom)
 			}
-			None => numer.into(),
+			None => value
+				.integer_part()
+				.as_str()
+				.parse::<BigInt>()
+				.unwrap()
+				.into(),
 		};
 
 		Self {
@@ -332,8 +614,12 @@ impl Deref for Decimal {
 
 /// Error raised when trying to create a decimal value from a rational without
 /// finite decimal representation.
-#[derive(Debug, thiserror::Error)]
-#[error("no decimal representation for rational number {0}")]
+#[derive(Debug)]
+#[cfg_attr(
+	feature = "std",
+	derive(thiserror::Error),
+	error("no decimal representation for rational number {0}")
+)]
 pub struct NoDecimalRepresentation(pub BigRational);
 
 impl TryFrom<BigRational> for Decimal {
@@ -368,13 +654,14 @@ impl ParseRdf for Decimal {
 }
 
 impl LexicalFormOf<Decimal> for lexical::Decimal {
-	type ValueError = std::convert::Infallible;
+	type ValueError = core::convert::Infallible;
 
 	fn try_as_value(&self) -> Result<Decimal, Self::ValueError> {
 		Ok(self.value())
 	}
 }
 
+#[cfg(feature = "std")]
 #[derive(Debug, thiserror::Error)]
 pub enum NonDecimalFloat {
 	#[error("float is NaN")]
@@ -387,6 +674,7 @@ pub enum NonDecimalFloat {
 	NegativeInfinity,
 }
 
+#[cfg(feature = "std")]
 impl TryFrom<Float> for Decimal {
 	type Error = NonDecimalFloat;
 
@@ -408,6 +696,7 @@ impl TryFrom<Float> for Decimal {
 	}
 }
 
+#[cfg(feature = "std")]
 impl TryFrom<Double> for Decimal {
 	type Error = NonDecimalFloat;
 
@@ -425,3 +714,253 @@ impl TryFrom<Double> for Decimal {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		let tests = [
+			("1.5", (3, 2)),
+			("1.50", (3, 2)),
+			("-1.25", (-5, 4)),
+			(".5", (1, 2)),
+			("-.05", (-1, 20)),
+			("12", (12, 1)),
+		];
+
+		for (input, (numer, denom)) in tests {
+			let d: Decimal = input.parse().unwrap();
+			assert_eq!(
+				*d.as_big_rational(),
+				BigRational::new(numer.into(), denom.into())
+			)
+		}
+	}
+
+	#[test]
+	fn cmp_01() {
+		let a: Decimal = "0.999999999999999999999999999".parse().unwrap();
+		let b: Decimal = "1.0".parse().unwrap();
+		assert!(a < b);
+		assert!(b > a);
+		assert_eq!(b, "1.000".parse::<Decimal>().unwrap());
+		assert!("-0.5".parse::<Decimal>().unwrap() < "-0.25".parse::<Decimal>().unwrap())
+	}
+
+	#[test]
+	fn eq_01() {
+		for (a, b) in [
+			("2.0", "2"),
+			("10.00", "10"),
+			("0.0", "0"),
+			("-0.0", "0"),
+			("-0", "+0.00"),
+		] {
+			let a: Decimal = a.parse().unwrap();
+			let b: Decimal = b.parse().unwrap();
+			assert_eq!(a, b);
+			assert_eq!(a.as_big_rational(), b.as_big_rational())
+		}
+	}
+
+	#[test]
+	fn cmp_integer_01() {
+		let one = Integer::from(1i64);
+		let a: Decimal = "0.999999999999999999999999999".parse().unwrap();
+		assert!(a < one);
+		assert!(one > a);
+		assert!("1.0".parse::<Decimal>().unwrap() == one);
+		assert!(one == "1.00".parse::<Decimal>().unwrap());
+		assert!("-1.5".parse::<Decimal>().unwrap() < Integer::from(-1i64));
+		assert!(Integer::from(-2i64) < "-1.5".parse::<Decimal>().unwrap())
+	}
+
+	#[test]
+	fn checked_01() {
+		let a: Decimal = "1.5".parse().unwrap();
+		let b: Decimal = "0.25".parse().unwrap();
+		assert_eq!(a.checked_add(&b), Some("1.75".parse().unwrap()));
+		assert_eq!(a.checked_sub(&b), Some("1.25".parse().unwrap()));
+		assert_eq!(a.checked_mul(&b), Some("0.375".parse().unwrap()));
+		assert_eq!(a.checked_div(&b), Some("6".parse().unwrap()));
+	}
+
+	#[test]
+	fn checked_02() {
+		let a: Decimal = "1".parse().unwrap();
+		assert_eq!(a.checked_div(&Decimal::zero()), None);
+		assert_eq!(a.checked_div(&"3".parse().unwrap()), None);
+	}
+
+	#[test]
+	fn signum_01() {
+		assert_eq!(
+			"-0.5".parse::<Decimal>().unwrap().signum(),
+			"-1".parse::<Decimal>().unwrap()
+		);
+		assert_eq!(Decimal::zero().signum(), Decimal::zero());
+		assert!(Decimal::zero().is_zero());
+		assert_eq!(
+			"12.5".parse::<Decimal>().unwrap().signum(),
+			"1".parse::<Decimal>().unwrap()
+		)
+	}
+
+	#[test]
+	fn from_float_exact_01() {
+		for f in [
+			0.1f64,
+			-0.1,
+			1.0 / 3.0,
+			1e-300,
+			1.7976931348623157e308,
+			0.0,
+			-0.0,
+			42.0,
+		] {
+			let d = Decimal::from_f64_exact(f).unwrap();
+			let s = decimal_lexical_representation(d.as_big_rational()).unwrap();
+			assert_eq!(s.as_str().parse::<f64>().unwrap(), f)
+		}
+
+		assert_eq!(
+			Decimal::from_f64_exact(0.1).unwrap(),
+			"0.1".parse::<Decimal>().unwrap()
+		);
+		assert_eq!(Decimal::from_f64_exact(f64::NAN), None);
+		assert_eq!(Decimal::from_f64_exact(f64::NEG_INFINITY), None)
+	}
+
+	#[test]
+	fn from_float_exact_02() {
+		assert_eq!(
+			Decimal::from_f32_exact(0.1).unwrap(),
+			"0.1".parse::<Decimal>().unwrap()
+		);
+		assert_eq!(
+			Decimal::from_f32_exact(16777216.0).unwrap(),
+			"16777216".parse::<Decimal>().unwrap()
+		);
+		assert_eq!(Decimal::from_f32_exact(f32::INFINITY), None)
+	}
+
+	#[test]
+	fn default_01() {
+		assert!(Decimal::default().is_zero());
+		assert_eq!(Decimal::default().to_string(), "0")
+	}
+
+	#[test]
+	fn from_str_scientific_01() {
+		for (input, expected) in [
+			("1.5e3", "1500"),
+			("2.5e-2", "0.025"),
+			("1.23E4", "12300"),
+			("-1.23e+4", "-12300"),
+			("12e0", "12"),
+			(".5e1", "5"),
+			("42", "42"),
+		] {
+			assert_eq!(
+				Decimal::from_str_scientific(input).unwrap(),
+				expected.parse::<Decimal>().unwrap(),
+				"{input}"
+			)
+		}
+
+		assert_eq!(
+			Decimal::from_str_scientific("2.5e-2").unwrap().to_string(),
+			"0.025"
+		);
+
+		for input in ["e3", "1.5e", "1.5e+", "1.5e3.0", "1.5ee3", "1e3x", "NaN"] {
+			assert!(Decimal::from_str_scientific(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn from_str_scientific_02() {
+		assert!(Decimal::from_str_scientific("1e1000").is_ok());
+		assert!(Decimal::from_str_scientific("1e-1000").is_ok());
+		for input in ["1e1001", "1e-1001", "1e999999999", "1e99999999999"] {
+			assert!(Decimal::from_str_scientific(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn from_f64_scientific_01() {
+		assert_eq!(
+			Decimal::from_f64_scientific(1500.0).unwrap(),
+			"1500".parse::<Decimal>().unwrap()
+		);
+		assert_eq!(
+			Decimal::from_f64_scientific(0.025).unwrap(),
+			"0.025".parse::<Decimal>().unwrap()
+		);
+		assert_eq!(
+			Decimal::from_f64_scientific(1e-300),
+			Decimal::from_f64_exact(1e-300)
+		);
+		assert_eq!(Decimal::from_f64_scientific(f64::NAN), None)
+	}
+
+	#[test]
+	fn sum_01() {
+		let values: [Decimal; 3] = ["1.5", "-0.25", "2"].map(|s| s.parse().unwrap());
+		assert_eq!(
+			values.iter().sum::<Decimal>(),
+			"3.25".parse::<Decimal>().unwrap()
+		);
+		assert_eq!(
+			values.iter().product::<Decimal>(),
+			"-0.75".parse::<Decimal>().unwrap()
+		);
+		assert_eq!(values.into_iter().sum::<Decimal>().to_string(), "3.25");
+		assert!(core::iter::empty::<Decimal>().sum::<Decimal>().is_zero());
+		assert_eq!(
+			core::iter::empty::<Decimal>().product::<Decimal>(),
+			"1".parse::<Decimal>().unwrap()
+		)
+	}
+
+	#[test]
+	fn scale_01() {
+		for (input, unscaled, scale, precision) in [
+			("1.25", 125, 2, 3),
+			("-1.250", -125, 2, 3),
+			("1200", 12, -2, 2),
+			("0.001", 1, 3, 1),
+			("10.5", 105, 1, 3),
+			("7", 7, 0, 1),
+			("0", 0, 0, 1),
+		] {
+			let d: Decimal = input.parse().unwrap();
+			assert_eq!(d.unscaled_value(), Integer::from(unscaled), "{input}");
+			assert_eq!(d.scale(), scale, "{input}");
+			assert_eq!(d.precision(), precision, "{input}");
+			assert_eq!(
+				Decimal::with_scale(d.unscaled_value(), d.scale()),
+				d,
+				"{input}"
+			)
+		}
+	}
+
+	#[test]
+	fn with_scale_01() {
+		assert_eq!(
+			Decimal::with_scale(Integer::from(12i64), -2).to_string(),
+			"1200"
+		);
+		assert_eq!(
+			Decimal::with_scale(Integer::from(-1250i64), 3).to_string(),
+			"-1.25"
+		);
+		assert_eq!(
+			Decimal::with_scale(Integer::from(-12i64), 0).to_string(),
+			"-12"
+		)
+	}
+}
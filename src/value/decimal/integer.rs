@@ -1,12 +1,14 @@
-use std::{
+use alloc::{format, string::String, vec::Vec};
+use core::{
 	borrow::Borrow,
 	fmt,
-	ops::{Add, Div, Mul, Sub},
+	iter::{Product, Sum},
+	ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Shl, Shr, Sub},
 	str::FromStr,
 };
 
 use num_bigint::{BigInt, TryFromBigIntError};
-use num_traits::{Signed, Zero};
+use num_traits::{One, Signed, Zero};
 
 use crate::{
 	lexical::{self, LexicalFormOf},
@@ -15,13 +17,15 @@ use crate::{
 	UnsignedShortDatatype, XsdDatatype,
 };
 
-use super::{Sign, I16_MIN, I32_MIN, I64_MIN, I8_MIN, U16_MAX, U32_MAX, U64_MAX, U8_MAX};
+use super::Sign;
 
 mod non_negative_integer;
 mod non_positive_integer;
+mod range;
 
 pub use non_negative_integer::*;
 pub use non_positive_integer::*;
+pub use range::*;
 
 /// Integer number.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
@@ -35,7 +39,7 @@ impl Integer {
 		unsafe {
 			// This is safe because `Integer` is a transparent wrapper around
 			// `BigInt`.
-			std::mem::transmute(n)
+			core::mem::transmute(n)
 		}
 	}
 
@@ -60,6 +64,30 @@ impl Integer {
 		Self(BigInt::zero())
 	}
 
+	#[inline(always)]
+	pub fn one() -> Self {
+		Self(BigInt::one())
+	}
+
+	#[inline(always)]
+	pub fn minus_one() -> Self {
+		Self(-BigInt::one())
+	}
+
+	/// Returns the smallest integer, which does not exist since integers are
+	/// unbounded.
+	#[inline(always)]
+	pub fn min_value() -> Option<Self> {
+		None
+	}
+
+	/// Returns the largest integer, which does not exist since integers are
+	/// unbounded.
+	#[inline(always)]
+	pub fn max_value() -> Option<Self> {
+		None
+	}
+
 	#[inline(always)]
 	pub fn is_zero(&self) -> bool {
 		self.0.is_zero()
@@ -78,13 +106,13 @@ impl Integer {
 	pub fn integer_type(&self) -> Option<IntegerDatatype> {
 		if self.0 >= BigInt::zero() {
 			if self.0 > BigInt::zero() {
-				if self.0 <= *U8_MAX {
+				if u8::try_from(&self.0).is_ok() {
 					Some(UnsignedShortDatatype::UnsignedByte.into())
-				} else if self.0 <= *U16_MAX {
+				} else if u16::try_from(&self.0).is_ok() {
 					Some(UnsignedIntDatatype::UnsignedShort(None).into())
-				} else if self.0 <= *U32_MAX {
+				} else if u32::try_from(&self.0).is_ok() {
 					Some(UnsignedLongDatatype::UnsignedInt(None).into())
-				} else if self.0 <= *U64_MAX {
+				} else if u64::try_from(&self.0).is_ok() {
 					Some(NonNegativeIntegerDatatype::UnsignedLong(None).into())
 				} else {
 					Some(NonNegativeIntegerDatatype::PositiveInteger.into())
@@ -92,13 +120,13 @@ impl Integer {
 			} else {
 				Some(UnsignedShortDatatype::UnsignedByte.into())
 			}
-		} else if self.0 >= *I8_MIN {
+		} else if i8::try_from(&self.0).is_ok() {
 			Some(ShortDatatype::Byte.into())
-		} else if self.0 >= *I16_MIN {
+		} else if i16::try_from(&self.0).is_ok() {
 			Some(IntDatatype::Short(None).into())
-		} else if self.0 >= *I32_MIN {
+		} else if i32::try_from(&self.0).is_ok() {
 			Some(LongDatatype::Int(None).into())
-		} else if self.0 >= *I64_MIN {
+		} else if i64::try_from(&self.0).is_ok() {
 			Some(IntegerDatatype::Long(None))
 		} else {
 			Some(NonPositiveIntegerDatatype::NegativeInteger.into())
@@ -130,6 +158,260 @@ impl Integer {
 	pub fn to_signed_bytes_le(&self) -> Vec<u8> {
 		self.0.to_signed_bytes_le()
 	}
+
+	/// Returns the binary representation of this integer on `bit_width`
+	/// bits, most significant bit first.
+	///
+	/// If `signed` is `true`, the two's complement representation is
+	/// returned. Otherwise the integer must not be negative.
+	pub fn to_bits(
+		&self,
+		bit_width: u32,
+		signed: bool,
+	) -> Result<Vec<bool>, IntegerRepresentationError> {
+		let required_width = if signed {
+			if self.0.is_negative() {
+				(-&self.0 - 1u32).bits() + 1
+			} else {
+				self.0.bits() + 1
+			}
+		} else if self.0.is_negative() {
+			return Err(IntegerRepresentationError::ValueIsNegativeButUnsignedRequested);
+		} else {
+			self.0.bits()
+		};
+
+		if required_width > bit_width as u64 {
+			return Err(IntegerRepresentationError::ValueTooLarge);
+		}
+
+		// `BigInt::bit` follows the two's complement representation.
+		Ok((0..bit_width as u64).rev().map(|i| self.0.bit(i)).collect())
+	}
+
+	/// Creates an integer from its binary representation, most significant
+	/// bit first.
+	///
+	/// If `signed` is `true`, `bits` is read as a two's complement
+	/// representation. The empty sequence represents zero.
+	pub fn from_bits(bits: &[bool], signed: bool) -> Self {
+		let n = bits.iter().fold(BigInt::zero(), |n, b| {
+			(n << 1u32) + if *b { BigInt::one() } else { BigInt::zero() }
+		});
+
+		if signed && bits.first().copied().unwrap_or(false) {
+			Self(n - (BigInt::one() << bits.len()))
+		} else {
+			Self(n)
+		}
+	}
+
+	/// Returns the representation of this integer in the given radix, using
+	/// lowercase letters for digits above 9.
+	///
+	/// # Panics
+	///
+	/// Panics if `radix` is not in the range `2..=36`.
+	pub fn to_radix_string(&self, radix: u32) -> String {
+		self.0.to_str_radix(radix)
+	}
+
+	impl_to_primitive! {
+		to_i8: i8, to_i16: i16, to_i32: i32, to_i64: i64, to_isize: isize,
+		to_u8: u8, to_u16: u16, to_u32: u32, to_u64: u64, to_usize: usize
+	}
+
+	/// Parses an integer written in the given radix, with an optional leading
+	/// sign. Digits above 9 are case insensitive.
+	///
+	/// The radix must be in the range `2..=36`. Unlike the [`FromStr`]
+	/// implementation, this does not follow the XSD lexical space.
+	pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseRadixError> {
+		parse_radix(s, radix).map(Self)
+	}
+
+	/// Parses a hexadecimal integer, with an optional leading sign and an
+	/// optional `0x` (or `0X`) prefix. Digits are case insensitive.
+	///
+	/// For instance `0xFF`, `0xff`, `FF` and `ff` are all parsed as `255`.
+	/// The result is an integer value, not an `xsd:hexBinary` value.
+	pub fn from_hex(s: &str) -> Result<Self, ParseHexError> {
+		parse_hex(s).map(Self)
+	}
+
+	/// Returns `-1`, `0` or `1` depending on the sign of this integer.
+	pub fn signum(&self) -> i8 {
+		match self.0.sign() {
+			Sign::Minus => -1,
+			Sign::NoSign => 0,
+			Sign::Plus => 1,
+		}
+	}
+
+	/// Returns the absolute value of this integer.
+	pub fn abs(&self) -> NonNegativeInteger {
+		unsafe {
+			// This is safe because an absolute value is non negative.
+			NonNegativeInteger::new_unchecked(self.0.abs())
+		}
+	}
+
+	/// Returns the smallest of `self` and `other`.
+	///
+	/// Note that on an owned `Integer`, method call syntax resolves to
+	/// [`Ord::min`] instead. The same goes for [`Self::max`] and
+	/// [`Self::clamp`].
+	pub fn min(&self, other: &Self) -> Self {
+		Ord::min(self, other).clone()
+	}
+
+	/// Returns the largest of `self` and `other`.
+	pub fn max(&self, other: &Self) -> Self {
+		Ord::max(self, other).clone()
+	}
+
+	/// Restricts this integer to the `[min, max]` interval.
+	///
+	/// # Panics
+	///
+	/// Panics if `min > max`.
+	pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+		Ord::clamp(self, min, max).clone()
+	}
+
+	/// Adds two integers.
+	///
+	/// Integers are not bounded, so this never fails and always returns
+	/// `Some`. It is provided for consistency with the bounded integer types.
+	pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		Some(Self(&self.0 + &rhs.0))
+	}
+
+	/// Subtracts two integers.
+	///
+	/// This never fails and always returns `Some`. See [`Self::checked_add`].
+	pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		Some(Self(&self.0 - &rhs.0))
+	}
+
+	/// Multiplies two integers.
+	///
+	/// This never fails and always returns `Some`. See [`Self::checked_add`].
+	pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+		Some(Self(&self.0 * &rhs.0))
+	}
+}
+
+/// Error raised when an integer cannot be represented on a given number of
+/// bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum IntegerRepresentationError {
+	/// The integer does not fit in the given number of bits.
+	#[cfg_attr(feature = "std", error("value too large for the bit width"))]
+	ValueTooLarge,
+
+	/// The integer is negative, but an unsigned representation was
+	/// requested.
+	#[cfg_attr(
+		feature = "std",
+		error("negative value cannot have an unsigned representation")
+	)]
+	ValueIsNegativeButUnsignedRequested,
+}
+
+/// Error raised when parsing an integer in a non-decimal radix fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum ParseRadixError {
+	/// The radix is not in the range `2..=36`.
+	#[cfg_attr(feature = "std", error("invalid radix {0}"))]
+	InvalidRadix(u32),
+
+	/// The input contains a character that is not a digit in the given
+	/// radix.
+	#[cfg_attr(feature = "std", error("invalid digit `{0}` in radix {1}"))]
+	InvalidDigit(char, u32),
+
+	/// The input has no digits.
+	#[cfg_attr(feature = "std", error("empty input"))]
+	EmptyInput,
+
+	/// The parsed integer is outside the value space of the target type.
+	#[cfg_attr(feature = "std", error("integer out of bounds"))]
+	OutOfBounds,
+}
+
+/// Error raised when parsing a hexadecimal integer fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum ParseHexError {
+	/// The input contains a character that is not a hexadecimal digit.
+	#[cfg_attr(feature = "std", error("invalid hexadecimal digit `{0}`"))]
+	InvalidDigit(char),
+
+	/// The input has no digits.
+	#[cfg_attr(feature = "std", error("empty input"))]
+	EmptyInput,
+
+	/// The parsed integer is negative, but the target type is not.
+	#[cfg_attr(feature = "std", error("negative value"))]
+	NegativeValue,
+
+	/// The parsed integer is zero, but the target type is positive.
+	#[cfg_attr(feature = "std", error("zero value"))]
+	ZeroValue,
+}
+
+pub(crate) fn parse_hex(s: &str) -> Result<BigInt, ParseHexError> {
+	let (sign, s) = s.split_at(usize::from(s.starts_with(['-', '+'])));
+	let digits = s
+		.strip_prefix("0x")
+		.or_else(|| s.strip_prefix("0X"))
+		.unwrap_or(s);
+
+	// The sign must come before the prefix.
+	if let Some(c @ ('-' | '+')) = digits.chars().next() {
+		return Err(ParseHexError::InvalidDigit(c));
+	}
+
+	let n = parse_radix(digits, 16).map_err(|e| match e {
+		ParseRadixError::InvalidDigit(c, _) => ParseHexError::InvalidDigit(c),
+		ParseRadixError::EmptyInput => ParseHexError::EmptyInput,
+		ParseRadixError::InvalidRadix(_) | ParseRadixError::OutOfBounds => unreachable!(),
+	})?;
+
+	Ok(if sign == "-" { -n } else { n })
+}
+
+pub(crate) fn parse_radix(s: &str, radix: u32) -> Result<BigInt, ParseRadixError> {
+	if !(2..=36).contains(&radix) {
+		return Err(ParseRadixError::InvalidRadix(radix));
+	}
+
+	let (negative, digits) = match s.as_bytes().first() {
+		Some(b'-') => (true, &s[1..]),
+		Some(b'+') => (false, &s[1..]),
+		_ => (false, s),
+	};
+
+	if digits.is_empty() {
+		return Err(ParseRadixError::EmptyInput);
+	}
+
+	if let Some(c) = digits.chars().find(|c| !c.is_digit(radix)) {
+		return Err(ParseRadixError::InvalidDigit(c, radix));
+	}
+
+	let n = BigInt::parse_bytes(digits.as_bytes(), radix).unwrap();
+	Ok(if negative { -n } else { n })
+}
+
+impl Default for Integer {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::zero()
+	}
 }
 
 impl XsdDatatype for Integer {
@@ -144,7 +426,7 @@ impl ParseRdf for Integer {
 }
 
 impl LexicalFormOf<Integer> for lexical::Integer {
-	type ValueError = std::convert::Infallible;
+	type ValueError = core::convert::Infallible;
 
 	fn try_as_value(&self) -> Result<Integer, Self::ValueError> {
 		Ok(self.value())
@@ -165,6 +447,13 @@ impl From<Integer> for BigInt {
 	}
 }
 
+impl<'a> From<&'a Integer> for BigInt {
+	#[inline(always)]
+	fn from(value: &'a Integer) -> Self {
+		value.0.clone()
+	}
+}
+
 impl<'a> From<&'a lexical::Integer> for Integer {
 	#[inline(always)]
 	fn from(value: &'a lexical::Integer) -> Self {
@@ -223,8 +512,12 @@ impl Borrow<BigInt> for Integer {
 	}
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("integer out of supported bounds: {0}")]
+#[derive(Debug)]
+#[cfg_attr(
+	feature = "std",
+	derive(thiserror::Error),
+	error("integer out of supported bounds: {0}")
+)]
 pub struct IntegerOutOfTargetBounds(pub Integer);
 
 macro_rules! from {
@@ -239,7 +532,7 @@ macro_rules! from {
 	};
 }
 
-from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
 macro_rules! try_into {
 	{ $( $ty:ty ),* } => {
@@ -259,6 +552,11 @@ try_into!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
 
 pub type Long = i64;
 
+/// Sub-type of a `Long` value.
+///
+/// A `Long` value always has the `xsd:long` [`XsdDatatype`], even when it
+/// fits in a narrower type. This trait returns the most specific sub-type
+/// able to represent the value instead, or `None` if there is none.
 pub trait XsdLong {
 	fn long_type(&self) -> Option<LongDatatype>;
 }
@@ -279,7 +577,7 @@ impl XsdLong for Long {
 
 impl XsdDatatype for Long {
 	fn type_(&self) -> Datatype {
-		self.long_type().into()
+		IntegerDatatype::Long(None).into()
 	}
 }
 
@@ -315,7 +613,7 @@ impl XsdInt for Int {
 
 impl XsdDatatype for Int {
 	fn type_(&self) -> Datatype {
-		self.int_type().into()
+		LongDatatype::Int(None).into()
 	}
 }
 
@@ -349,7 +647,7 @@ impl XsdShort for Short {
 
 impl XsdDatatype for Short {
 	fn type_(&self) -> Datatype {
-		self.short_type().into()
+		IntDatatype::Short(None).into()
 	}
 }
 
@@ -458,3 +756,472 @@ impl_integer_arithmetic! {
 		Integer [.0], i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
 	}
 }
+
+impl Sum for Integer {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		Self(iter.map(|n| n.0).sum())
+	}
+}
+
+impl<'a> Sum<&'a Integer> for Integer {
+	fn sum<I: Iterator<Item = &'a Integer>>(iter: I) -> Self {
+		Self(iter.map(|n| &n.0).sum())
+	}
+}
+
+impl Sum<NonNegativeInteger> for Integer {
+	fn sum<I: Iterator<Item = NonNegativeInteger>>(iter: I) -> Self {
+		Self(iter.map(NonNegativeInteger::into_big_int).sum())
+	}
+}
+
+impl Sum<PositiveInteger> for Integer {
+	fn sum<I: Iterator<Item = PositiveInteger>>(iter: I) -> Self {
+		Self(iter.map(PositiveInteger::into_big_int).sum())
+	}
+}
+
+impl Product for Integer {
+	fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+		Self(iter.map(|n| n.0).product())
+	}
+}
+
+impl<'a> Product<&'a Integer> for Integer {
+	fn product<I: Iterator<Item = &'a Integer>>(iter: I) -> Self {
+		Self(iter.map(|n| &n.0).product())
+	}
+}
+
+/// Checked conversions into primitive integers, to be invoked inside an
+/// `impl` block.
+macro_rules! impl_to_primitive {
+	($($method:ident: $ty:ty),*) => {
+		$(
+			#[doc = concat!("Converts this integer into `", stringify!($ty), "`, returning `None` if it is out of range.")]
+			#[inline]
+			pub fn $method(&self) -> Option<$ty> {
+				<$ty>::try_from(&self.0).ok()
+			}
+		)*
+	};
+}
+
+pub(crate) use impl_to_primitive;
+
+/// Comparisons with primitive integers.
+macro_rules! impl_integer_cmp {
+	(for $target:ident { $($ty:ty),* }) => {
+		$(
+			impl PartialEq<$ty> for $target {
+				fn eq(&self, other: &$ty) -> bool {
+					self.0 == BigInt::from(*other)
+				}
+			}
+
+			impl PartialEq<$target> for $ty {
+				fn eq(&self, other: &$target) -> bool {
+					BigInt::from(*self) == other.0
+				}
+			}
+
+			impl PartialOrd<$ty> for $target {
+				fn partial_cmp(&self, other: &$ty) -> Option<core::cmp::Ordering> {
+					self.0.partial_cmp(&BigInt::from(*other))
+				}
+			}
+
+			impl PartialOrd<$target> for $ty {
+				fn partial_cmp(&self, other: &$target) -> Option<core::cmp::Ordering> {
+					BigInt::from(*self).partial_cmp(&other.0)
+				}
+			}
+		)*
+	};
+}
+
+pub(crate) use impl_integer_cmp;
+
+impl_integer_cmp! {
+	for Integer { i8, i16, i32, i64, isize, u8, u16, u32, u64, usize }
+}
+
+/// Bitwise operations, following the two's complement representation of
+/// integers with an infinite sign extension.
+macro_rules! impl_integer_bitwise {
+	($($ty:ident),*) => {
+		$(
+			impl BitAnd for $ty {
+				type Output = Self;
+
+				fn bitand(self, rhs: Self) -> Self::Output {
+					Self(self.0 & rhs.0)
+				}
+			}
+
+			impl BitOr for $ty {
+				type Output = Self;
+
+				fn bitor(self, rhs: Self) -> Self::Output {
+					Self(self.0 | rhs.0)
+				}
+			}
+
+			impl BitXor for $ty {
+				type Output = Self;
+
+				fn bitxor(self, rhs: Self) -> Self::Output {
+					Self(self.0 ^ rhs.0)
+				}
+			}
+
+			impl Shl<u32> for $ty {
+				type Output = Self;
+
+				fn shl(self, rhs: u32) -> Self::Output {
+					Self(self.0 << rhs)
+				}
+			}
+
+			/// Arithmetic shift, rounding towards negative infinity.
+			impl Shr<u32> for $ty {
+				type Output = Self;
+
+				fn shr(self, rhs: u32) -> Self::Output {
+					Self(self.0 >> rhs)
+				}
+			}
+		)*
+	};
+}
+
+pub(crate) use impl_integer_bitwise;
+
+impl_integer_bitwise!(Integer);
+
+impl Not for Integer {
+	type Output = Self;
+
+	/// Returns `-self - 1`.
+	fn not(self) -> Self::Output {
+		Self(!self.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn radix_01() {
+		let i = Integer::from(-255i64);
+		assert_eq!(i.to_radix_string(16), "-ff");
+		assert_eq!(i.to_radix_string(2), "-11111111");
+		assert_eq!(i.to_radix_string(36), "-73");
+	}
+
+	#[test]
+	fn bits_01() {
+		fn bits(s: &str) -> Vec<bool> {
+			s.chars().map(|c| c == '1').collect()
+		}
+
+		for (n, signed, expected) in [
+			(-1i64, true, "11111111"),
+			(-128, true, "10000000"),
+			(127, true, "01111111"),
+			(0, true, "00000000"),
+			(255, false, "11111111"),
+			(5, false, "00000101"),
+		] {
+			let i = Integer::from(n);
+			assert_eq!(i.to_bits(8, signed), Ok(bits(expected)), "{n}");
+			assert_eq!(Integer::from_bits(&bits(expected), signed), i)
+		}
+
+		assert_eq!(
+			Integer::from(-129i64).to_bits(8, true),
+			Err(IntegerRepresentationError::ValueTooLarge)
+		);
+		assert_eq!(
+			Integer::from(128i64).to_bits(8, true),
+			Err(IntegerRepresentationError::ValueTooLarge)
+		);
+		assert_eq!(
+			Integer::from(256i64).to_bits(8, false),
+			Err(IntegerRepresentationError::ValueTooLarge)
+		);
+		assert_eq!(
+			Integer::from(-1i64).to_bits(8, false),
+			Err(IntegerRepresentationError::ValueIsNegativeButUnsignedRequested)
+		);
+		assert_eq!(Integer::zero().to_bits(0, false), Ok(Vec::new()));
+		assert_eq!(Integer::from_bits(&[], true), Integer::zero())
+	}
+
+	#[test]
+	fn hex_01() {
+		for input in ["0xFF", "0xff", "0XfF", "FF", "ff", "+0xff"] {
+			assert_eq!(
+				Integer::from_hex(input),
+				Ok(Integer::from(255i64)),
+				"{input}"
+			)
+		}
+
+		assert_eq!(Integer::from_hex("0x100"), Ok(Integer::from(256i64)));
+		assert_eq!(Integer::from_hex("-0x10"), Ok(Integer::from(-16i64)));
+		assert_eq!(Integer::from_hex("0x"), Err(ParseHexError::EmptyInput));
+		assert_eq!(Integer::from_hex(""), Err(ParseHexError::EmptyInput));
+		assert_eq!(
+			Integer::from_hex("0xfg"),
+			Err(ParseHexError::InvalidDigit('g'))
+		);
+		assert_eq!(
+			Integer::from_hex("0x0x1"),
+			Err(ParseHexError::InvalidDigit('x'))
+		);
+		assert_eq!(
+			Integer::from_hex("0x-1"),
+			Err(ParseHexError::InvalidDigit('-'))
+		);
+		assert_eq!(
+			Integer::from_hex("--1"),
+			Err(ParseHexError::InvalidDigit('-'))
+		)
+	}
+
+	#[test]
+	fn radix_02() {
+		assert_eq!(
+			Integer::from_str_radix("-ff", 16),
+			Ok(Integer::from(-255i64))
+		);
+		assert_eq!(Integer::from_str_radix("+101", 2), Ok(Integer::from(5i64)));
+		assert_eq!(
+			Integer::from_str_radix("Zz", 36),
+			Ok(Integer::from(1295i64))
+		);
+		assert_eq!(
+			Integer::from_str_radix("12", 2),
+			Err(ParseRadixError::InvalidDigit('2', 2))
+		);
+		assert_eq!(
+			Integer::from_str_radix("1_0", 10),
+			Err(ParseRadixError::InvalidDigit('_', 10))
+		);
+		assert_eq!(
+			Integer::from_str_radix("10", 37),
+			Err(ParseRadixError::InvalidRadix(37))
+		);
+		assert_eq!(
+			Integer::from_str_radix("", 16),
+			Err(ParseRadixError::EmptyInput)
+		);
+		assert_eq!(
+			Integer::from_str_radix("-", 16),
+			Err(ParseRadixError::EmptyInput)
+		);
+	}
+
+	#[test]
+	fn radix_04() {
+		assert_eq!(Integer::from_str_radix("FF", 16), Ok(Integer::from(255i64)));
+		assert_eq!(Integer::from_str_radix("77", 8), Ok(Integer::from(63i64)));
+		assert_eq!(Integer::from_str_radix("1010", 2), Ok(Integer::from(10i64)));
+	}
+
+	#[test]
+	fn radix_03() {
+		let n = NonNegativeInteger::from(255u64);
+		assert_eq!(n.to_hex_string(), "ff");
+		assert_eq!(n.to_binary_string(), "11111111");
+		assert_eq!(n.to_octal_string(), "377");
+	}
+
+	#[test]
+	fn abs_01() {
+		assert_eq!(Integer::from(-5i64).abs(), NonNegativeInteger::from(5u64));
+		assert_eq!(Integer::from(5i64).abs(), NonNegativeInteger::from(5u64));
+		assert_eq!(Integer::zero().abs(), NonNegativeInteger::zero());
+	}
+
+	#[test]
+	fn signum_01() {
+		assert_eq!(Integer::from(-5i64).signum(), -1);
+		assert_eq!(Integer::zero().signum(), 0);
+		assert_eq!(Integer::from(12i64).signum(), 1);
+	}
+
+	#[test]
+	fn min_max_01() {
+		let a = Integer::from(-5i64);
+		let b = Integer::from(3i64);
+		assert_eq!(Integer::min(&a, &b), a);
+		assert_eq!(Integer::max(&a, &b), b);
+		assert_eq!(Integer::clamp(&Integer::from(10i64), &a, &b), b);
+		assert_eq!(Integer::clamp(&Integer::from(-10i64), &a, &b), a);
+		assert_eq!(Integer::clamp(&Integer::zero(), &a, &b), Integer::zero());
+	}
+
+	#[test]
+	fn one_01() {
+		assert_eq!(Integer::one(), Integer::from(1i64));
+		assert_eq!(Integer::minus_one(), Integer::from(-1i64));
+	}
+
+	#[test]
+	fn bitwise_01() {
+		assert_eq!(
+			Integer::from(12i64) & Integer::from(10i64),
+			Integer::from(8i64)
+		);
+		assert_eq!(
+			Integer::from(12i64) | Integer::from(10i64),
+			Integer::from(14i64)
+		);
+		assert_eq!(
+			Integer::from(12i64) ^ Integer::from(10i64),
+			Integer::from(6i64)
+		);
+		assert_eq!(!Integer::from(12i64), Integer::from(-13i64));
+		assert_eq!(
+			Integer::from(-12i64) & Integer::from(10i64),
+			Integer::from(-12i64 & 10)
+		);
+	}
+
+	#[test]
+	fn bitwise_02() {
+		assert_eq!(
+			Integer::from(3i64) << 70,
+			Integer::from(BigInt::from(3) << 70)
+		);
+		assert_eq!(Integer::from(12i64) >> 2, Integer::from(3i64));
+		assert_eq!(Integer::from(-5i64) >> 1, Integer::from(-3i64));
+	}
+
+	#[test]
+	fn default_01() {
+		assert_eq!(Integer::default(), Integer::from(0i64));
+		assert!(NonPositiveInteger::default().is_zero())
+	}
+
+	#[test]
+	fn cmp_primitive_01() {
+		let i = Integer::from(42i64);
+		assert_eq!(i, 42i64);
+		assert_eq!(42u8, i);
+		assert_ne!(i, 43i32);
+		assert!(i > 41i16 && i < 43u64);
+		assert!(-1i8 < i && usize::MAX > i);
+		assert!(Integer::from(-1i64) < 0u32)
+	}
+
+	#[test]
+	fn to_primitive_01() {
+		let i = Integer::from(300i64);
+		assert_eq!(i.to_i64(), Some(300));
+		assert_eq!(i.to_u32(), Some(300));
+		assert_eq!(i.to_u8(), None);
+		assert_eq!(i.to_i8(), None);
+		assert_eq!(Integer::from(-1i64).to_usize(), None);
+		assert_eq!(Integer::from(u64::MAX).to_u64(), Some(u64::MAX));
+		assert_eq!(Integer::from(u64::MAX).to_i64(), None)
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn from_primitive_01() {
+		assert_eq!(Integer::from(u64::MAX).to_string(), "18446744073709551615");
+		assert_eq!(
+			Integer::from(u128::MAX).to_string(),
+			"340282366920938463463374607431768211455"
+		);
+		assert_eq!(
+			Integer::from(i128::MIN).to_string(),
+			"-170141183460469231731687303715884105728"
+		);
+		assert_eq!(Integer::from(7usize), Integer::from(7u8));
+		assert_eq!(
+			NonNegativeInteger::from(u128::MAX).to_string(),
+			"340282366920938463463374607431768211455"
+		)
+	}
+
+	#[test]
+	fn big_int_01() {
+		let n = Integer::from(-42i64);
+		assert_eq!(BigInt::from(&n), BigInt::from(-42));
+		assert_eq!(Integer::from(BigInt::from(&n)), n);
+		assert_eq!(BigInt::from(n), BigInt::from(-42))
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn primitive_type_01() {
+		assert_eq!(5i64.type_(), IntegerDatatype::Long(None).into());
+		assert_eq!(5i32.type_(), LongDatatype::Int(None).into());
+		assert_eq!(5i16.type_(), IntDatatype::Short(None).into());
+		assert_eq!(5i8.type_(), ShortDatatype::Byte.into());
+		assert_eq!(5i64.long_type(), Some(ShortDatatype::Byte.into()));
+		assert_eq!(
+			5u64.type_(),
+			NonNegativeIntegerDatatype::UnsignedLong(None).into()
+		);
+		assert_eq!(5u32.type_(), UnsignedLongDatatype::UnsignedInt(None).into());
+		assert_eq!(
+			5u16.type_(),
+			UnsignedIntDatatype::UnsignedShort(None).into()
+		);
+		assert_eq!(5u8.type_(), UnsignedShortDatatype::UnsignedByte.into());
+		assert_eq!(5u64.type_().iri(), crate::XSD_UNSIGNED_LONG)
+	}
+
+	#[test]
+	fn cmp_01() {
+		let big: Integer = "100000000000000000000000000000".parse().unwrap();
+		let minus_big: Integer = "-100000000000000000000000000000".parse().unwrap();
+		let (min, max) = (Integer::from(i64::MIN), Integer::from(u64::MAX));
+		assert!(minus_big < min && min < Integer::minus_one());
+		assert!(Integer::minus_one() < Integer::zero());
+		assert!(Integer::zero() < Integer::one());
+		assert!(max < big);
+		assert_eq!(big.cmp(&big.clone()), core::cmp::Ordering::Equal);
+		assert_eq!(Integer::min_value(), None);
+		assert_eq!(Integer::max_value(), None)
+	}
+
+	#[test]
+	fn sum_01() {
+		assert_eq!(
+			(1..=5).map(Integer::from).sum::<Integer>(),
+			Integer::from(15i64)
+		);
+		assert_eq!(
+			(1..=5).map(Integer::from).product::<Integer>(),
+			Integer::from(120i64)
+		);
+		assert_eq!(
+			core::iter::empty::<Integer>().sum::<Integer>(),
+			Integer::zero()
+		);
+		assert_eq!(
+			core::iter::empty::<Integer>().product::<Integer>(),
+			Integer::one()
+		);
+
+		let values = [Integer::from(-2i64), Integer::from(7i64)];
+		assert_eq!(values.iter().sum::<Integer>(), Integer::from(5i64));
+		assert_eq!(values.iter().product::<Integer>(), Integer::from(-14i64));
+
+		let positives = [PositiveInteger::one(), PositiveInteger::one() + 1u8];
+		assert_eq!(positives.into_iter().sum::<Integer>(), Integer::from(3i64));
+		assert_eq!(
+			[NonNegativeInteger::zero(), NonNegativeInteger::one()]
+				.into_iter()
+				.sum::<Integer>(),
+			Integer::one()
+		)
+	}
+}
@@ -1,22 +1,23 @@
-use std::{
+use alloc::{format, string::String, vec::Vec};
+use core::{
 	borrow::Borrow,
 	fmt,
-	ops::{Add, Div, Mul, Sub},
+	iter::{Product, Sum},
+	ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Shl, Shr, Sub},
 	str::FromStr,
 };
 
-use num_bigint::{BigInt, TryFromBigIntError};
-use num_traits::{Signed, Zero};
+use num_bigint::{BigInt, BigUint, TryFromBigIntError};
+use num_traits::{One, Signed, Zero};
 
 use crate::{
-	impl_integer_arithmetic,
+	impl_integer_arithmetic, impl_integer_bitwise, impl_integer_cmp, impl_to_primitive,
 	lexical::{self, LexicalFormOf},
-	value::decimal::{U16_MAX, U32_MAX, U64_MAX, U8_MAX},
 	Datatype, Integer, NonNegativeIntegerDatatype, ParseRdf, UnsignedIntDatatype,
 	UnsignedLongDatatype, UnsignedShortDatatype, XsdDatatype,
 };
 
-use super::Sign;
+use super::{parse_hex, parse_radix, ParseHexError, ParseRadixError, Sign};
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct NonNegativeInteger(BigInt);
@@ -81,26 +82,37 @@ impl NonNegativeInteger {
 		Self(BigInt::zero())
 	}
 
+	/// Returns the smallest non negative integer, zero.
+	#[inline(always)]
+	pub fn min_value() -> Self {
+		Self::zero()
+	}
+
+	#[inline(always)]
+	pub fn one() -> Self {
+		Self(BigInt::one())
+	}
+
 	#[inline(always)]
 	pub fn is_zero(&self) -> bool {
 		self.0.is_zero()
 	}
 
+	/// Returns the most specific sub-type able to represent this integer.
+	///
+	/// Values up to `u64::MAX` (zero included) are given the narrowest
+	/// unsigned primitive type, larger values are positive integers.
 	pub fn non_negative_integer_type(&self) -> Option<NonNegativeIntegerDatatype> {
-		if self.0 > BigInt::zero() {
-			if self.0 <= *U8_MAX {
-				Some(UnsignedShortDatatype::UnsignedByte.into())
-			} else if self.0 <= *U16_MAX {
-				Some(UnsignedIntDatatype::UnsignedShort(None).into())
-			} else if self.0 <= *U32_MAX {
-				Some(UnsignedLongDatatype::UnsignedInt(None).into())
-			} else if self.0 <= *U64_MAX {
-				Some(NonNegativeIntegerDatatype::UnsignedLong(None))
-			} else {
-				Some(NonNegativeIntegerDatatype::PositiveInteger)
-			}
-		} else {
+		if u8::try_from(&self.0).is_ok() {
 			Some(UnsignedShortDatatype::UnsignedByte.into())
+		} else if u16::try_from(&self.0).is_ok() {
+			Some(UnsignedIntDatatype::UnsignedShort(None).into())
+		} else if u32::try_from(&self.0).is_ok() {
+			Some(UnsignedLongDatatype::UnsignedInt(None).into())
+		} else if u64::try_from(&self.0).is_ok() {
+			Some(NonNegativeIntegerDatatype::UnsignedLong(None))
+		} else {
+			Some(NonNegativeIntegerDatatype::PositiveInteger)
 		}
 	}
 
@@ -129,6 +141,143 @@ impl NonNegativeInteger {
 	pub fn to_signed_bytes_le(&self) -> Vec<u8> {
 		self.0.to_signed_bytes_le()
 	}
+
+	/// Parses a non negative integer written in the given radix, with an
+	/// optional leading `+` sign.
+	///
+	/// See [`Integer::from_str_radix`].
+	pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseRadixError> {
+		let n = parse_radix(s, radix)?;
+		if n.is_negative() {
+			Err(ParseRadixError::OutOfBounds)
+		} else {
+			Ok(Self(n))
+		}
+	}
+
+	/// Parses a non negative hexadecimal integer, with an optional leading
+	/// `+` sign and an optional `0x` (or `0X`) prefix.
+	///
+	/// See [`Integer::from_hex`].
+	pub fn from_hex(s: &str) -> Result<Self, ParseHexError> {
+		let n = parse_hex(s)?;
+		if n.is_negative() {
+			Err(ParseHexError::NegativeValue)
+		} else {
+			Ok(Self(n))
+		}
+	}
+
+	/// Returns the lowercase hexadecimal representation of this integer.
+	pub fn to_hex_string(&self) -> String {
+		self.0.to_str_radix(16)
+	}
+
+	/// Returns the binary representation of this integer.
+	pub fn to_binary_string(&self) -> String {
+		self.0.to_str_radix(2)
+	}
+
+	/// Returns the octal representation of this integer.
+	pub fn to_octal_string(&self) -> String {
+		self.0.to_str_radix(8)
+	}
+
+	impl_to_primitive! {
+		to_i8: i8, to_i16: i16, to_i32: i32, to_i64: i64, to_isize: isize,
+		to_u8: u8, to_u16: u16, to_u32: u32, to_u64: u64, to_usize: usize
+	}
+
+	/// Returns the integer square root of this number, `⌊√n⌋`.
+	pub fn isqrt(&self) -> Self {
+		Self(self.0.sqrt())
+	}
+
+	/// Checks if this number is the square of an integer.
+	pub fn is_perfect_square(&self) -> bool {
+		let r = self.0.sqrt();
+		&r * &r == self.0
+	}
+
+	/// Adds two non-negative integers.
+	///
+	/// Non-negative integers are closed under addition, so this always
+	/// returns `Some`.
+	pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		Some(Self(&self.0 + &rhs.0))
+	}
+
+	/// Subtracts two non-negative integers.
+	///
+	/// Returns `None` if the result is not a non-negative integer.
+	pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		let r = &self.0 - &rhs.0;
+		(!r.is_negative()).then_some(Self(r))
+	}
+
+	/// Multiplies two non-negative integers.
+	///
+	/// Non-negative integers are closed under multiplication, so this always
+	/// returns `Some`.
+	pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+		Some(Self(&self.0 * &rhs.0))
+	}
+
+	/// Returns the number of trailing zero bits in the binary representation
+	/// of this number.
+	///
+	/// By convention, this is `0` for the value `0`.
+	pub fn trailing_zeros(&self) -> u32 {
+		saturate(self.0.trailing_zeros().unwrap_or(0))
+	}
+
+	/// Returns the number of leading zero bits in the `bit_width`-bit binary
+	/// representation of this number.
+	///
+	/// Returns `0` if this number does not fit in `bit_width` bits.
+	pub fn leading_zeros(&self, bit_width: u32) -> u32 {
+		saturate((bit_width as u64).saturating_sub(self.0.bits()))
+	}
+
+	/// Returns the number of one bits in the binary representation of this
+	/// number.
+	pub fn count_ones(&self) -> u32 {
+		saturate(self.0.magnitude().count_ones())
+	}
+
+	/// Returns the number of zero bits in the `bit_width`-bit binary
+	/// representation of this number.
+	///
+	/// Bits beyond `bit_width` are ignored.
+	pub fn count_zeros(&self, bit_width: u32) -> u32 {
+		let magnitude = self.0.magnitude();
+		let ones = if magnitude.bits() <= bit_width as u64 {
+			magnitude.count_ones()
+		} else {
+			let mask = (BigUint::one() << bit_width) - 1u32;
+			(magnitude & mask).count_ones()
+		};
+
+		bit_width - ones as u32
+	}
+
+	/// Returns the `n`-th bit of the binary representation of this number,
+	/// starting from the least significant bit.
+	pub fn bit(&self, n: u32) -> bool {
+		self.0.bit(n as u64)
+	}
+}
+
+/// Converts a bit count into a `u32`, saturating at `u32::MAX`.
+fn saturate(n: u64) -> u32 {
+	u32::try_from(n).unwrap_or(u32::MAX)
+}
+
+impl Default for NonNegativeInteger {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::zero()
+	}
 }
 
 impl XsdDatatype for NonNegativeInteger {
@@ -142,7 +291,7 @@ impl ParseRdf for NonNegativeInteger {
 }
 
 impl LexicalFormOf<NonNegativeInteger> for lexical::NonNegativeInteger {
-	type ValueError = std::convert::Infallible;
+	type ValueError = core::convert::Infallible;
 
 	fn try_as_value(&self) -> Result<NonNegativeInteger, Self::ValueError> {
 		Ok(self.value())
@@ -199,8 +348,20 @@ impl Borrow<BigInt> for NonNegativeInteger {
 	}
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("integer {0} is negative")]
+impl AsRef<Integer> for NonNegativeInteger {
+	#[inline(always)]
+	fn as_ref(&self) -> &Integer {
+		Integer::from_bigint_ref(&self.0)
+	}
+}
+
+#[derive(Debug)]
+#[cfg_attr(
+	feature = "std",
+	derive(thiserror::Error),
+	error("integer {0} is negative")
+)]
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
 pub struct IntegerIsNegative(Integer);
 
 impl TryFrom<Integer> for NonNegativeInteger {
@@ -215,6 +376,27 @@ impl TryFrom<Integer> for NonNegativeInteger {
 	}
 }
 
+impl TryFrom<BigInt> for NonNegativeInteger {
+	type Error = IntegerIsNegative;
+
+	#[inline(always)]
+	fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+		Integer::from(value).try_into()
+	}
+}
+
+impl TryFrom<BigInt> for PositiveInteger {
+	type Error = NotPositive;
+
+	fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+		if value.is_positive() {
+			Ok(Self(value))
+		} else {
+			Err(NotPositive)
+		}
+	}
+}
+
 macro_rules! from {
 	{ $( $ty:ty ),* } => {
 		$(
@@ -227,10 +409,14 @@ macro_rules! from {
 	};
 }
 
-from!(u8, u16, u32, u64, usize);
+from!(u8, u16, u32, u64, u128, usize);
 
-#[derive(Debug, thiserror::Error)]
-#[error("integer out of supported bounds: {0}")]
+#[derive(Debug)]
+#[cfg_attr(
+	feature = "std",
+	derive(thiserror::Error),
+	error("integer out of supported bounds: {0}")
+)]
 pub struct NonNegativeIntegerOutOfTargetBounds(pub NonNegativeInteger);
 
 macro_rules! try_into {
@@ -249,6 +435,25 @@ macro_rules! try_into {
 
 try_into!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
 
+impl_integer_bitwise!(NonNegativeInteger);
+
+impl_integer_cmp! {
+	for NonNegativeInteger { u8, u16, u32, u64, usize }
+}
+
+impl_integer_cmp! {
+	for PositiveInteger { u8, u16, u32, u64, usize }
+}
+
+impl Not for NonNegativeInteger {
+	type Output = Integer;
+
+	/// Returns `-self - 1`, which is always negative.
+	fn not(self) -> Self::Output {
+		(!self.0).into()
+	}
+}
+
 impl_integer_arithmetic!(
 	for NonNegativeInteger where r ( !r.is_negative() ) {
 		Integer [.0],
@@ -269,13 +474,41 @@ impl_integer_arithmetic!(
 	}
 );
 
+impl Sum for NonNegativeInteger {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		Self(iter.map(|n| n.0).sum())
+	}
+}
+
+impl Product for NonNegativeInteger {
+	fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+		Self(iter.map(|n| n.0).product())
+	}
+}
+
 pub type UnsignedLong = u64;
 
-pub trait XsdUnsignedLong {
+/// Sub-type of an `UnsignedLong` value.
+///
+/// See [`XsdLong`](crate::XsdLong).
+pub trait XsdUnsignedLong: Sized {
 	fn unsigned_long_type(&self) -> Option<UnsignedLongDatatype>;
+
+	/// Parses an unsigned long written in the given radix, with an optional
+	/// leading `+` sign.
+	///
+	/// Unlike [`u64::from_str_radix`], errors are reported as
+	/// [`ParseRadixError`], as for [`Integer::from_str_radix`].
+	fn unsigned_long_from_str_radix(s: &str, radix: u32) -> Result<Self, ParseRadixError>;
 }
 
 impl XsdUnsignedLong for UnsignedLong {
+	fn unsigned_long_from_str_radix(s: &str, radix: u32) -> Result<Self, ParseRadixError> {
+		parse_radix(s, radix)?
+			.try_into()
+			.map_err(|_| ParseRadixError::OutOfBounds)
+	}
+
 	fn unsigned_long_type(&self) -> Option<UnsignedLongDatatype> {
 		if *self <= u8::MAX as u64 {
 			Some(UnsignedShortDatatype::UnsignedByte.into())
@@ -291,7 +524,7 @@ impl XsdUnsignedLong for UnsignedLong {
 
 impl XsdDatatype for UnsignedLong {
 	fn type_(&self) -> Datatype {
-		self.unsigned_long_type().into()
+		NonNegativeIntegerDatatype::UnsignedLong(None).into()
 	}
 }
 
@@ -327,7 +560,7 @@ impl XsdUnsignedInt for UnsignedInt {
 
 impl XsdDatatype for UnsignedInt {
 	fn type_(&self) -> Datatype {
-		self.unsigned_int_type().into()
+		UnsignedLongDatatype::UnsignedInt(None).into()
 	}
 }
 
@@ -361,7 +594,7 @@ impl XsdUnsignedShort for UnsignedShort {
 
 impl XsdDatatype for UnsignedShort {
 	fn type_(&self) -> Datatype {
-		self.unsigned_short_type().into()
+		UnsignedIntDatatype::UnsignedShort(None).into()
 	}
 }
 
@@ -410,6 +643,34 @@ impl PositiveInteger {
 		Self(n)
 	}
 
+	/// Parses a positive integer written in the given radix, with an
+	/// optional leading `+` sign.
+	///
+	/// See [`Integer::from_str_radix`].
+	pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseRadixError> {
+		let n = parse_radix(s, radix)?;
+		if n.is_positive() {
+			Ok(Self(n))
+		} else {
+			Err(ParseRadixError::OutOfBounds)
+		}
+	}
+
+	/// Parses a positive hexadecimal integer, with an optional leading `+`
+	/// sign and an optional `0x` (or `0X`) prefix.
+	///
+	/// See [`Integer::from_hex`].
+	pub fn from_hex(s: &str) -> Result<Self, ParseHexError> {
+		let n = parse_hex(s)?;
+		if n.is_negative() {
+			Err(ParseHexError::NegativeValue)
+		} else if n.is_zero() {
+			Err(ParseHexError::ZeroValue)
+		} else {
+			Ok(Self(n))
+		}
+	}
+
 	/// Creates a positive integer from its unsigned big endian bytes
 	/// representation.
 	pub fn from_bytes_be(bytes: &[u8]) -> Self {
@@ -446,10 +707,22 @@ impl PositiveInteger {
 		self.0
 	}
 
+	#[inline(always)]
+	pub fn one() -> Self {
+		Self(BigInt::one())
+	}
+
 	pub fn is_one(&self) -> bool {
 		matches!(u8::try_from(&self.0), Ok(1))
 	}
 
+	/// Returns the integer square root of this number, `⌊√n⌋`.
+	///
+	/// The result is positive since `n ≥ 1`.
+	pub fn isqrt(&self) -> Self {
+		Self(self.0.sqrt())
+	}
+
 	pub fn to_bytes_be(&self) -> (Sign, Vec<u8>) {
 		self.0.to_bytes_be()
 	}
@@ -465,6 +738,37 @@ impl PositiveInteger {
 	pub fn to_signed_bytes_le(&self) -> Vec<u8> {
 		self.0.to_signed_bytes_le()
 	}
+
+	/// Adds two positive integers.
+	///
+	/// Positive integers are closed under addition, so this always returns
+	/// `Some`.
+	pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		Some(Self(&self.0 + &rhs.0))
+	}
+
+	/// Subtracts two positive integers.
+	///
+	/// Returns `None` if the result is not a positive integer.
+	pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		let r = &self.0 - &rhs.0;
+		r.is_positive().then_some(Self(r))
+	}
+
+	/// Multiplies two positive integers.
+	///
+	/// Positive integers are closed under multiplication, so this always
+	/// returns `Some`.
+	pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+		Some(Self(&self.0 * &rhs.0))
+	}
+}
+
+impl Default for PositiveInteger {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::one()
+	}
 }
 
 impl XsdDatatype for PositiveInteger {
@@ -477,8 +781,32 @@ impl ParseRdf for PositiveInteger {
 	type LexicalForm = lexical::PositiveInteger;
 }
 
+impl<'a> From<&'a lexical::PositiveInteger> for PositiveInteger {
+	#[inline(always)]
+	fn from(value: &'a lexical::PositiveInteger) -> Self {
+		value.value()
+	}
+}
+
+impl From<lexical::PositiveIntegerBuf> for PositiveInteger {
+	#[inline(always)]
+	fn from(value: lexical::PositiveIntegerBuf) -> Self {
+		value.as_positive_integer().into()
+	}
+}
+
+impl FromStr for PositiveInteger {
+	type Err = lexical::InvalidPositiveInteger;
+
+	#[inline(always)]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let l = lexical::PositiveInteger::new(s)?;
+		Ok(l.into())
+	}
+}
+
 impl LexicalFormOf<PositiveInteger> for lexical::PositiveInteger {
-	type ValueError = std::convert::Infallible;
+	type ValueError = core::convert::Infallible;
 
 	fn try_as_value(&self) -> Result<PositiveInteger, Self::ValueError> {
 		Ok(self.value())
@@ -497,6 +825,49 @@ impl From<PositiveInteger> for BigInt {
 	}
 }
 
+impl From<PositiveInteger> for NonNegativeInteger {
+	#[inline(always)]
+	fn from(value: PositiveInteger) -> Self {
+		Self(value.0)
+	}
+}
+
+/// Error returned when converting an integer that is not positive into a
+/// [`PositiveInteger`], such as a zero [`NonNegativeInteger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+	feature = "std",
+	derive(thiserror::Error),
+	error("integer is not positive")
+)]
+pub struct NotPositive;
+
+impl TryFrom<NonNegativeInteger> for PositiveInteger {
+	type Error = NotPositive;
+
+	fn try_from(value: NonNegativeInteger) -> Result<Self, Self::Error> {
+		if value.is_zero() {
+			Err(NotPositive)
+		} else {
+			Ok(Self(value.0))
+		}
+	}
+}
+
+impl AsRef<BigInt> for PositiveInteger {
+	#[inline(always)]
+	fn as_ref(&self) -> &BigInt {
+		&self.0
+	}
+}
+
+impl Borrow<BigInt> for PositiveInteger {
+	#[inline(always)]
+	fn borrow(&self) -> &BigInt {
+		&self.0
+	}
+}
+
 impl_integer_arithmetic!(
 	for PositiveInteger where r ( r.is_positive() ) {
 		Integer [.0],
@@ -516,3 +887,302 @@ impl_integer_arithmetic!(
 		usize
 	}
 );
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bitwise_01() {
+		let a = NonNegativeInteger::from(12u8);
+		let b = NonNegativeInteger::from(10u8);
+		assert_eq!(a.clone() & b.clone(), NonNegativeInteger::from(8u8));
+		assert_eq!(a.clone() | b.clone(), NonNegativeInteger::from(14u8));
+		assert_eq!(a.clone() ^ b, NonNegativeInteger::from(6u8));
+		assert_eq!(a.clone() << 2, NonNegativeInteger::from(48u8));
+		assert_eq!(a.clone() >> 2, NonNegativeInteger::from(3u8));
+		assert_eq!(!a, Integer::from(-13i64));
+	}
+
+	#[test]
+	fn hex_01() {
+		assert_eq!(
+			NonNegativeInteger::from_hex("0xFF").unwrap(),
+			NonNegativeInteger::from(255u8)
+		);
+		assert!(NonNegativeInteger::from_hex("0").unwrap().is_zero());
+		assert_eq!(
+			NonNegativeInteger::from_hex("-0x1"),
+			Err(ParseHexError::NegativeValue)
+		);
+		assert_eq!(PositiveInteger::from_hex("0x100").unwrap(), 256u16);
+		assert_eq!(
+			PositiveInteger::from_hex("0x00"),
+			Err(ParseHexError::ZeroValue)
+		);
+		assert_eq!(
+			PositiveInteger::from_hex("-ff"),
+			Err(ParseHexError::NegativeValue)
+		)
+	}
+
+	#[test]
+	fn radix_01() {
+		assert_eq!(
+			NonNegativeInteger::from_str_radix("FF", 16).unwrap(),
+			NonNegativeInteger::from(255u8)
+		);
+		assert_eq!(
+			NonNegativeInteger::from_str_radix("+0", 2).unwrap(),
+			NonNegativeInteger::zero()
+		);
+		assert_eq!(
+			NonNegativeInteger::from_str_radix("-1", 2),
+			Err(ParseRadixError::OutOfBounds)
+		);
+		assert_eq!(
+			BigInt::from(PositiveInteger::from_str_radix("77", 8).unwrap()),
+			BigInt::from(63)
+		);
+		assert_eq!(
+			BigInt::from(PositiveInteger::from_str_radix("1010", 2).unwrap()),
+			BigInt::from(10)
+		);
+		assert_eq!(
+			PositiveInteger::from_str_radix("0", 16),
+			Err(ParseRadixError::OutOfBounds)
+		);
+		assert_eq!(
+			PositiveInteger::from_str_radix("1g", 16),
+			Err(ParseRadixError::InvalidDigit('g', 16))
+		);
+	}
+
+	#[test]
+	fn radix_02() {
+		assert_eq!(
+			UnsignedLong::unsigned_long_from_str_radix("FF", 16),
+			Ok(255)
+		);
+		assert_eq!(
+			UnsignedLong::unsigned_long_from_str_radix("+ffffffffffffffff", 16),
+			Ok(u64::MAX)
+		);
+		assert_eq!(
+			UnsignedLong::unsigned_long_from_str_radix("10000000000000000", 16),
+			Err(ParseRadixError::OutOfBounds)
+		);
+		assert_eq!(
+			UnsignedLong::unsigned_long_from_str_radix("-1", 2),
+			Err(ParseRadixError::OutOfBounds)
+		);
+		assert_eq!(
+			UnsignedLong::unsigned_long_from_str_radix("", 2),
+			Err(ParseRadixError::EmptyInput)
+		);
+		assert_eq!(
+			UnsignedLong::unsigned_long_from_str_radix("12", 2),
+			Err(ParseRadixError::InvalidDigit('2', 2))
+		);
+		assert_eq!(
+			UnsignedLong::unsigned_long_from_str_radix("1", 1),
+			Err(ParseRadixError::InvalidRadix(1))
+		)
+	}
+
+	#[test]
+	fn isqrt_01() {
+		for (n, expected) in [(0u64, 0u64), (1, 1), (4, 2), (8, 2), (9, 3), (10, 3)] {
+			assert_eq!(
+				NonNegativeInteger::from(n).isqrt(),
+				NonNegativeInteger::from(expected)
+			)
+		}
+	}
+
+	#[test]
+	fn isqrt_02() {
+		let n = NonNegativeInteger(num_traits::pow(BigInt::from(10), 100));
+		let expected = NonNegativeInteger(num_traits::pow(BigInt::from(10), 50));
+		assert_eq!(n.isqrt(), expected);
+		assert!(n.is_perfect_square());
+
+		let n = NonNegativeInteger(n.into_big_int() - 1);
+		assert_eq!(n.isqrt(), NonNegativeInteger(expected.into_big_int() - 1));
+		assert!(!n.is_perfect_square())
+	}
+
+	#[test]
+	fn isqrt_03() {
+		let n = PositiveInteger(BigInt::from(3));
+		assert!(n.isqrt().is_one());
+		assert!(NonNegativeInteger::from(16u64).is_perfect_square());
+		assert!(!NonNegativeInteger::from(15u64).is_perfect_square());
+	}
+
+	#[test]
+	fn one_01() {
+		assert!(PositiveInteger::one().is_one());
+		assert!(PositiveInteger::default().is_one());
+		assert_eq!(NonNegativeInteger::one(), NonNegativeInteger::from(1u64));
+	}
+
+	#[test]
+	fn default_01() {
+		assert!(NonNegativeInteger::default().is_zero());
+		assert_eq!(PositiveInteger::default(), PositiveInteger::one())
+	}
+
+	#[test]
+	fn cmp_primitive_01() {
+		let n = NonNegativeInteger::from(7u32);
+		assert_eq!(n, 7u64);
+		assert_eq!(7u8, n);
+		assert!(n < 8u16 && 6usize < n);
+		assert_eq!(PositiveInteger::one(), 1u32);
+		assert!(PositiveInteger::one() < 2u64)
+	}
+
+	#[test]
+	fn to_primitive_01() {
+		let n = NonNegativeInteger::from(200u32);
+		assert_eq!(n.to_u8(), Some(200));
+		assert_eq!(n.to_i8(), None);
+		assert_eq!(n.to_usize(), Some(200));
+		assert_eq!(NonNegativeInteger::from(u64::MAX).to_u32(), None)
+	}
+
+	#[test]
+	fn as_ref_01() {
+		let n = NonNegativeInteger::from(12u8);
+		assert_eq!(AsRef::<BigInt>::as_ref(&n), &BigInt::from(12));
+		assert_eq!(AsRef::<Integer>::as_ref(&n), &Integer::from(12i64));
+		let p = PositiveInteger::one();
+		assert_eq!(AsRef::<BigInt>::as_ref(&p), &BigInt::one());
+		assert_eq!(Borrow::<BigInt>::borrow(&p), &BigInt::one())
+	}
+
+	#[test]
+	fn from_str_01() {
+		let n: PositiveInteger = "42".parse().unwrap();
+		assert_eq!(n, 42u8);
+		assert_eq!("+007".parse::<PositiveInteger>().unwrap(), 7u8);
+		assert!("0".parse::<PositiveInteger>().is_err());
+		assert!("-1".parse::<PositiveInteger>().is_err())
+	}
+
+	#[test]
+	fn sub_type_01() {
+		let n = NonNegativeInteger::from(12u8);
+		let p = PositiveInteger::try_from(n.clone()).unwrap();
+		assert_eq!(NonNegativeInteger::from(p), n);
+		assert_eq!(
+			PositiveInteger::try_from(NonNegativeInteger::zero()),
+			Err(NotPositive)
+		);
+		assert!(PositiveInteger::try_from(NonNegativeInteger::one())
+			.unwrap()
+			.is_one())
+	}
+
+	#[test]
+	fn try_from_big_int_01() {
+		assert!(NonNegativeInteger::try_from(BigInt::zero())
+			.unwrap()
+			.is_zero());
+		assert!(NonNegativeInteger::try_from(-BigInt::one()).is_err());
+		assert!(PositiveInteger::try_from(BigInt::one()).unwrap().is_one());
+		assert!(matches!(
+			PositiveInteger::try_from(BigInt::zero()),
+			Err(NotPositive)
+		));
+		assert!(matches!(
+			PositiveInteger::try_from(-BigInt::one()),
+			Err(NotPositive)
+		))
+	}
+
+	#[test]
+	fn non_negative_integer_type_01() {
+		let byte = Some(UnsignedShortDatatype::UnsignedByte.into());
+		let short = Some(UnsignedIntDatatype::UnsignedShort(None).into());
+		let int = Some(UnsignedLongDatatype::UnsignedInt(None).into());
+		let long = Some(NonNegativeIntegerDatatype::UnsignedLong(None));
+		let positive = Some(NonNegativeIntegerDatatype::PositiveInteger);
+
+		for (n, expected) in [
+			(NonNegativeInteger::zero(), byte),
+			(NonNegativeInteger::from(255u8), byte),
+			(NonNegativeInteger::from(256u16), short),
+			(NonNegativeInteger::from(65535u16), short),
+			(NonNegativeInteger::from(65536u32), int),
+			(NonNegativeInteger::from(u32::MAX), int),
+			(NonNegativeInteger::from(u32::MAX as u64 + 1), long),
+			(NonNegativeInteger::from(u64::MAX), long),
+			(NonNegativeInteger::from(u64::MAX as u128 + 1), positive),
+		] {
+			assert_eq!(n.non_negative_integer_type(), expected, "{n}")
+		}
+	}
+
+	#[test]
+	fn cmp_01() {
+		let big: NonNegativeInteger = "100000000000000000000000000000".parse().unwrap();
+		assert!(NonNegativeInteger::min_value() < NonNegativeInteger::one());
+		let max = NonNegativeInteger::from(u64::MAX);
+		assert!(max < big);
+		assert!(NonNegativeInteger::min_value().is_zero());
+		assert!(PositiveInteger::one() < PositiveInteger::one() + 1u8)
+	}
+
+	#[test]
+	fn sum_01() {
+		let n: NonNegativeInteger = (1u8..=4).map(NonNegativeInteger::from).sum();
+		assert_eq!(n, 10u8);
+		let n: NonNegativeInteger = (1u8..=4).map(NonNegativeInteger::from).product();
+		assert_eq!(n, 24u8);
+		assert!(core::iter::empty::<NonNegativeInteger>()
+			.sum::<NonNegativeInteger>()
+			.is_zero())
+	}
+
+	#[test]
+	fn checked_01() {
+		let two: PositiveInteger = "2".parse().unwrap();
+		let three: PositiveInteger = "3".parse().unwrap();
+		assert_eq!(two.checked_add(&three).unwrap(), 5u8);
+		assert_eq!(two.checked_mul(&three).unwrap(), 6u8);
+		assert_eq!(three.checked_sub(&two).unwrap(), 1u8);
+		assert_eq!(two.checked_sub(&two), None);
+
+		let two = NonNegativeInteger::from(2u8);
+		assert!(two.checked_sub(&two).unwrap().is_zero());
+		assert_eq!(two.checked_sub(&NonNegativeInteger::from(3u8)), None)
+	}
+
+	#[test]
+	fn bits_01() {
+		let n = NonNegativeInteger::from(0b1011_0100u8);
+		assert_eq!(n.trailing_zeros(), 2);
+		assert_eq!(n.leading_zeros(8), 0);
+		assert_eq!(n.leading_zeros(16), 8);
+		assert_eq!(n.leading_zeros(4), 0);
+		assert_eq!(n.count_ones(), 4);
+		assert_eq!(n.count_zeros(8), 4);
+		assert_eq!(n.count_zeros(4), 3);
+		assert!(n.bit(2) && !n.bit(3) && !n.bit(100));
+
+		let zero = NonNegativeInteger::zero();
+		assert_eq!(zero.trailing_zeros(), 0);
+		assert_eq!(zero.leading_zeros(32), 32);
+		assert_eq!(zero.count_ones(), 0);
+		assert_eq!(zero.count_zeros(32), 32);
+
+		let n: NonNegativeInteger = "340282366920938463463374607431768211456".parse().unwrap();
+		assert_eq!(n.trailing_zeros(), 128);
+		assert_eq!(n.count_ones(), 1);
+		assert_eq!(n.count_zeros(128), 128);
+		assert_eq!(n.count_zeros(129), 128);
+		assert_eq!(n.count_zeros(u32::MAX), u32::MAX - 1)
+	}
+}
@@ -1,4 +1,5 @@
-use std::{
+use alloc::{format, vec::Vec};
+use core::{
 	borrow::Borrow,
 	fmt,
 	ops::{Add, Div, Mul, Sub},
@@ -6,12 +7,13 @@ use std::{
 };
 
 use num_bigint::{BigInt, TryFromBigIntError};
-use num_traits::{Signed, Zero};
+use num_traits::{One, Signed, Zero};
 
 use crate::{
-	impl_integer_arithmetic,
+	impl_integer_arithmetic, impl_integer_cmp,
 	lexical::{self, LexicalFormOf},
-	Datatype, Integer, NonPositiveIntegerDatatype, ParseRdf, XsdDatatype,
+	Datatype, Integer, NonNegativeInteger, NonPositiveIntegerDatatype, ParseRdf, PositiveInteger,
+	XsdDatatype,
 };
 
 use super::Sign;
@@ -118,6 +120,46 @@ impl NonPositiveInteger {
 	pub fn to_signed_bytes_le(&self) -> Vec<u8> {
 		self.0.to_signed_bytes_le()
 	}
+
+	/// Returns the absolute value of this integer.
+	pub fn abs(&self) -> NonNegativeInteger {
+		unsafe {
+			// This is safe because an absolute value is non negative.
+			NonNegativeInteger::new_unchecked(-&self.0)
+		}
+	}
+
+	/// Adds two non-positive integers.
+	///
+	/// Non-positive integers are closed under addition, so this always
+	/// returns `Some`.
+	pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		Some(Self(&self.0 + &rhs.0))
+	}
+
+	/// Subtracts two non-positive integers.
+	///
+	/// Returns `None` if the result is not a non-positive integer.
+	pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		let r = &self.0 - &rhs.0;
+		(!r.is_positive()).then_some(Self(r))
+	}
+
+	/// Multiplies two non-positive integers.
+	///
+	/// The product of two non-positive integers is non-negative, so this
+	/// returns `None` unless one of them is zero.
+	pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+		let r = &self.0 * &rhs.0;
+		(!r.is_positive()).then_some(Self(r))
+	}
+}
+
+impl Default for NonPositiveInteger {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::zero()
+	}
 }
 
 impl XsdDatatype for NonPositiveInteger {
@@ -132,7 +174,7 @@ impl ParseRdf for NonPositiveInteger {
 }
 
 impl LexicalFormOf<NonPositiveInteger> for lexical::NonPositiveInteger {
-	type ValueError = std::convert::Infallible;
+	type ValueError = core::convert::Infallible;
 
 	fn try_as_value(&self) -> Result<NonPositiveInteger, Self::ValueError> {
 		Ok(self.value())
@@ -169,6 +211,8 @@ impl FromStr for NonPositiveInteger {
 	}
 }
 
+/// Displays the canonical XSD lexical form of the integer: zero is always
+/// displayed as `0`, never `-0`.
 impl fmt::Display for NonPositiveInteger {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		self.0.fmt(f)
@@ -189,6 +233,21 @@ impl Borrow<BigInt> for NonPositiveInteger {
 	}
 }
 
+impl AsRef<Integer> for NonPositiveInteger {
+	#[inline(always)]
+	fn as_ref(&self) -> &Integer {
+		Integer::from_bigint_ref(&self.0)
+	}
+}
+
+impl_integer_cmp! {
+	for NonPositiveInteger { i8, i16, i32, i64, isize }
+}
+
+impl_integer_cmp! {
+	for NegativeInteger { i8, i16, i32, i64, isize }
+}
+
 impl_integer_arithmetic!(
 	for NonPositiveInteger where r ( !r.is_positive() ) {
 		Integer [.0],
@@ -209,8 +268,12 @@ impl_integer_arithmetic!(
 	}
 );
 
-#[derive(Debug, thiserror::Error)]
-#[error("integer out of supported bounds: {0}")]
+#[derive(Debug)]
+#[cfg_attr(
+	feature = "std",
+	derive(thiserror::Error),
+	error("integer out of supported bounds: {0}")
+)]
 pub struct NonPositiveIntegerOutOfTargetBounds(pub NonPositiveInteger);
 
 macro_rules! try_into {
@@ -229,8 +292,13 @@ macro_rules! try_into {
 
 try_into!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
 
-#[derive(Debug, thiserror::Error)]
-#[error("integer {0} is negative")]
+#[derive(Debug)]
+#[cfg_attr(
+	feature = "std",
+	derive(thiserror::Error),
+	error("integer {0} is negative")
+)]
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
 pub struct IntegerIsPositive(Integer);
 
 impl TryFrom<Integer> for NonPositiveInteger {
@@ -245,6 +313,27 @@ impl TryFrom<Integer> for NonPositiveInteger {
 	}
 }
 
+impl TryFrom<BigInt> for NonPositiveInteger {
+	type Error = IntegerIsPositive;
+
+	#[inline(always)]
+	fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+		Integer::from(value).try_into()
+	}
+}
+
+impl TryFrom<BigInt> for NegativeInteger {
+	type Error = NotNegative;
+
+	fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+		if value.is_negative() {
+			Ok(Self(value))
+		} else {
+			Err(NotNegative)
+		}
+	}
+}
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct NegativeInteger(BigInt);
 
@@ -302,10 +391,24 @@ impl NegativeInteger {
 		self.0
 	}
 
+	#[inline(always)]
+	pub fn minus_one() -> Self {
+		Self(-BigInt::one())
+	}
+
 	pub fn is_minus_one(&self) -> bool {
 		matches!(i8::try_from(&self.0), Ok(-1))
 	}
 
+	/// Returns the absolute value of this integer.
+	pub fn abs(&self) -> PositiveInteger {
+		unsafe {
+			// This is safe because the opposite of a negative integer is
+			// positive.
+			PositiveInteger::new_unchecked(-&self.0)
+		}
+	}
+
 	pub fn to_bytes_be(&self) -> (Sign, Vec<u8>) {
 		self.0.to_bytes_be()
 	}
@@ -321,6 +424,22 @@ impl NegativeInteger {
 	pub fn to_signed_bytes_le(&self) -> Vec<u8> {
 		self.0.to_signed_bytes_le()
 	}
+
+	/// Adds two negative integers.
+	///
+	/// Negative integers are closed under addition, so this always returns
+	/// `Some`.
+	pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		Some(Self(&self.0 + &rhs.0))
+	}
+
+	/// Subtracts two negative integers.
+	///
+	/// Returns `None` if the result is not a negative integer.
+	pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		let r = &self.0 - &rhs.0;
+		r.is_negative().then_some(Self(r))
+	}
 }
 
 impl XsdDatatype for NegativeInteger {
@@ -333,14 +452,40 @@ impl ParseRdf for NegativeInteger {
 	type LexicalForm = lexical::NegativeInteger;
 }
 
+impl<'a> From<&'a lexical::NegativeInteger> for NegativeInteger {
+	#[inline(always)]
+	fn from(value: &'a lexical::NegativeInteger) -> Self {
+		value.value()
+	}
+}
+
+impl From<lexical::NegativeIntegerBuf> for NegativeInteger {
+	#[inline(always)]
+	fn from(value: lexical::NegativeIntegerBuf) -> Self {
+		value.as_negative_integer().into()
+	}
+}
+
+impl FromStr for NegativeInteger {
+	type Err = lexical::InvalidNegativeInteger;
+
+	#[inline(always)]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let l = lexical::NegativeInteger::new(s)?;
+		Ok(l.into())
+	}
+}
+
 impl LexicalFormOf<NegativeInteger> for lexical::NegativeInteger {
-	type ValueError = std::convert::Infallible;
+	type ValueError = core::convert::Infallible;
 
 	fn try_as_value(&self) -> Result<NegativeInteger, Self::ValueError> {
 		Ok(self.value())
 	}
 }
 
+/// Displays the canonical XSD lexical form of the integer, always starting
+/// with `-`.
 impl fmt::Display for NegativeInteger {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		self.0.fmt(f)
@@ -353,6 +498,49 @@ impl From<NegativeInteger> for BigInt {
 	}
 }
 
+impl From<NegativeInteger> for NonPositiveInteger {
+	#[inline(always)]
+	fn from(value: NegativeInteger) -> Self {
+		Self(value.0)
+	}
+}
+
+/// Error returned when converting an integer that is not negative into a
+/// [`NegativeInteger`], such as a zero [`NonPositiveInteger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+	feature = "std",
+	derive(thiserror::Error),
+	error("integer is not negative")
+)]
+pub struct NotNegative;
+
+impl TryFrom<NonPositiveInteger> for NegativeInteger {
+	type Error = NotNegative;
+
+	fn try_from(value: NonPositiveInteger) -> Result<Self, Self::Error> {
+		if value.is_zero() {
+			Err(NotNegative)
+		} else {
+			Ok(Self(value.0))
+		}
+	}
+}
+
+impl AsRef<BigInt> for NegativeInteger {
+	#[inline(always)]
+	fn as_ref(&self) -> &BigInt {
+		&self.0
+	}
+}
+
+impl Borrow<BigInt> for NegativeInteger {
+	#[inline(always)]
+	fn borrow(&self) -> &BigInt {
+		&self.0
+	}
+}
+
 impl_integer_arithmetic!(
 	for NegativeInteger where r ( r.is_negative() ) {
 		Integer [.0],
@@ -372,3 +560,124 @@ impl_integer_arithmetic!(
 		usize
 	}
 );
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn abs_01() {
+		let n: NonPositiveInteger = "-12".parse().unwrap();
+		assert_eq!(n.abs(), NonNegativeInteger::from(12u64));
+		assert_eq!(NonPositiveInteger::zero().abs(), NonNegativeInteger::zero());
+		let n = NegativeInteger::minus_one();
+		assert!(n.abs().is_one())
+	}
+
+	#[test]
+	fn minus_one_01() {
+		assert!(NegativeInteger::minus_one().is_minus_one());
+		assert!(NonPositiveInteger::zero().is_zero());
+	}
+
+	#[test]
+	fn cmp_primitive_01() {
+		let n: NonPositiveInteger = "-12".parse().unwrap();
+		assert_eq!(n, -12i64);
+		assert_eq!(-12i8, n);
+		assert!(n < -11i32 && -13i16 < n);
+		assert_eq!(NegativeInteger::minus_one(), -1isize);
+		assert!(NegativeInteger::minus_one() < 0i64)
+	}
+
+	#[test]
+	fn as_ref_01() {
+		let n: NonPositiveInteger = "-12".parse().unwrap();
+		assert_eq!(AsRef::<BigInt>::as_ref(&n), &BigInt::from(-12));
+		assert_eq!(AsRef::<Integer>::as_ref(&n), &Integer::from(-12i64));
+		let m = NegativeInteger::minus_one();
+		assert_eq!(AsRef::<BigInt>::as_ref(&m), &-BigInt::one());
+		assert_eq!(Borrow::<BigInt>::borrow(&m), &-BigInt::one())
+	}
+
+	#[test]
+	fn from_str_01() {
+		let n: NegativeInteger = "-42".parse().unwrap();
+		assert_eq!(n, -42i8);
+		assert!("0".parse::<NegativeInteger>().is_err());
+		assert!("-0".parse::<NegativeInteger>().is_err());
+		assert!("-000".parse::<NegativeInteger>().is_err());
+		assert_eq!("-007".parse::<NegativeInteger>().unwrap(), -7i8);
+		assert!("1".parse::<NegativeInteger>().is_err())
+	}
+
+	#[test]
+	fn sub_type_01() {
+		let n: NonPositiveInteger = "-12".parse().unwrap();
+		let m = NegativeInteger::try_from(n.clone()).unwrap();
+		assert_eq!(NonPositiveInteger::from(m), n);
+		assert_eq!(
+			NegativeInteger::try_from(NonPositiveInteger::zero()),
+			Err(NotNegative)
+		);
+		let minus_one: NonPositiveInteger = "-1".parse().unwrap();
+		assert!(NegativeInteger::try_from(minus_one).unwrap().is_minus_one())
+	}
+
+	#[test]
+	fn try_from_big_int_01() {
+		assert!(NonPositiveInteger::try_from(BigInt::zero())
+			.unwrap()
+			.is_zero());
+		assert!(NonPositiveInteger::try_from(BigInt::one()).is_err());
+		assert!(NegativeInteger::try_from(-BigInt::one())
+			.unwrap()
+			.is_minus_one());
+		assert!(matches!(
+			NegativeInteger::try_from(BigInt::zero()),
+			Err(NotNegative)
+		));
+		assert!(matches!(
+			NegativeInteger::try_from(BigInt::one()),
+			Err(NotNegative)
+		))
+	}
+
+	#[test]
+	fn checked_01() {
+		let a: NegativeInteger = "-2".parse().unwrap();
+		let b: NegativeInteger = "-3".parse().unwrap();
+		assert_eq!(a.checked_add(&b).unwrap(), -5i8);
+		assert_eq!(a.checked_sub(&b), None);
+		assert_eq!(b.checked_sub(&a).unwrap(), -1i8);
+
+		let a: NonPositiveInteger = "-2".parse().unwrap();
+		assert!(a.checked_sub(&a).unwrap().is_zero());
+		assert!(a
+			.checked_mul(&NonPositiveInteger::zero())
+			.unwrap()
+			.is_zero());
+		assert_eq!(a.checked_mul(&a), None)
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn display_01() {
+		assert_eq!(NonPositiveInteger::zero().to_string(), "0");
+		for input in ["0", "000", "+0", "-0", "-000"] {
+			let n: NonPositiveInteger = input.parse().unwrap();
+			assert_eq!(n.to_string(), "0")
+		}
+
+		let n = NonPositiveInteger::try_from(-BigInt::one()).unwrap();
+		assert_eq!(n.to_string(), "-1");
+		assert!("+".parse::<NonPositiveInteger>().is_err());
+		assert!("+1".parse::<NonPositiveInteger>().is_err());
+		let n: NonPositiveInteger = "-0042".parse().unwrap();
+		assert_eq!(n.to_string(), "-42");
+
+		assert_eq!(NegativeInteger::minus_one().to_string(), "-1");
+		let n: NegativeInteger = "-007".parse().unwrap();
+		assert_eq!(n.to_string(), "-7")
+	}
+}
@@ -0,0 +1,137 @@
+use num_bigint::BigInt;
+use num_traits::{One, ToPrimitive};
+
+use super::Integer;
+use crate::NonNegativeInteger;
+
+/// Inclusive interval of integers.
+///
+/// Iterating over the range yields every integer from the start to the end
+/// (inclusive), by steps of 1. Bounds are arbitrarily large.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntegerRange {
+	start: Integer,
+	end_inclusive: Integer,
+}
+
+/// Error raised when creating a range whose start is greater than its end.
+#[derive(Debug)]
+#[cfg_attr(
+	feature = "std",
+	derive(thiserror::Error),
+	error("empty integer range")
+)]
+pub struct EmptyRange;
+
+impl IntegerRange {
+	/// Creates a new range from `start` to `end_inclusive`.
+	///
+	/// Returns an error if `start > end_inclusive`.
+	pub fn new(start: Integer, end_inclusive: Integer) -> Result<Self, EmptyRange> {
+		if start <= end_inclusive {
+			Ok(Self {
+				start,
+				end_inclusive,
+			})
+		} else {
+			Err(EmptyRange)
+		}
+	}
+
+	/// Returns the next integer yielded by this range.
+	pub fn start(&self) -> &Integer {
+		&self.start
+	}
+
+	/// Returns the (inclusive) upper bound of this range.
+	pub fn end_inclusive(&self) -> &Integer {
+		&self.end_inclusive
+	}
+
+	/// Checks if the given integer is in the range.
+	pub fn contains(&self, n: &Integer) -> bool {
+		self.start <= *n && *n <= self.end_inclusive
+	}
+
+	/// Checks if the range is empty, meaning that the iterator is exhausted.
+	pub fn is_empty(&self) -> bool {
+		self.start > self.end_inclusive
+	}
+
+	/// Returns the number of integers remaining in the range.
+	pub fn len(&self) -> NonNegativeInteger {
+		if self.is_empty() {
+			NonNegativeInteger::zero()
+		} else {
+			unsafe {
+				// This is safe because `end_inclusive >= start`.
+				NonNegativeInteger::new_unchecked(
+					&self.end_inclusive.0 - &self.start.0 + BigInt::one(),
+				)
+			}
+		}
+	}
+}
+
+impl Iterator for IntegerRange {
+	type Item = Integer;
+
+	fn next(&mut self) -> Option<Integer> {
+		if self.is_empty() {
+			None
+		} else {
+			let next = Integer(&self.start.0 + BigInt::one());
+			Some(core::mem::replace(&mut self.start, next))
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match self.len().into_big_int().to_usize() {
+			Some(len) => (len, Some(len)),
+			None => (usize::MAX, None),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn iter_01() {
+		let range = IntegerRange::new(Integer::from(-2i64), Integer::from(2i64)).unwrap();
+		assert_eq!(range.len(), NonNegativeInteger::from(5u64));
+		let items: Vec<_> = range.collect();
+		assert_eq!(items, [-2i64, -1, 0, 1, 2].map(Integer::from).to_vec())
+	}
+
+	#[test]
+	fn iter_02() {
+		let mut range = IntegerRange::new(Integer::one(), Integer::one()).unwrap();
+		assert!(!range.is_empty());
+		assert_eq!(range.next(), Some(Integer::one()));
+		assert!(range.is_empty());
+		assert_eq!(range.len(), NonNegativeInteger::zero());
+		assert_eq!(range.next(), None);
+	}
+
+	#[test]
+	fn iter_03() {
+		let start = Integer::from(BigInt::from(u64::MAX));
+		let end = Integer::from(BigInt::from(u64::MAX) * 4u32);
+		let mut range = IntegerRange::new(start.clone(), end.clone()).unwrap();
+		assert!(range.contains(&Integer::from(BigInt::from(u64::MAX) * 2u32)));
+		assert!(!range.contains(&Integer::from(0i64)));
+		assert_eq!(range.next(), Some(start.clone()));
+		assert_eq!(
+			range.next(),
+			Some(Integer::from(BigInt::from(u64::MAX) + 1u32))
+		);
+	}
+
+	#[test]
+	fn new_01() {
+		assert!(IntegerRange::new(Integer::one(), Integer::zero()).is_err())
+	}
+}
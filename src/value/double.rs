@@ -1,5 +1,6 @@
 use std::{
 	borrow::Borrow,
+	cmp::Ordering,
 	fmt,
 	ops::{Add, Deref, DerefMut, Div, Mul, Sub},
 	str::FromStr,
@@ -9,10 +10,17 @@ use ordered_float::OrderedFloat;
 
 use crate::{
 	lexical::{self, LexicalFormOf},
-	Datatype, ParseRdf, XsdDatatype,
+	Datatype, Float, ParseRdf, XsdDatatype,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Double precision floating point number.
+///
+/// Values are totally ordered following the XSD order, where
+/// `-INF < finite numbers < INF < NaN`. Positive and negative zero are equal,
+/// and all NaNs are equal to each other. This is the order provided by
+/// [`OrderedFloat`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
 pub struct Double(OrderedFloat<f64>);
 
 impl Double {
@@ -27,6 +35,16 @@ impl Double {
 		Self(OrderedFloat(f))
 	}
 
+	/// Parses a double from its XSD lexical form.
+	///
+	/// Unlike the standard `f64` parser, this is case-sensitive and only
+	/// accepts the XSD special values `INF`, `-INF` and `NaN` (not `inf`,
+	/// `nan` or `Infinity`). This is what the [`FromStr`] and [`ParseRdf`]
+	/// implementations use.
+	pub fn from_xsd_str(s: &str) -> Result<Self, lexical::InvalidDouble> {
+		Ok(lexical::Double::new(s)?.into())
+	}
+
 	/// Returns `true` if this value is NaN.
 	#[inline(always)]
 	pub fn is_nan(&self) -> bool {
@@ -80,6 +98,55 @@ impl Double {
 	pub const fn into_f64(self) -> f64 {
 		self.0 .0
 	}
+
+	/// Returns a key whose natural ordering matches the XSD total order on
+	/// doubles.
+	///
+	/// Both zeros are mapped to the same key, and every NaN is mapped to
+	/// `u64::MAX`, above positive infinity.
+	pub fn total_order_value(&self) -> u64 {
+		let f = self.0 .0;
+		if f.is_nan() {
+			u64::MAX
+		} else if f == 0.0 {
+			0x8000_0000_0000_0000
+		} else {
+			let bits = f.to_bits();
+			if bits & 0x8000_0000_0000_0000 == 0 {
+				bits | 0x8000_0000_0000_0000
+			} else {
+				!bits
+			}
+		}
+	}
+
+	/// Widens a float into a double.
+	///
+	/// Every float is exactly representable as a double, so this conversion
+	/// never loses precision.
+	#[inline(always)]
+	pub fn from_float(f: Float) -> Self {
+		Self::new(f.into_f32() as f64)
+	}
+
+	/// Compares `self` and `other` according to the XSD total order.
+	pub fn xsd_cmp(&self, other: &Self) -> Ordering {
+		self.total_order_value().cmp(&other.total_order_value())
+	}
+}
+
+impl PartialOrd for Double {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Double {
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.xsd_cmp(other)
+	}
 }
 
 // <https://www.w3.org/TR/xmlschema11-2/#f-doubleLexmap>
@@ -87,13 +154,26 @@ const XSD_CANONICAL_DOUBLE: pretty_dtoa::FmtFloatConfig = pretty_dtoa::FmtFloatC
 	.force_e_notation()
 	.capitalize_e(true);
 
+/// Formats the value using its XSD canonical representation: `INF`, `-INF`,
+/// `NaN`, or a mantissa with a single non-zero digit before the decimal
+/// point followed by an exponent (e.g. `1.5E3` or `-0.0E0`).
 impl fmt::Display for Double {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		pretty_dtoa::dtoa(self.0 .0, XSD_CANONICAL_DOUBLE).fmt(f)
+		let value = self.0 .0;
+		if value.is_nan() {
+			f.write_str("NaN")
+		} else if value == f64::INFINITY {
+			f.write_str("INF")
+		} else if value == f64::NEG_INFINITY {
+			f.write_str("-INF")
+		} else {
+			pretty_dtoa::dtoa(value, XSD_CANONICAL_DOUBLE).fmt(f)
+		}
 	}
 }
 
 impl XsdDatatype for Double {
+	#[inline(always)]
 	fn type_(&self) -> Datatype {
 		Datatype::Double
 	}
@@ -127,8 +207,7 @@ impl FromStr for Double {
 	type Err = lexical::InvalidDouble;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let l = lexical::Double::new(s)?;
-		Ok(l.into())
+		Self::from_xsd_str(s)
 	}
 }
 
@@ -138,6 +217,18 @@ impl From<f32> for Double {
 	}
 }
 
+/// Widening conversion, going through `f32 as f64`.
+///
+/// This is always exact, but note that the resulting double is the exact
+/// value of the float, not the closest double to its decimal form: `0.1`
+/// as a float becomes `0.10000000149011612` as a double.
+impl From<Float> for Double {
+	#[inline(always)]
+	fn from(value: Float) -> Self {
+		Self::from_float(value)
+	}
+}
+
 impl From<f64> for Double {
 	fn from(value: f64) -> Self {
 		Self(OrderedFloat(value))
@@ -207,3 +298,94 @@ impl Div for Double {
 		Self(OrderedFloat(*self.0 / *rhs.0))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn total_order_01() {
+		let values = [
+			Double::NEG_INFINITY,
+			Double::MIN,
+			Double::new(-1.0),
+			Double::new(-f64::MIN_POSITIVE),
+			Double::new(0.0),
+			Double::new(f64::MIN_POSITIVE),
+			Double::new(1.0),
+			Double::MAX,
+			Double::INFINITY,
+			Double::NAN,
+		];
+
+		for w in values.windows(2) {
+			assert!(w[0].total_order_value() < w[1].total_order_value());
+			assert_eq!(w[0].xsd_cmp(&w[1]), Ordering::Less);
+			assert_eq!(w[0].cmp(&w[1]), w[0].0.cmp(&w[1].0))
+		}
+	}
+
+	#[test]
+	fn total_order_02() {
+		assert_eq!(
+			Double::new(-0.0).xsd_cmp(&Double::new(0.0)),
+			Ordering::Equal
+		);
+		assert_eq!(
+			Double::NAN.xsd_cmp(&Double::new(-f64::NAN)),
+			Ordering::Equal
+		)
+	}
+
+	#[test]
+	fn from_float_01() {
+		assert_eq!(Double::from(Float::new(1.5)), Double::new(1.5));
+		assert_eq!(
+			Double::from_float(Float::new(0.1)),
+			Double::new(0.1f32 as f64)
+		);
+		assert_ne!(Double::from_float(Float::new(0.1)), Double::new(0.1));
+		assert!(Double::from(Float::NAN).is_nan());
+		assert_eq!(Double::from(Float::NEG_INFINITY), Double::NEG_INFINITY)
+	}
+
+	#[test]
+	fn format_01() {
+		assert_eq!(Double::new(1.0).to_string(), "1.0E0");
+		assert_eq!(Double::from(1.5f32), Double::new(1.5));
+		assert_eq!("-INF".parse::<Double>().unwrap(), Double::NEG_INFINITY)
+	}
+
+	#[test]
+	fn format_02() {
+		for (value, expected) in [
+			(Double::INFINITY, "INF"),
+			(Double::NEG_INFINITY, "-INF"),
+			(Double::NAN, "NaN"),
+			(Double::new(0.0), "0.0E0"),
+			(Double::new(-0.0), "-0.0E0"),
+			(Double::new(100.0), "1.0E2"),
+			(Double::new(-0.00125), "-1.25E-3"),
+		] {
+			let s = value.to_string();
+			assert_eq!(s, expected);
+			assert_eq!(
+				s.parse::<Double>().unwrap().into_f64().to_bits(),
+				value.into_f64().to_bits()
+			)
+		}
+	}
+
+	#[test]
+	fn from_xsd_str_01() {
+		for input in ["INF", "-INF", "NaN", "1.5E3", "-0"] {
+			assert!(Double::from_xsd_str(input).is_ok(), "{input}");
+			assert!(input.parse::<Double>().is_ok(), "{input}")
+		}
+
+		for input in ["inf", "-inf", "nan", "Infinity", "NAN", "1.5e"] {
+			assert!(Double::from_xsd_str(input).is_err(), "{input}");
+			assert!(input.parse::<Double>().is_err(), "{input}")
+		}
+	}
+}
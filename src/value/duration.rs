@@ -1,8 +1,624 @@
-use crate::{Datatype, XsdDatatype};
-use core::fmt;
+use crate::{lexical, Datatype, DateTime, Decimal, ParseRdf, XsdDatatype};
+use core::{cmp::Ordering, fmt};
+use num_bigint::BigInt;
+use num_traits::{Signed, ToPrimitive};
+use once_cell::sync::Lazy;
+use std::ops::Neg;
 
-#[derive(Debug, Clone, Copy)]
-pub struct Duration(());
+pub(crate) const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+pub(crate) const SECONDS_PER_DAY: u64 = 86_400;
+const SECONDS_PER_WEEK: u64 = 7 * SECONDS_PER_DAY;
+
+/// Reference date-times used to compare durations, as given by the XSD
+/// specification.
+///
+/// See [`Duration::xsd_partial_cmp`].
+fn reference_date_times() -> &'static [DateTime; 4] {
+	static REFERENCES: Lazy<[DateTime; 4]> = Lazy::new(|| {
+		[
+			"1696-09-01T00:00:00Z",
+			"1697-02-01T00:00:00Z",
+			"1903-03-01T00:00:00Z",
+			"1903-07-01T00:00:00Z",
+		]
+		.map(|r| r.parse().unwrap())
+	});
+
+	&REFERENCES
+}
+
+/// Error raised when parsing an invalid ISO 8601 duration.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid ISO 8601 duration")]
+pub struct ParseIso8601Error;
+
+/// Error raised when the number of months of a duration overflows a `u32`,
+/// or its number of seconds overflows a `u64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("duration out of range")]
+pub struct DurationOverflow;
+
+/// Duration.
+///
+/// Following the XSD 1.1 value space, a duration is made of an integer
+/// number of months and a (decimal) number of seconds, both sharing the same
+/// sign.
+///
+/// Durations are only partially ordered, since the length of a month
+/// varies: `P1M` is greater than `P27D` and less than `P32D`, but is
+/// incomparable with `P30D`. See [`Duration::xsd_partial_cmp`].
+///
+/// See: <https://www.w3.org/TR/xmlschema11-2/#duration>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Duration {
+	is_negative: bool,
+	months: u32,
+	seconds: u64,
+	nano_seconds: u32,
+}
+
+impl Duration {
+	/// Zero duration.
+	pub const ZERO: Self = Self {
+		is_negative: false,
+		months: 0,
+		seconds: 0,
+		nano_seconds: 0,
+	};
+
+	/// Creates a new duration.
+	///
+	/// Nanoseconds exceeding one second are carried over to the seconds.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of seconds overflows a `u64` once the
+	/// nanoseconds are carried over.
+	pub fn new(is_negative: bool, months: u32, seconds: u64, nano_seconds: u32) -> Self {
+		Self::checked_new(is_negative, months, seconds, nano_seconds)
+			.expect("duration out of range")
+	}
+
+	/// Creates a new duration, or returns `None` if the number of seconds
+	/// overflows a `u64` once the nanoseconds are carried over.
+	///
+	/// See [`Self::new`].
+	pub fn checked_new(
+		is_negative: bool,
+		months: u32,
+		seconds: u64,
+		nano_seconds: u32,
+	) -> Option<Self> {
+		let seconds = seconds.checked_add((nano_seconds / NANOSECONDS_PER_SECOND) as u64)?;
+		let nano_seconds = nano_seconds % NANOSECONDS_PER_SECOND;
+		let is_zero = months == 0 && seconds == 0 && nano_seconds == 0;
+
+		Some(Self {
+			is_negative: is_negative && !is_zero,
+			months,
+			seconds,
+			nano_seconds,
+		})
+	}
+
+	/// Returns the zero duration.
+	#[inline(always)]
+	pub fn zero() -> Self {
+		Self::ZERO
+	}
+
+	/// Returns a builder to create a duration from its components.
+	pub fn builder() -> DurationBuilder {
+		DurationBuilder::default()
+	}
+
+	/// Creates a duration of `y` years.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of months overflows a `u32`.
+	pub fn from_years(y: i64) -> Self {
+		let months = y
+			.unsigned_abs()
+			.checked_mul(12)
+			.and_then(|m| u32::try_from(m).ok())
+			.expect("duration out of range");
+		Self::new(y < 0, months, 0, 0)
+	}
+
+	/// Creates a duration of `m` months.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of months overflows a `u32`.
+	pub fn from_months(m: i64) -> Self {
+		let months = u32::try_from(m.unsigned_abs()).expect("duration out of range");
+		Self::new(m < 0, months, 0, 0)
+	}
+
+	/// Creates a duration of `d` days.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of seconds overflows a `u64`.
+	pub fn from_days(d: i64) -> Self {
+		Self::from_signed_units(d, SECONDS_PER_DAY)
+	}
+
+	/// Creates a duration of `h` hours.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of seconds overflows a `u64`.
+	pub fn from_hours(h: i64) -> Self {
+		Self::from_signed_units(h, 3600)
+	}
+
+	/// Creates a duration of `m` minutes.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of seconds overflows a `u64`.
+	pub fn from_minutes(m: i64) -> Self {
+		Self::from_signed_units(m, 60)
+	}
+
+	/// Creates a duration of `s` seconds.
+	///
+	/// Fractions of seconds beyond the nanosecond are truncated.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of seconds overflows a `u64`.
+	pub fn from_seconds(s: Decimal) -> Self {
+		let nano_seconds = (s.as_big_rational().abs() * BigInt::from(NANOSECONDS_PER_SECOND))
+			.trunc()
+			.to_integer()
+			.to_u128()
+			.expect("duration out of range");
+		let seconds = u64::try_from(nano_seconds / NANOSECONDS_PER_SECOND as u128)
+			.expect("duration out of range");
+
+		Self::new(
+			s.is_negative(),
+			0,
+			seconds,
+			(nano_seconds % NANOSECONDS_PER_SECOND as u128) as u32,
+		)
+	}
+
+	fn from_signed_units(n: i64, unit: u64) -> Self {
+		let seconds = n
+			.unsigned_abs()
+			.checked_mul(unit)
+			.expect("duration out of range");
+		Self::new(n < 0, 0, seconds, 0)
+	}
+
+	#[inline(always)]
+	pub fn is_zero(&self) -> bool {
+		self.months == 0 && self.seconds == 0 && self.nano_seconds == 0
+	}
+
+	#[inline(always)]
+	pub fn is_negative(&self) -> bool {
+		self.is_negative
+	}
+
+	/// Returns the absolute number of months of the year-month part of this
+	/// duration.
+	#[inline(always)]
+	pub fn months(&self) -> u32 {
+		self.months
+	}
+
+	/// Returns the absolute number of whole seconds of the day-time part of
+	/// this duration.
+	#[inline(always)]
+	pub fn seconds(&self) -> u64 {
+		self.seconds
+	}
+
+	/// Returns the fractional part of the seconds of this duration, in
+	/// nanoseconds.
+	#[inline(always)]
+	pub fn nano_seconds(&self) -> u32 {
+		self.nano_seconds
+	}
+
+	/// Returns the number of weeks of this duration, if it is a non negative
+	/// whole number of weeks, without any other component.
+	pub fn to_weeks(&self) -> Option<u64> {
+		if !self.is_negative
+			&& self.months == 0
+			&& self.nano_seconds == 0
+			&& self.seconds % SECONDS_PER_WEEK == 0
+		{
+			Some(self.seconds / SECONDS_PER_WEEK)
+		} else {
+			None
+		}
+	}
+
+	/// Returns an approximation of the total number of seconds of this
+	/// duration, assuming the average month of the Gregorian calendar
+	/// (30.436875 days, that is 365.2425 days per year).
+	///
+	/// This is only an approximation: the actual number of seconds of a
+	/// duration with months depends on the date it is added to. See
+	/// [`Self::min_total_seconds`] and [`Self::max_total_seconds`] for
+	/// bounds.
+	pub fn total_seconds_approximate(&self) -> f64 {
+		self.signed_total_seconds(self.months as f64 * 30.436875 * SECONDS_PER_DAY as f64)
+	}
+
+	/// Returns a lower bound of the total number of seconds of this duration,
+	/// using 28-day months and 365-day years.
+	///
+	/// For negative durations, the bounds are swapped so that this is never
+	/// greater than [`Self::max_total_seconds`].
+	pub fn min_total_seconds(&self) -> f64 {
+		if self.is_negative {
+			self.signed_total_seconds(self.month_seconds(31, 366))
+		} else {
+			self.signed_total_seconds(self.month_seconds(28, 365))
+		}
+	}
+
+	/// Returns an upper bound of the total number of seconds of this
+	/// duration, using 31-day months and 366-day years.
+	///
+	/// For negative durations, the bounds are swapped so that this is never
+	/// less than [`Self::min_total_seconds`].
+	pub fn max_total_seconds(&self) -> f64 {
+		if self.is_negative {
+			self.signed_total_seconds(self.month_seconds(28, 365))
+		} else {
+			self.signed_total_seconds(self.month_seconds(31, 366))
+		}
+	}
+
+	/// Returns the number of seconds of the year-month part of this
+	/// duration given the number of days per month and year.
+	fn month_seconds(&self, days_per_month: u32, days_per_year: u32) -> f64 {
+		let days = (self.months / 12) as f64 * days_per_year as f64
+			+ (self.months % 12) as f64 * days_per_month as f64;
+		days * SECONDS_PER_DAY as f64
+	}
+
+	/// Adds the day-time part of this duration to the given number of
+	/// seconds, and applies the sign of this duration.
+	fn signed_total_seconds(&self, month_seconds: f64) -> f64 {
+		let seconds = month_seconds
+			+ self.seconds as f64
+			+ self.nano_seconds as f64 / NANOSECONDS_PER_SECOND as f64;
+		if self.is_negative {
+			-seconds
+		} else {
+			seconds
+		}
+	}
+
+	/// Compares two durations following the XSD partial order.
+	///
+	/// Both durations are added to each of the four reference date-times
+	/// given by the XSD specification (`1696-09-01T00:00:00Z`,
+	/// `1697-02-01T00:00:00Z`, `1903-03-01T00:00:00Z` and
+	/// `1903-07-01T00:00:00Z`). The durations are ordered only if the
+	/// resulting date-times are ordered the same way for all four,
+	/// otherwise they are incomparable and `None` is returned.
+	///
+	/// For instance `P1M` and `P30D` are incomparable, whereas `P1M` is
+	/// greater than `P27D` and less than `P32D`.
+	///
+	/// Durations so large that adding them to a reference date-time
+	/// overflows are also considered incomparable.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema11-2/#duration>
+	pub fn xsd_partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		if self == other {
+			return Some(Ordering::Equal);
+		}
+
+		let mut result = None;
+		for reference in reference_date_times() {
+			let ordering = reference
+				.checked_add_duration(self)
+				.ok()?
+				.cmp(&reference.checked_add_duration(other).ok()?);
+
+			match result {
+				None => result = Some(ordering),
+				Some(r) if r == ordering => (),
+				Some(_) => return None,
+			}
+		}
+
+		result.filter(|r| *r != Ordering::Equal)
+	}
+
+	/// Formats this duration using the ISO 8601 format with designators
+	/// (`PnYnMnDTnHnMnS`).
+	///
+	/// This is the XSD canonical representation, which is also a valid ISO
+	/// 8601 duration, except for negative durations: ISO 8601 has no
+	/// negative durations, and the leading `-` sign used here is an
+	/// extension (also found in ISO 8601-2).
+	pub fn to_iso8601(&self) -> String {
+		self.to_string()
+	}
+
+	/// Parses an ISO 8601 duration with designators.
+	///
+	/// Compared to the XSD lexical space, the following is accepted:
+	///   - the week designator `W`, one week being 7 days (`P2W` is the
+	///     same duration as `P14D`);
+	///   - a comma as decimal sign for seconds (`PT1,5S`).
+	///
+	/// A leading `-` sign is accepted as in XSD. Fractions are only allowed
+	/// on seconds, and the alternative format (`PYYYY-MM-DDThh:mm:ss`) is not
+	/// supported.
+	pub fn from_iso8601(s: &str) -> Result<Self, ParseIso8601Error> {
+		let (is_negative, s) = match s.strip_prefix('-') {
+			Some(s) => (true, s),
+			None => (false, s),
+		};
+
+		let s = s.strip_prefix('P').ok_or(ParseIso8601Error)?;
+		let (date, time) = match s.split_once('T') {
+			Some((_, "")) => return Err(ParseIso8601Error),
+			Some((date, time)) => (date, Some(time)),
+			None => (s, None),
+		};
+
+		if date.is_empty() && time.is_none() {
+			return Err(ParseIso8601Error);
+		}
+
+		let mut months = 0u32;
+		let mut seconds = 0u64;
+		let mut nano_seconds = 0u32;
+
+		for (n, designator) in components(date, "YMWD")? {
+			let n = parse_u64(n)?;
+			match designator {
+				'Y' => {
+					let n = u32::try_from(n)
+						.ok()
+						.and_then(|n| n.checked_mul(12))
+						.ok_or(ParseIso8601Error)?;
+					months = months.checked_add(n).ok_or(ParseIso8601Error)?
+				}
+				'M' => {
+					let n = u32::try_from(n).map_err(|_| ParseIso8601Error)?;
+					months = months.checked_add(n).ok_or(ParseIso8601Error)?
+				}
+				'W' => seconds = add_seconds(seconds, n, SECONDS_PER_WEEK)?,
+				_ => seconds = add_seconds(seconds, n, SECONDS_PER_DAY)?,
+			}
+		}
+
+		if let Some(time) = time {
+			for (n, designator) in components(time, "HMS")? {
+				match designator {
+					'H' => seconds = add_seconds(seconds, parse_u64(n)?, 3600)?,
+					'M' => seconds = add_seconds(seconds, parse_u64(n)?, 60)?,
+					_ => {
+						let (whole, fraction) = match n.split_once(['.', ',']) {
+							Some((whole, fraction)) => (whole, Some(fraction)),
+							None => (n, None),
+						};
+
+						seconds = add_seconds(seconds, parse_u64(whole)?, 1)?;
+
+						if let Some(fraction) = fraction {
+							if fraction.is_empty() || !fraction.bytes().all(|c| c.is_ascii_digit())
+							{
+								return Err(ParseIso8601Error);
+							}
+
+							nano_seconds = fraction
+								.bytes()
+								.chain(std::iter::repeat(b'0'))
+								.take(9)
+								.fold(0, |n, c| n * 10 + (c - b'0') as u32)
+						}
+					}
+				}
+			}
+		}
+
+		Ok(Self::new(is_negative, months, seconds, nano_seconds))
+	}
+
+	/// Returns the signed number of months of this duration.
+	pub(crate) fn signed_months(&self) -> i64 {
+		if self.is_negative {
+			-(self.months as i64)
+		} else {
+			self.months as i64
+		}
+	}
+
+	/// Returns the signed day-time part of this duration in nanoseconds.
+	pub(crate) fn signed_nano_seconds(&self) -> i128 {
+		let n = self.seconds as i128 * NANOSECONDS_PER_SECOND as i128 + self.nano_seconds as i128;
+		if self.is_negative {
+			-n
+		} else {
+			n
+		}
+	}
+
+	/// Creates a day-time duration from a signed number of nanoseconds.
+	pub(crate) fn from_signed_nano_seconds(n: i128) -> Self {
+		let abs = n.unsigned_abs();
+		Self::new(
+			n < 0,
+			0,
+			(abs / NANOSECONDS_PER_SECOND as u128) as u64,
+			(abs % NANOSECONDS_PER_SECOND as u128) as u32,
+		)
+	}
+}
+
+/// Duration builder.
+///
+/// Components are added together when building the duration, so
+/// `Duration::builder().hours(36).build()` is the same as
+/// `Duration::builder().days(1).hours(12).build()`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DurationBuilder {
+	is_negative: bool,
+	years: u64,
+	months: u64,
+	days: u64,
+	hours: u64,
+	minutes: u64,
+	seconds: u64,
+	nano_seconds: u32,
+}
+
+impl DurationBuilder {
+	/// Makes the built duration negative.
+	pub fn negative(mut self) -> Self {
+		self.is_negative = true;
+		self
+	}
+
+	pub fn years(mut self, years: u64) -> Self {
+		self.years = years;
+		self
+	}
+
+	pub fn months(mut self, months: u64) -> Self {
+		self.months = months;
+		self
+	}
+
+	pub fn days(mut self, days: u64) -> Self {
+		self.days = days;
+		self
+	}
+
+	pub fn hours(mut self, hours: u64) -> Self {
+		self.hours = hours;
+		self
+	}
+
+	pub fn minutes(mut self, minutes: u64) -> Self {
+		self.minutes = minutes;
+		self
+	}
+
+	pub fn seconds(mut self, seconds: u64) -> Self {
+		self.seconds = seconds;
+		self
+	}
+
+	/// Sets the fractional part of the seconds, in nanoseconds.
+	pub fn nano_seconds(mut self, nano_seconds: u32) -> Self {
+		self.nano_seconds = nano_seconds;
+		self
+	}
+
+	/// Builds the duration.
+	///
+	/// # Panics
+	///
+	/// Panics if the total number of months overflows a `u32`, or if the
+	/// total number of seconds overflows a `u64`.
+	pub fn build(self) -> Duration {
+		self.checked_build().expect("duration out of range")
+	}
+
+	/// Builds the duration, or returns `None` if the total number of months
+	/// overflows a `u32`, or if the total number of seconds overflows a
+	/// `u64`.
+	pub fn checked_build(self) -> Option<Duration> {
+		let months = self
+			.years
+			.checked_mul(12)
+			.and_then(|m| m.checked_add(self.months))
+			.and_then(|m| u32::try_from(m).ok())?;
+
+		let seconds = [
+			(self.days, SECONDS_PER_DAY),
+			(self.hours, 3600),
+			(self.minutes, 60),
+			(self.seconds, 1),
+		]
+		.into_iter()
+		.try_fold(0u64, |seconds, (n, unit)| {
+			n.checked_mul(unit).and_then(|n| seconds.checked_add(n))
+		})?;
+
+		Duration::checked_new(self.is_negative, months, seconds, self.nano_seconds)
+	}
+}
+
+/// Splits the given duration part into its `<number><designator>`
+/// components, checking that designators appear at most once and in the
+/// given order.
+fn components<'a>(
+	mut s: &'a str,
+	designators: &str,
+) -> Result<Vec<(&'a str, char)>, ParseIso8601Error> {
+	let mut result = Vec::new();
+	let mut allowed = designators;
+
+	while !s.is_empty() {
+		let i = s
+			.find(|c: char| c.is_ascii_alphabetic())
+			.ok_or(ParseIso8601Error)?;
+		let designator = s[i..].chars().next().unwrap();
+		let j = allowed.find(designator).ok_or(ParseIso8601Error)?;
+		allowed = &allowed[j + 1..];
+
+		if i == 0 {
+			return Err(ParseIso8601Error);
+		}
+
+		result.push((&s[..i], designator));
+		s = &s[i + 1..];
+	}
+
+	Ok(result)
+}
+
+fn parse_u64(s: &str) -> Result<u64, ParseIso8601Error> {
+	if s.bytes().all(|c| c.is_ascii_digit()) {
+		s.parse().map_err(|_| ParseIso8601Error)
+	} else {
+		Err(ParseIso8601Error)
+	}
+}
+
+fn add_seconds(seconds: u64, n: u64, unit: u64) -> Result<u64, ParseIso8601Error> {
+	n.checked_mul(unit)
+		.and_then(|n| seconds.checked_add(n))
+		.ok_or(ParseIso8601Error)
+}
+
+impl Neg for Duration {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		Self::new(
+			!self.is_negative,
+			self.months,
+			self.seconds,
+			self.nano_seconds,
+		)
+	}
+}
+
+/// Follows the XSD partial order, see [`Duration::xsd_partial_cmp`].
+impl PartialOrd for Duration {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		self.xsd_partial_cmp(other)
+	}
+}
 
 impl XsdDatatype for Duration {
 	fn type_(&self) -> Datatype {
@@ -10,8 +626,258 @@ impl XsdDatatype for Duration {
 	}
 }
 
+impl ParseRdf for Duration {
+	type LexicalForm = lexical::Duration;
+}
+
 impl fmt::Display for Duration {
-	fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		unimplemented!()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_negative {
+			f.write_str("-")?
+		}
+
+		f.write_str("P")?;
+
+		if self.is_zero() {
+			return f.write_str("T0S");
+		}
+
+		let years = self.months / 12;
+		let months = self.months % 12;
+
+		if years != 0 {
+			write!(f, "{years}Y")?
+		}
+
+		if months != 0 {
+			write!(f, "{months}M")?
+		}
+
+		let days = self.seconds / SECONDS_PER_DAY;
+		let hours = self.seconds % SECONDS_PER_DAY / 3600;
+		let minutes = self.seconds % 3600 / 60;
+		let seconds = self.seconds % 60;
+
+		if days != 0 {
+			write!(f, "{days}D")?
+		}
+
+		if hours != 0 || minutes != 0 || seconds != 0 || self.nano_seconds != 0 {
+			f.write_str("T")?;
+
+			if hours != 0 {
+				write!(f, "{hours}H")?
+			}
+
+			if minutes != 0 {
+				write!(f, "{minutes}M")?
+			}
+
+			if seconds != 0 || self.nano_seconds != 0 {
+				write!(f, "{seconds}")?;
+
+				if self.nano_seconds != 0 {
+					let fraction = format!("{:09}", self.nano_seconds);
+					write!(f, ".{}", fraction.trim_end_matches('0'))?
+				}
+
+				f.write_str("S")?
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn format_01() {
+		assert_eq!(Duration::zero().to_string(), "PT0S")
+	}
+
+	#[test]
+	fn format_02() {
+		assert_eq!(
+			Duration::new(false, 14, 3 * 86400 + 4 * 3600 + 5 * 60 + 6, 500_000_000).to_string(),
+			"P1Y2M3DT4H5M6.5S"
+		)
+	}
+
+	#[test]
+	fn format_03() {
+		assert_eq!(Duration::new(true, 0, 7200, 0).to_string(), "-PT2H")
+	}
+
+	#[test]
+	fn format_04() {
+		assert_eq!(Duration::new(true, 0, 0, 0).to_string(), "PT0S")
+	}
+
+	#[test]
+	fn iso8601_01() {
+		for (input, expected) in [
+			("P2W", "P14D"),
+			("P1Y2M3DT4H5M6.5S", "P1Y2M3DT4H5M6.5S"),
+			("-PT2H", "-PT2H"),
+			("PT1,25S", "PT1.25S"),
+			("P1W2D", "P9D"),
+			("PT36H", "P1DT12H"),
+			("P0D", "PT0S"),
+		] {
+			let d = Duration::from_iso8601(input).unwrap();
+			assert_eq!(d.to_iso8601(), expected, "{input}")
+		}
+	}
+
+	#[test]
+	fn iso8601_02() {
+		for input in [
+			"",
+			"P",
+			"PT",
+			"1D",
+			"P1",
+			"PD",
+			"P1D2Y",
+			"P1M1M",
+			"PT1.5H",
+			"P1DT",
+			"P+1D",
+			"PT1.S",
+			"P99999999999Y",
+		] {
+			assert!(Duration::from_iso8601(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn weeks_01() {
+		assert_eq!(Duration::from_iso8601("P2W").unwrap().to_weeks(), Some(2));
+		assert_eq!(Duration::from_iso8601("P14D").unwrap().to_weeks(), Some(2));
+		assert_eq!(Duration::from_iso8601("P15D").unwrap().to_weeks(), None);
+		assert_eq!(Duration::from_iso8601("P1M").unwrap().to_weeks(), None);
+		assert_eq!(Duration::from_iso8601("-P1W").unwrap().to_weeks(), None)
+	}
+
+	#[test]
+	fn constructors_01() {
+		for (d, expected) in [
+			(Duration::from_years(2), "P2Y"),
+			(Duration::from_years(-1), "-P1Y"),
+			(Duration::from_months(14), "P1Y2M"),
+			(Duration::from_days(-3), "-P3D"),
+			(Duration::from_hours(36), "P1DT12H"),
+			(Duration::from_minutes(90), "PT1H30M"),
+			(Duration::from_seconds("-1.5".parse().unwrap()), "-PT1.5S"),
+			(Duration::from_days(0), "PT0S"),
+			(Duration::ZERO, "PT0S"),
+		] {
+			assert_eq!(d.to_string(), expected)
+		}
+	}
+
+	#[test]
+	fn builder_01() {
+		assert_eq!(
+			Duration::builder()
+				.years(1)
+				.months(6)
+				.days(15)
+				.build()
+				.to_string(),
+			"P1Y6M15D"
+		);
+		assert_eq!(
+			Duration::builder()
+				.negative()
+				.hours(25)
+				.seconds(1)
+				.nano_seconds(250_000_000)
+				.build()
+				.to_string(),
+			"-P1DT1H1.25S"
+		);
+		assert!(Duration::builder().build().is_zero())
+	}
+
+	#[test]
+	fn builder_02() {
+		assert_eq!(
+			Duration::checked_new(false, 0, u64::MAX, 999_999_999),
+			Some(Duration::new(false, 0, u64::MAX, 999_999_999))
+		);
+		assert_eq!(
+			Duration::checked_new(false, 0, u64::MAX, 1_000_000_000),
+			None
+		);
+		assert_eq!(
+			Duration::builder()
+				.seconds(u64::MAX)
+				.nano_seconds(u32::MAX)
+				.checked_build(),
+			None
+		);
+		assert_eq!(Duration::builder().years(u64::MAX).checked_build(), None);
+		assert_eq!(
+			Duration::builder()
+				.days(u64::MAX / 86400 + 1)
+				.checked_build(),
+			None
+		);
+		assert_eq!(
+			Duration::builder().minutes(2).checked_build(),
+			Some(Duration::from_minutes(2))
+		)
+	}
+
+	#[test]
+	fn total_seconds_01() {
+		let d = Duration::builder().years(1).months(1).hours(1).build();
+		assert_eq!(
+			d.total_seconds_approximate(),
+			13.0 * 30.436875 * 86400.0 + 3600.0
+		);
+		assert_eq!(d.min_total_seconds(), (365.0 + 28.0) * 86400.0 + 3600.0);
+		assert_eq!(d.max_total_seconds(), (366.0 + 31.0) * 86400.0 + 3600.0);
+
+		let d = -Duration::from_months(1);
+		assert_eq!(d.min_total_seconds(), -31.0 * 86400.0);
+		assert_eq!(d.max_total_seconds(), -28.0 * 86400.0);
+
+		let d = Duration::from_seconds("1.5".parse().unwrap());
+		assert_eq!(d.total_seconds_approximate(), 1.5);
+		assert_eq!(d.min_total_seconds(), 1.5);
+		assert_eq!(d.max_total_seconds(), 1.5)
+	}
+
+	#[test]
+	fn partial_cmp_01() {
+		let month = Duration::from_months(1);
+		assert_eq!(month.partial_cmp(&Duration::from_days(30)), None);
+		assert_eq!(month.partial_cmp(&Duration::from_days(31)), None);
+		assert!(month > Duration::from_days(27));
+		assert!(month < Duration::from_days(32));
+		let year = Duration::from_years(1);
+		assert!(year > Duration::from_days(364));
+		assert_eq!(year.partial_cmp(&Duration::from_days(365)), None);
+		assert_eq!(year.partial_cmp(&Duration::from_days(366)), None);
+		assert!(year < Duration::from_days(367));
+		assert_eq!(
+			year.partial_cmp(&Duration::from_months(12)),
+			Some(Ordering::Equal)
+		);
+		assert!(-month < Duration::ZERO);
+		assert!(Duration::from_hours(24) < Duration::from_minutes(1441))
+	}
+
+	#[test]
+	fn partial_cmp_02() {
+		let d = Duration::from_years(300000);
+		assert_eq!(d.partial_cmp(&Duration::ZERO), None);
+		assert_eq!(Duration::ZERO.partial_cmp(&-d), None);
+		assert_eq!(d.partial_cmp(&d), Some(Ordering::Equal))
 	}
 }
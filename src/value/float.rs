@@ -1,5 +1,6 @@
 use std::{
 	borrow::Borrow,
+	cmp::Ordering,
 	fmt,
 	ops::{Add, Div, Mul, Sub},
 	str::FromStr,
@@ -9,10 +10,16 @@ use ordered_float::OrderedFloat;
 
 use crate::{
 	lexical::{self, LexicalFormOf},
-	Datatype, ParseRdf, XsdDatatype,
+	Datatype, Double, ParseRdf, XsdDatatype,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Single precision floating point number.
+///
+/// Values are totally ordered following the XSD order, where
+/// `-INF < finite numbers < INF < NaN`. Positive and negative zero are equal,
+/// and all NaNs are equal to each other. This is the order provided by
+/// [`OrderedFloat`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct Float(OrderedFloat<f32>);
 
@@ -28,6 +35,16 @@ impl Float {
 		Self(OrderedFloat(f))
 	}
 
+	/// Parses a float from its XSD lexical form.
+	///
+	/// Unlike the standard `f32` parser, this is case-sensitive and only
+	/// accepts the XSD special values `INF`, `-INF` and `NaN` (not `inf`,
+	/// `nan` or `Infinity`). This is what the [`FromStr`] and [`ParseRdf`]
+	/// implementations use.
+	pub fn from_xsd_str(s: &str) -> Result<Self, lexical::InvalidFloat> {
+		Ok(lexical::Float::new(s)?.into())
+	}
+
 	/// Returns `true` if this value is NaN.
 	#[inline(always)]
 	pub fn is_nan(&self) -> bool {
@@ -81,6 +98,68 @@ impl Float {
 	pub const fn into_f32(self) -> f32 {
 		self.0 .0
 	}
+
+	/// Returns a key whose natural ordering matches the XSD total order on
+	/// floats.
+	///
+	/// Both zeros are mapped to the same key, and every NaN is mapped to
+	/// `u32::MAX`, above positive infinity.
+	pub fn total_order_value(&self) -> u32 {
+		let f = self.0 .0;
+		if f.is_nan() {
+			u32::MAX
+		} else if f == 0.0 {
+			0x8000_0000
+		} else {
+			let bits = f.to_bits();
+			if bits & 0x8000_0000 == 0 {
+				bits | 0x8000_0000
+			} else {
+				!bits
+			}
+		}
+	}
+
+	/// Converts a double into a float, if it fits in the float value space.
+	///
+	/// See the `TryFrom<Double>` implementation for details.
+	pub fn try_from_double(d: Double) -> Option<Self> {
+		d.try_into().ok()
+	}
+
+	/// Converts a double into a float without any loss of precision.
+	///
+	/// Returns `None` unless the double is exactly representable as a float,
+	/// meaning that the `f64 as f32 as f64` round-trip gives back the same
+	/// number. NaN is always converted.
+	pub fn try_from_double_exact(d: Double) -> Option<Self> {
+		let f = d.into_f64();
+		let narrowed = f as f32;
+		if f.is_nan() || narrowed as f64 == f {
+			Some(Self::new(narrowed))
+		} else {
+			None
+		}
+	}
+
+	/// Compares `self` and `other` according to the XSD total order.
+	pub fn xsd_cmp(&self, other: &Self) -> Ordering {
+		self.total_order_value().cmp(&other.total_order_value())
+	}
+}
+
+impl PartialOrd for Float {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Float {
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.xsd_cmp(other)
+	}
 }
 
 // <https://www.w3.org/TR/xmlschema11-2/#f-doubleLexmap>
@@ -88,9 +167,21 @@ const XSD_CANONICAL_FLOAT: pretty_dtoa::FmtFloatConfig = pretty_dtoa::FmtFloatCo
 	.force_e_notation()
 	.capitalize_e(true);
 
+/// Formats the value using its XSD canonical representation: `INF`, `-INF`,
+/// `NaN`, or a mantissa with a single non-zero digit before the decimal
+/// point followed by an exponent (e.g. `1.5E3` or `-0.0E0`).
 impl fmt::Display for Float {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		pretty_dtoa::ftoa(self.0 .0, XSD_CANONICAL_FLOAT).fmt(f)
+		let value = self.0 .0;
+		if value.is_nan() {
+			f.write_str("NaN")
+		} else if value == f32::INFINITY {
+			f.write_str("INF")
+		} else if value == f32::NEG_INFINITY {
+			f.write_str("-INF")
+		} else {
+			pretty_dtoa::ftoa(value, XSD_CANONICAL_FLOAT).fmt(f)
+		}
 	}
 }
 
@@ -129,8 +220,7 @@ impl FromStr for Float {
 	type Err = lexical::InvalidFloat;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let l = lexical::Float::new(s)?;
-		Ok(l.into())
+		Self::from_xsd_str(s)
 	}
 }
 
@@ -155,6 +245,35 @@ impl From<Float> for f64 {
 	}
 }
 
+/// Error returned when narrowing a [`Double`] that does not fit in the
+/// [`Float`] value space.
+#[derive(Debug, thiserror::Error)]
+#[error("double out of float range: {0}")]
+pub struct DoubleOutOfFloatRange(pub Double);
+
+/// Narrowing conversion.
+///
+/// Finite doubles are rounded to the nearest float, losing precision
+/// beyond the 24 bits of the float mantissa. The conversion fails if the
+/// double is finite but greater than [`Float::MAX`] in magnitude (it would
+/// round to an infinity), or if a non-zero double is too small to be
+/// represented and would round to zero. Infinities and NaN are preserved.
+///
+/// Use [`Float::try_from_double_exact`] to reject any loss of precision.
+impl TryFrom<Double> for Float {
+	type Error = DoubleOutOfFloatRange;
+
+	fn try_from(value: Double) -> Result<Self, Self::Error> {
+		let f = value.into_f64();
+		let narrowed = f as f32;
+		if (f.is_finite() && narrowed.is_infinite()) || (f != 0.0 && narrowed == 0.0) {
+			Err(DoubleOutOfFloatRange(value))
+		} else {
+			Ok(Self::new(narrowed))
+		}
+	}
+}
+
 impl AsRef<f32> for Float {
 	#[inline(always)]
 	fn as_ref(&self) -> &f32 {
@@ -204,3 +323,108 @@ impl Div for Float {
 		Self(OrderedFloat(*self.0 / *rhs.0))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn total_order_01() {
+		let values = [
+			Float::NEG_INFINITY,
+			Float::MIN,
+			Float::new(-1.0),
+			Float::new(-f32::MIN_POSITIVE),
+			Float::new(0.0),
+			Float::new(f32::MIN_POSITIVE),
+			Float::new(1.0),
+			Float::MAX,
+			Float::INFINITY,
+			Float::NAN,
+		];
+
+		for w in values.windows(2) {
+			assert!(w[0].total_order_value() < w[1].total_order_value());
+			assert_eq!(w[0].xsd_cmp(&w[1]), Ordering::Less);
+			assert_eq!(w[0].cmp(&w[1]), w[0].0.cmp(&w[1].0))
+		}
+	}
+
+	#[test]
+	fn total_order_02() {
+		assert_eq!(Float::new(-0.0).xsd_cmp(&Float::new(0.0)), Ordering::Equal);
+		assert_eq!(Float::NAN.xsd_cmp(&Float::new(-f32::NAN)), Ordering::Equal);
+		assert_eq!(Float::new(-0.0), Float::new(0.0))
+	}
+
+	#[test]
+	fn from_double_01() {
+		assert_eq!(Float::try_from(Double::new(1.5)).unwrap(), Float::new(1.5));
+		assert_eq!(
+			Float::try_from(Double::new(0.1)).unwrap(),
+			Float::new(0.1f64 as f32)
+		);
+		assert!(Float::try_from(Double::INFINITY).unwrap().is_infinite());
+		assert!(Float::try_from(Double::NAN).unwrap().is_nan());
+		assert!(Float::try_from(Double::MAX).is_err());
+		assert!(Float::try_from(Double::new(1e-300)).is_err());
+		assert_eq!(
+			Float::try_from_double(Double::new(-2.0)),
+			Some(Float::new(-2.0))
+		);
+		assert_eq!(Float::try_from_double(Double::MIN), None)
+	}
+
+	#[test]
+	fn from_double_02() {
+		assert_eq!(
+			Float::try_from_double_exact(Double::new(0.5)),
+			Some(Float::new(0.5))
+		);
+		assert_eq!(Float::try_from_double_exact(Double::new(0.1)), None);
+		assert!(Float::try_from_double_exact(Double::NAN).unwrap().is_nan());
+		assert_eq!(
+			Float::try_from_double_exact(Double::NEG_INFINITY),
+			Some(Float::NEG_INFINITY)
+		)
+	}
+
+	#[test]
+	fn format_01() {
+		for (value, expected) in [
+			(Float::INFINITY, "INF"),
+			(Float::NEG_INFINITY, "-INF"),
+			(Float::NAN, "NaN"),
+			(Float::new(0.0), "0.0E0"),
+			(Float::new(-0.0), "-0.0E0"),
+			(Float::new(1.5), "1.5E0"),
+			(Float::new(-0.00125), "-1.25E-3"),
+		] {
+			let s = value.to_string();
+			assert_eq!(s, expected);
+			assert_eq!(
+				s.parse::<Float>().unwrap().into_f32().to_bits(),
+				value.into_f32().to_bits()
+			)
+		}
+	}
+
+	#[test]
+	fn default_01() {
+		assert_eq!(Float::default().into_f32().to_bits(), 0.0f32.to_bits());
+		assert_eq!(Double::default().into_f64().to_bits(), 0.0f64.to_bits())
+	}
+
+	#[test]
+	fn from_xsd_str_01() {
+		for input in ["INF", "-INF", "NaN", "1.5E3", "-0"] {
+			assert!(Float::from_xsd_str(input).is_ok(), "{input}");
+			assert!(input.parse::<Float>().is_ok(), "{input}")
+		}
+
+		for input in ["inf", "-inf", "nan", "Infinity", "NAN", "1.5e"] {
+			assert!(Float::from_xsd_str(input).is_err(), "{input}");
+			assert!(input.parse::<Float>().is_err(), "{input}")
+		}
+	}
+}
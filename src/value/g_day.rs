@@ -1,8 +1,34 @@
-use crate::{Datatype, XsdDatatype};
-use core::fmt;
+use chrono::FixedOffset;
 
-#[derive(Debug, Clone, Copy)]
-pub struct GDay(());
+use crate::{Datatype, Date, InvalidDate, XsdDatatype};
+use core::{fmt, str::FromStr};
+
+use super::date::{fmt_time_zone, parse_time_zone, parse_two_digits};
+
+/// Gregorian calendar day of a month.
+///
+/// See: <https://www.w3.org/TR/xmlschema11-2/#gDay>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GDay {
+	pub day: u8,
+	pub offset: Option<FixedOffset>,
+}
+
+impl GDay {
+	pub fn new(day: u8, offset: Option<FixedOffset>) -> Self {
+		Self { day, offset }
+	}
+
+	/// Returns the date of this day in the given year and month.
+	///
+	/// The date has the time zone of this day, or UTC if it has none.
+	/// Returns an error if the day does not exist in the given month (e.g.
+	/// `---31` in April).
+	pub fn to_date(&self, year: i32, month: u8) -> Result<Date, InvalidDate> {
+		let date = Date::from_ymd(year, month, self.day)?;
+		Ok(Date::new(date.date, self.offset.unwrap_or(date.offset)))
+	}
+}
 
 impl XsdDatatype for GDay {
 	fn type_(&self) -> Datatype {
@@ -10,8 +36,57 @@ impl XsdDatatype for GDay {
 	}
 }
 
+impl FromStr for GDay {
+	type Err = InvalidDate;
+
+	/// Parses a `gDay` lexical form, such as `---01` or `---31Z`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.strip_prefix("---").ok_or(InvalidDate)?;
+		let (day, s) = parse_two_digits(s, 1..=31)?;
+		Ok(Self::new(day, parse_time_zone(s)?))
+	}
+}
+
 impl fmt::Display for GDay {
-	fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		unimplemented!()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "---{:02}", self.day)?;
+
+		if let Some(offset) = &self.offset {
+			fmt_time_zone(offset, f)?
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in ["---01", "---31Z", "---15+14:00"] {
+			let d: GDay = input.parse().unwrap();
+			assert_eq!(d.to_string(), input)
+		}
+
+		for input in ["---00", "---32", "--01", "---1", "---01+15:00"] {
+			assert!(input.parse::<GDay>().is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn to_date_01() {
+		let last: GDay = "---31".parse().unwrap();
+		assert_eq!(last.to_date(2024, 1).unwrap().to_string(), "2024-01-31Z");
+		assert!(last.to_date(2024, 4).is_err());
+		assert!(last.to_date(2024, 13).is_err());
+
+		let leap_day: GDay = "---29-05:00".parse().unwrap();
+		assert_eq!(
+			leap_day.to_date(2000, 2).unwrap().to_string(),
+			"2000-02-29-05:00"
+		);
+		assert!(leap_day.to_date(1900, 2).is_err())
 	}
 }
@@ -1,8 +1,55 @@
-use crate::{Datatype, XsdDatatype};
-use core::fmt;
+use chrono::FixedOffset;
 
-#[derive(Debug, Clone, Copy)]
-pub struct GMonth;
+use crate::{Datatype, Date, InvalidDate, XsdDatatype};
+use core::{fmt, str::FromStr};
+
+use super::date::{days_in_month, fmt_time_zone, parse_time_zone, parse_two_digits};
+
+/// Gregorian calendar month.
+///
+/// See: <https://www.w3.org/TR/xmlschema11-2/#gMonth>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GMonth {
+	pub month: u8,
+	pub offset: Option<FixedOffset>,
+}
+
+impl GMonth {
+	pub fn new(month: u8, offset: Option<FixedOffset>) -> Self {
+		Self { month, offset }
+	}
+
+	/// Returns the first day of this month in the given year.
+	///
+	/// The date has the time zone of this month, or UTC if it has none.
+	///
+	/// # Panics
+	///
+	/// Panics if the month is not between 1 and 12, or if the year is out
+	/// of the range supported by [`Date`].
+	pub fn first_day(&self, year: i32) -> Date {
+		self.date(year, 1).expect("invalid month")
+	}
+
+	/// Returns the last day of this month in the given year.
+	///
+	/// The date has the time zone of this month, or UTC if it has none.
+	///
+	/// # Panics
+	///
+	/// Panics if the month is not between 1 and 12, or if the year is out
+	/// of the range supported by [`Date`].
+	pub fn last_day(&self, year: i32) -> Date {
+		days_in_month(year, self.month)
+			.and_then(|day| self.date(year, day).ok())
+			.expect("invalid month")
+	}
+
+	fn date(&self, year: i32, day: u8) -> Result<Date, InvalidDate> {
+		let date = Date::from_ymd(year, self.month, day)?;
+		Ok(Date::new(date.date, self.offset.unwrap_or(date.offset)))
+	}
+}
 
 impl XsdDatatype for GMonth {
 	fn type_(&self) -> Datatype {
@@ -10,8 +57,54 @@ impl XsdDatatype for GMonth {
 	}
 }
 
+impl FromStr for GMonth {
+	type Err = InvalidDate;
+
+	/// Parses a `gMonth` lexical form, such as `--05` or `--12Z`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.strip_prefix("--").ok_or(InvalidDate)?;
+		let (month, s) = parse_two_digits(s, 1..=12)?;
+		Ok(Self::new(month, parse_time_zone(s)?))
+	}
+}
+
 impl fmt::Display for GMonth {
-	fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		unimplemented!()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "--{:02}", self.month)?;
+
+		if let Some(offset) = &self.offset {
+			fmt_time_zone(offset, f)?
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		for input in ["--01", "--12Z", "--05-05:00"] {
+			let m: GMonth = input.parse().unwrap();
+			assert_eq!(m.to_string(), input)
+		}
+
+		for input in ["--00", "--13", "-05", "--5", "--05--", "--05T"] {
+			assert!(input.parse::<GMonth>().is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn days_01() {
+		let february: GMonth = "--02".parse().unwrap();
+		assert_eq!(february.first_day(2024).to_string(), "2024-02-01Z");
+		assert_eq!(february.last_day(2024).to_string(), "2024-02-29Z");
+		assert_eq!(february.last_day(2023).to_string(), "2023-02-28Z");
+		assert_eq!(february.last_day(1900).to_string(), "1900-02-28Z");
+
+		let december: GMonth = "--12+01:00".parse().unwrap();
+		assert_eq!(december.last_day(-1).to_string(), "-0001-12-31+01:00")
 	}
 }
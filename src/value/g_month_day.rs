@@ -1,8 +1,35 @@
-use crate::{Datatype, XsdDatatype};
-use core::fmt;
+use chrono::FixedOffset;
 
-#[derive(Debug, Clone, Copy)]
-pub struct GMonthDay(());
+use crate::{Datatype, Date, InvalidDate, XsdDatatype};
+use core::{fmt, str::FromStr};
+
+use super::date::{days_in_month, fmt_time_zone, parse_time_zone, parse_two_digits};
+
+/// Gregorian calendar day of a month.
+///
+/// See: <https://www.w3.org/TR/xmlschema11-2/#gMonthDay>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GMonthDay {
+	pub month: u8,
+	pub day: u8,
+	pub offset: Option<FixedOffset>,
+}
+
+impl GMonthDay {
+	pub fn new(month: u8, day: u8, offset: Option<FixedOffset>) -> Self {
+		Self { month, day, offset }
+	}
+
+	/// Returns the date of this month day in the given year.
+	///
+	/// The date has the time zone of this month day, or UTC if it has none.
+	/// Returns an error if the day does not exist in the given year (e.g.
+	/// `--02-29` in a non-leap year).
+	pub fn to_date(&self, year: i32) -> Result<Date, InvalidDate> {
+		let date = Date::from_ymd(year, self.month, self.day)?;
+		Ok(Date::new(date.date, self.offset.unwrap_or(date.offset)))
+	}
+}
 
 impl XsdDatatype for GMonthDay {
 	fn type_(&self) -> Datatype {
@@ -10,8 +37,49 @@ impl XsdDatatype for GMonthDay {
 	}
 }
 
+impl FromStr for GMonthDay {
+	type Err = InvalidDate;
+
+	/// Parses a `gMonthDay` lexical form, such as `--02-29` or `--12-25Z`.
+	///
+	/// February 29th is accepted since it exists in leap years.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.strip_prefix("--").ok_or(InvalidDate)?;
+		let (month, s) = parse_two_digits(s, 1..=12)?;
+		let s = s.strip_prefix('-').ok_or(InvalidDate)?;
+		let (day, s) = parse_two_digits(s, 1..=days_in_month(0, month).ok_or(InvalidDate)?)?;
+		Ok(Self::new(month, day, parse_time_zone(s)?))
+	}
+}
+
 impl fmt::Display for GMonthDay {
-	fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		unimplemented!()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "--{:02}-{:02}", self.month, self.day)?;
+
+		if let Some(offset) = &self.offset {
+			fmt_time_zone(offset, f)?
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn to_date_01() {
+		let leap_day: GMonthDay = "--02-29".parse().unwrap();
+		assert_eq!(leap_day.to_date(2024).unwrap().to_string(), "2024-02-29Z");
+		assert!(leap_day.to_date(2023).is_err());
+		assert_eq!(leap_day.to_date(2000).unwrap().to_string(), "2000-02-29Z");
+		assert!(leap_day.to_date(1900).is_err());
+
+		let christmas: GMonthDay = "--12-25+01:00".parse().unwrap();
+		assert_eq!(
+			christmas.to_date(-1).unwrap().to_string(),
+			"-0001-12-25+01:00"
+		)
 	}
 }
@@ -1,8 +1,118 @@
-use crate::{Datatype, XsdDatatype};
-use core::fmt;
+use chrono::FixedOffset;
 
-#[derive(Debug, Clone, Copy)]
-pub struct GYear(());
+use crate::{Datatype, Date, GMonthDay, InvalidDate, XsdDatatype};
+use core::{fmt, ops::Sub, str::FromStr};
+
+use super::date::{fmt_time_zone, fmt_year, is_leap_year, parse_time_zone, parse_year};
+
+/// Gregorian calendar year.
+///
+/// See: <https://www.w3.org/TR/xmlschema11-2/#gYear>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GYear {
+	pub year: i32,
+	pub offset: Option<FixedOffset>,
+}
+
+impl GYear {
+	pub fn new(year: i32, offset: Option<FixedOffset>) -> Self {
+		Self { year, offset }
+	}
+
+	/// Checks if this year is a leap year in the proleptic Gregorian calendar.
+	///
+	/// Year `0` (1 BCE) is a leap year.
+	pub fn is_leap(&self) -> bool {
+		is_leap_year(self.year)
+	}
+
+	/// Returns the number of days in this year.
+	pub fn length(&self) -> u16 {
+		if self.is_leap() {
+			366
+		} else {
+			365
+		}
+	}
+
+	/// Returns the first day of the year, `--01-01`.
+	pub fn first_day(&self) -> GMonthDay {
+		GMonthDay::new(1, 1, self.offset)
+	}
+
+	/// Returns the last day of the year, `--12-31`.
+	pub fn last_day(&self) -> GMonthDay {
+		GMonthDay::new(12, 31, self.offset)
+	}
+
+	/// Returns the first and last dates of the year, with the time zone of
+	/// this year (or UTC if it has none).
+	///
+	/// # Panics
+	///
+	/// Panics if the year is out of the range supported by [`Date`].
+	pub fn to_date_range(&self) -> (Date, Date) {
+		let first = self.first_day().to_date(self.year);
+		let last = self.last_day().to_date(self.year);
+		match (first, last) {
+			(Ok(first), Ok(last)) => (first, last),
+			_ => panic!("year out of range"),
+		}
+	}
+
+	/// Returns the following year, with the same time zone.
+	///
+	/// XSD includes year `0` (1 BCE), so the year following `-0001` is
+	/// `0000`.
+	///
+	/// # Panics
+	///
+	/// Panics if the resulting year overflows an `i32`.
+	pub fn next_year(&self) -> Self {
+		self.add_years(1)
+	}
+
+	/// Returns the preceding year, with the same time zone.
+	///
+	/// # Panics
+	///
+	/// Panics if the resulting year overflows an `i32`.
+	pub fn prev_year(&self) -> Self {
+		self.add_years(-1)
+	}
+
+	/// Adds `n` years to this year, keeping the same time zone.
+	///
+	/// # Panics
+	///
+	/// Panics if the resulting year overflows an `i32`.
+	pub fn add_years(&self, n: i32) -> Self {
+		self.checked_add_years(n).expect("year out of range")
+	}
+
+	/// Adds `n` years to this year, keeping the same time zone, or returns
+	/// `None` if the resulting year overflows an `i32`.
+	pub fn checked_add_years(&self, n: i32) -> Option<Self> {
+		self.year
+			.checked_add(n)
+			.map(|year| Self::new(year, self.offset))
+	}
+
+	/// Returns the number of years from `other` to `self`.
+	///
+	/// Time zones are ignored.
+	pub fn years_since(&self, other: &Self) -> i64 {
+		self.year as i64 - other.year as i64
+	}
+}
+
+impl Sub for GYear {
+	type Output = i64;
+
+	fn sub(self, rhs: Self) -> i64 {
+		self.years_since(&rhs)
+	}
+}
 
 impl XsdDatatype for GYear {
 	fn type_(&self) -> Datatype {
@@ -10,8 +120,135 @@ impl XsdDatatype for GYear {
 	}
 }
 
+impl FromStr for GYear {
+	type Err = InvalidDate;
+
+	/// Parses a `gYear` lexical form, such as `2002` or `-0045Z`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (year, s) = parse_year(s)?;
+		Ok(Self::new(year, parse_time_zone(s)?))
+	}
+}
+
 impl fmt::Display for GYear {
-	fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		unimplemented!()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt_year(self.year, f)?;
+
+		if let Some(offset) = &self.offset {
+			fmt_time_zone(offset, f)?
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn leap_01() {
+		let y = GYear::new(2000, None);
+		assert!(y.is_leap());
+		assert_eq!(y.length(), 366)
+	}
+
+	#[test]
+	fn leap_02() {
+		let y = GYear::new(1900, None);
+		assert!(!y.is_leap());
+		assert_eq!(y.length(), 365)
+	}
+
+	#[test]
+	fn leap_03() {
+		let y = GYear::new(0, None);
+		assert!(y.is_leap());
+		assert_eq!(y.length(), 366)
+	}
+
+	#[test]
+	fn first_last_day_01() {
+		let y = GYear::new(1900, None);
+		assert_eq!(y.first_day().to_string(), "--01-01");
+		assert_eq!(y.last_day().to_string(), "--12-31")
+	}
+
+	#[test]
+	fn format_01() {
+		assert_eq!(GYear::new(-42, None).to_string(), "-0042");
+		assert_eq!(
+			GYear::new(2000, FixedOffset::west_opt(5 * 3600)).to_string(),
+			"2000-05:00"
+		)
+	}
+
+	#[test]
+	fn parse_01() {
+		for input in [
+			"2002",
+			"0000",
+			"-0045",
+			"12345",
+			"2002Z",
+			"2002-05:00",
+			"2002+14:00",
+		] {
+			let y: GYear = input.parse().unwrap();
+			assert_eq!(y.to_string(), input)
+		}
+	}
+
+	#[test]
+	fn parse_02() {
+		for input in ["02", "02002", "2002-", "2002+15:00", "2002+14:30", "2002 "] {
+			assert!(input.parse::<GYear>().is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn arithmetic_01() {
+		let y = GYear::new(-1, None);
+		assert_eq!(y.next_year(), GYear::new(0, None));
+		assert_eq!(y.next_year().next_year(), GYear::new(1, None));
+		assert_eq!(GYear::new(0, None).prev_year(), y);
+		assert_eq!(y.add_years(2002), GYear::new(2001, None));
+		assert_eq!(GYear::new(1, None).years_since(&y), 2);
+		assert_eq!(GYear::new(-10, None) - GYear::new(10, None), -20);
+		assert_eq!(
+			GYear::new(i32::MAX, None) - GYear::new(-1, None),
+			i32::MAX as i64 + 1
+		);
+		assert_eq!(
+			GYear::new(i32::MIN, None).years_since(&GYear::new(i32::MAX, None)),
+			i32::MIN as i64 - i32::MAX as i64
+		)
+	}
+
+	#[test]
+	fn arithmetic_02() {
+		let y: GYear = "-0001".parse().unwrap();
+		assert_eq!(y.next_year().to_string(), "0000");
+		assert_eq!("0000".parse::<GYear>().unwrap(), y.next_year());
+		assert_eq!("-0000".parse::<GYear>().unwrap(), y.next_year());
+		assert_eq!(GYear::new(i32::MAX, None).checked_add_years(1), None);
+		assert_eq!(GYear::new(i32::MIN, None).checked_add_years(-1), None);
+		assert_eq!(
+			GYear::new(0, None).checked_add_years(i32::MAX),
+			Some(GYear::new(i32::MAX, None))
+		)
+	}
+
+	#[test]
+	fn to_date_range_01() {
+		let (first, last) = GYear::new(2024, None).to_date_range();
+		assert_eq!(first.to_string(), "2024-01-01Z");
+		assert_eq!(last.to_string(), "2024-12-31Z");
+		assert_eq!(last.days_since(&first), 365);
+
+		let year: GYear = "-0001-05:00".parse().unwrap();
+		let (first, last) = year.to_date_range();
+		assert_eq!(first.to_string(), "-0001-01-01-05:00");
+		assert_eq!(last.to_string(), "-0001-12-31-05:00")
 	}
 }
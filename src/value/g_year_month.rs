@@ -1,8 +1,55 @@
-use crate::{Datatype, XsdDatatype};
-use core::fmt;
+use chrono::FixedOffset;
 
-#[derive(Debug, Clone, Copy)]
-pub struct GYearMonth(());
+use crate::{Datatype, InvalidDate, XsdDatatype};
+use core::{fmt, str::FromStr};
+
+use super::date::{
+	days_in_month, fmt_time_zone, fmt_year, parse_time_zone, parse_two_digits, parse_year,
+};
+
+/// Gregorian calendar month of a year.
+///
+/// See: <https://www.w3.org/TR/xmlschema11-2/#gYearMonth>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GYearMonth {
+	year: i32,
+	month: u8,
+	offset: Option<FixedOffset>,
+}
+
+impl GYearMonth {
+	/// Creates a new year and month, or returns an error if the month is not
+	/// between 1 and 12.
+	pub fn new(year: i32, month: u8, offset: Option<FixedOffset>) -> Result<Self, InvalidDate> {
+		if (1..=12).contains(&month) {
+			Ok(Self {
+				year,
+				month,
+				offset,
+			})
+		} else {
+			Err(InvalidDate)
+		}
+	}
+
+	pub fn year(&self) -> i32 {
+		self.year
+	}
+
+	/// Returns the month, between 1 and 12.
+	pub fn month(&self) -> u8 {
+		self.month
+	}
+
+	pub fn offset(&self) -> Option<FixedOffset> {
+		self.offset
+	}
+
+	/// Returns the number of days in this month.
+	pub fn length(&self) -> u8 {
+		days_in_month(self.year, self.month).expect("valid month")
+	}
+}
 
 impl XsdDatatype for GYearMonth {
 	fn type_(&self) -> Datatype {
@@ -10,8 +57,64 @@ impl XsdDatatype for GYearMonth {
 	}
 }
 
+impl FromStr for GYearMonth {
+	type Err = InvalidDate;
+
+	/// Parses a `gYearMonth` lexical form, such as `2002-10` or
+	/// `2002-10-05:00`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (year, s) = parse_year(s)?;
+		let s = s.strip_prefix('-').ok_or(InvalidDate)?;
+		let (month, s) = parse_two_digits(s, 1..=12)?;
+		Self::new(year, month, parse_time_zone(s)?)
+	}
+}
+
 impl fmt::Display for GYearMonth {
-	fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		unimplemented!()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt_year(self.year, f)?;
+		write!(f, "-{:02}", self.month)?;
+
+		if let Some(offset) = &self.offset {
+			fmt_time_zone(offset, f)?
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn length_01() {
+		assert_eq!(GYearMonth::new(2000, 2, None).unwrap().length(), 29);
+		assert_eq!(GYearMonth::new(1900, 2, None).unwrap().length(), 28);
+		assert_eq!(GYearMonth::new(0, 2, None).unwrap().length(), 29);
+		assert_eq!(GYearMonth::new(1900, 4, None).unwrap().length(), 30);
+		assert_eq!(GYearMonth::new(1900, 12, None).unwrap().length(), 31);
+		assert!(GYearMonth::new(1900, 0, None).is_err());
+		assert!(GYearMonth::new(1900, 13, None).is_err())
+	}
+
+	#[test]
+	fn format_01() {
+		assert_eq!(
+			GYearMonth::new(2000, 2, None).unwrap().to_string(),
+			"2000-02"
+		)
+	}
+
+	#[test]
+	fn parse_01() {
+		for input in ["2002-10", "-0045-01Z", "2002-12+05:30"] {
+			let ym: GYearMonth = input.parse().unwrap();
+			assert_eq!(ym.to_string(), input)
+		}
+
+		for input in ["2002", "2002-13", "2002-00", "2002-1", "2002-10T"] {
+			assert!(input.parse::<GYearMonth>().is_err(), "{input}")
+		}
 	}
 }
@@ -18,6 +18,17 @@ const CHARS: [char; 16] = [
 #[error("invalid hexadecimal")]
 pub struct InvalidHex;
 
+/// Error raised when combining two binary values of different lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("length mismatch: {left} bytes and {right} bytes")]
+pub struct LengthMismatch {
+	/// Length of the left operand, in bytes.
+	pub left: usize,
+
+	/// Length of the right operand, in bytes.
+	pub right: usize,
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct HexBinaryBuf(Vec<u8>);
 
@@ -82,6 +93,24 @@ impl fmt::Display for HexBinaryBuf {
 	}
 }
 
+impl fmt::LowerHex for HexBinaryBuf {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerHex::fmt(self.as_hex_binary(), f)
+	}
+}
+
+impl fmt::UpperHex for HexBinaryBuf {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperHex::fmt(self.as_hex_binary(), f)
+	}
+}
+
+impl fmt::Binary for HexBinaryBuf {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Binary::fmt(self.as_hex_binary(), f)
+	}
+}
+
 impl From<Vec<u8>> for HexBinaryBuf {
 	fn from(value: Vec<u8>) -> Self {
 		HexBinaryBuf::from_bytes(value)
@@ -138,6 +167,14 @@ impl ParseRdf for HexBinaryBuf {
 	type LexicalForm = lexical::HexBinary;
 }
 
+/// Decodes the lexical form.
+impl From<lexical::HexBinaryBuf> for HexBinaryBuf {
+	#[inline(always)]
+	fn from(value: lexical::HexBinaryBuf) -> Self {
+		value.value()
+	}
+}
+
 impl LexicalFormOf<HexBinaryBuf> for lexical::HexBinary {
 	type ValueError = std::convert::Infallible;
 
@@ -158,7 +195,7 @@ impl HexBinary {
 		unsafe { std::mem::transmute(bytes) }
 	}
 
-	pub fn chars(&self) -> Chars {
+	pub fn chars(&self) -> Chars<'_> {
 		Chars {
 			pending: None,
 			bytes: self.0.iter(),
@@ -168,6 +205,72 @@ impl HexBinary {
 	pub fn as_bytes(&self) -> &[u8] {
 		&self.0
 	}
+
+	/// Returns the lowercase hexadecimal representation of the bytes.
+	///
+	/// This is a valid lexical form, but not the canonical one.
+	pub fn to_lowercase_hex(&self) -> String {
+		format!("{self:x}")
+	}
+
+	/// Returns the uppercase hexadecimal representation of the bytes, which
+	/// is the canonical lexical form.
+	pub fn to_uppercase_hex(&self) -> String {
+		self.to_string()
+	}
+
+	/// Combines the bytes of `self` and `other` pairwise using `f`.
+	fn zip_with(
+		&self,
+		other: &Self,
+		f: impl Fn(u8, u8) -> u8,
+	) -> Result<HexBinaryBuf, LengthMismatch> {
+		if self.0.len() == other.0.len() {
+			Ok(HexBinaryBuf::from_bytes(
+				self.0
+					.iter()
+					.zip(&other.0)
+					.map(|(a, b)| f(*a, *b))
+					.collect(),
+			))
+		} else {
+			Err(LengthMismatch {
+				left: self.0.len(),
+				right: other.0.len(),
+			})
+		}
+	}
+
+	/// Computes the byte-wise exclusive or of `self` and `other`, which must
+	/// have the same length.
+	pub fn xor(&self, other: &Self) -> Result<HexBinaryBuf, LengthMismatch> {
+		self.zip_with(other, |a, b| a ^ b)
+	}
+
+	/// Computes the byte-wise and of `self` and `other`, which must have the
+	/// same length.
+	pub fn and(&self, other: &Self) -> Result<HexBinaryBuf, LengthMismatch> {
+		self.zip_with(other, |a, b| a & b)
+	}
+
+	/// Computes the byte-wise or of `self` and `other`, which must have the
+	/// same length.
+	pub fn or(&self, other: &Self) -> Result<HexBinaryBuf, LengthMismatch> {
+		self.zip_with(other, |a, b| a | b)
+	}
+
+	/// Computes the bitwise complement of every byte.
+	#[allow(clippy::should_implement_trait)]
+	pub fn not(&self) -> HexBinaryBuf {
+		HexBinaryBuf::from_bytes(self.0.iter().map(|b| !b).collect())
+	}
+
+	/// Returns the number of differing bits between `self` and `other`,
+	/// which must have the same length.
+	pub fn hamming_distance(&self, other: &Self) -> Result<u32, LengthMismatch> {
+		self.xor(other)
+			.map(|x| x.as_bytes().iter().map(|b| b.count_ones()).sum())
+	}
 }
 
 impl<'a> From<&'a [u8]> for &'a HexBinary {
@@ -198,6 +301,32 @@ impl fmt::Display for HexBinary {
 	}
 }
 
+impl fmt::LowerHex for HexBinary {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for b in self.as_bytes() {
+			write!(f, "{b:02x}")?
+		}
+
+		Ok(())
+	}
+}
+
+impl fmt::UpperHex for HexBinary {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+
+impl fmt::Binary for HexBinary {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for b in self.as_bytes() {
+			write!(f, "{b:08b}")?
+		}
+
+		Ok(())
+	}
+}
+
 impl ToOwned for HexBinary {
 	type Owned = HexBinaryBuf;
 
@@ -237,7 +366,7 @@ impl<'a> Iterator for Chars<'a> {
 mod tests {
 	use super::*;
 
-	const TESTS: [(&'static [u8], &'static str); 9] = [
+	const TESTS: [(&[u8], &str); 9] = [
 		(b"M", "4D"),
 		(b"Ma", "4D61"),
 		(b"Man", "4D616E"),
@@ -267,4 +396,49 @@ mod tests {
 			assert_eq!(output.as_bytes(), expected)
 		}
 	}
+
+	#[test]
+	fn mixed_case_01() {
+		assert_eq!("0a0B".parse::<HexBinaryBuf>().unwrap().to_string(), "0A0B");
+		assert_eq!(
+			HexBinaryBuf::parse_rdf("0a0B").ok().unwrap().as_bytes(),
+			&[0x0a, 0x0b]
+		)
+	}
+
+	#[test]
+	fn format_01() {
+		let h = HexBinaryBuf::from_bytes(vec![0x0a, 0x0b]);
+		assert_eq!(format!("{h:x}"), "0a0b");
+		assert_eq!(format!("{h:X}"), "0A0B");
+		assert_eq!(format!("{h:b}"), "0000101000001011");
+		assert_eq!(h.to_lowercase_hex(), "0a0b");
+		assert_eq!(h.to_uppercase_hex(), h.to_string());
+		assert_eq!(format!("{:x}", HexBinary::new(&[])), "")
+	}
+
+	#[test]
+	fn bitwise_01() {
+		let a: HexBinaryBuf = "0F0F".parse().unwrap();
+		let b: HexBinaryBuf = "FF00".parse().unwrap();
+		assert_eq!(a.xor(&b).unwrap().to_string(), "F00F");
+		assert_eq!(a.and(&b).unwrap().to_string(), "0F00");
+		assert_eq!(a.or(&b).unwrap().to_string(), "FF0F");
+		assert_eq!(a.not().to_string(), "F0F0");
+		assert_eq!(a.hamming_distance(&b), Ok(8));
+		assert_eq!(a.hamming_distance(&a), Ok(0));
+
+		let key = HexBinary::new(b"key");
+		let message = HexBinary::new(b"msg");
+		let cipher = message.xor(key).unwrap();
+		assert_eq!(cipher.as_bytes(), [0x06, 0x16, 0x1e]);
+		assert_eq!(cipher.xor(key).unwrap().as_bytes(), b"msg");
+
+		let c: HexBinaryBuf = "00".parse().unwrap();
+		assert_eq!(a.xor(&c), Err(LengthMismatch { left: 2, right: 1 }));
+		assert_eq!(
+			c.hamming_distance(&a),
+			Err(LengthMismatch { left: 1, right: 2 })
+		)
+	}
 }
@@ -0,0 +1,333 @@
+use std::{borrow::Borrow, fmt, str::FromStr};
+
+use crate::{Datatype, ListDatatype, Value, WrongType, XsdDatatype};
+
+/// Error raised when building a list value from items not conforming to the
+/// list item datatype.
+#[derive(Debug, thiserror::Error)]
+#[error("list item {index} of type `{}` does not conform to `{}`", .actual.iri(), .item_type.iri())]
+pub struct ListTypeError {
+	/// Position of the offending item.
+	pub index: usize,
+
+	/// Expected item datatype.
+	pub item_type: Datatype,
+
+	/// Datatype of the offending item.
+	pub actual: Datatype,
+}
+
+/// Error raised when building a list that must not be empty from no items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("empty list")]
+pub struct EmptyListError;
+
+/// List value.
+///
+/// Sequence of atomic values of a built-in list datatype. The lexical form of
+/// a list is the space-separated lexical forms of its items.
+///
+/// See: <https://www.w3.org/TR/xmlschema-2/#list-datatypes>
+#[derive(Debug, Clone)]
+pub struct ListValue {
+	datatype: ListDatatype,
+	items: Vec<Value>,
+}
+
+impl ListValue {
+	/// Creates a new list value, checking that every item conforms to
+	/// the item datatype of `datatype`.
+	///
+	/// Items may report a less specific datatype than the item datatype
+	/// (e.g. `xsd:NMTOKEN` items are string values), in which case their
+	/// lexical form must be valid for the item datatype.
+	///
+	/// Lists cannot be nested: list items are always rejected.
+	pub fn new(datatype: ListDatatype, items: Vec<Value>) -> Result<Self, ListTypeError> {
+		let item_type = *datatype.item_type();
+		for (index, item) in items.iter().enumerate() {
+			let conforms = !item.is_list()
+				&& item_type
+					.parse(&item.to_string())
+					.map_or(false, |value| value == *item);
+
+			if !conforms {
+				return Err(ListTypeError {
+					index,
+					item_type,
+					actual: item.type_(),
+				});
+			}
+		}
+
+		Ok(Self { datatype, items })
+	}
+
+	/// Creates a new list value without checking the items datatype.
+	///
+	/// Items must have been parsed using the item datatype of `datatype`.
+	pub(crate) fn new_unchecked(datatype: ListDatatype, items: Vec<Value>) -> Self {
+		Self { datatype, items }
+	}
+
+	/// Returns the list datatype of this value.
+	pub fn list_datatype(&self) -> ListDatatype {
+		self.datatype
+	}
+
+	/// Returns the datatype of the list items.
+	pub fn item_type(&self) -> Datatype {
+		*self.datatype.item_type()
+	}
+
+	pub fn as_slice(&self) -> &[Value] {
+		&self.items
+	}
+
+	pub fn into_vec(self) -> Vec<Value> {
+		self.items
+	}
+
+	pub fn len(&self) -> usize {
+		self.items.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.items.is_empty()
+	}
+
+	pub fn iter(&self) -> std::slice::Iter<'_, Value> {
+		self.items.iter()
+	}
+}
+
+impl XsdDatatype for ListValue {
+	fn type_(&self) -> Datatype {
+		Datatype::List(self.datatype)
+	}
+}
+
+impl fmt::Display for ListValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (i, item) in self.items.iter().enumerate() {
+			if i > 0 {
+				f.write_str(" ")?;
+			}
+
+			item.fmt(f)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<'a> IntoIterator for &'a ListValue {
+	type Item = &'a Value;
+	type IntoIter = std::slice::Iter<'a, Value>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.items.iter()
+	}
+}
+
+impl IntoIterator for ListValue {
+	type Item = Value;
+	type IntoIter = std::vec::IntoIter<Value>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.items.into_iter()
+	}
+}
+
+/// Item type of a built-in list datatype.
+///
+/// See [`NonEmptyList`].
+pub trait ListItem: Sized + fmt::Display + FromStr {
+	/// Built-in list datatype whose items are of this type.
+	const LIST_DATATYPE: ListDatatype;
+}
+
+/// Value of a built-in list datatype (`xsd:NMTOKENS`, `xsd:IDREFS` or
+/// `xsd:ENTITIES`).
+///
+/// Built-in list datatypes have a `minLength` of 1, so such values are never
+/// empty. They can be converted from and into a [`Value::List`] as returned
+/// by [`ListDatatype::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonEmptyList<T>(Vec<T>);
+
+impl<T: ListItem> NonEmptyList<T> {
+	/// Creates a new list.
+	///
+	/// Returns an [`EmptyListError`] if `items` is empty.
+	pub fn new(items: Vec<T>) -> Result<Self, EmptyListError> {
+		if items.is_empty() {
+			Err(EmptyListError)
+		} else {
+			Ok(Self(items))
+		}
+	}
+
+	/// Collects a new list.
+	///
+	/// Returns an [`EmptyListError`] if `items` yields no item.
+	#[allow(clippy::should_implement_trait)]
+	pub fn from_iter(items: impl IntoIterator<Item = T>) -> Result<Self, EmptyListError> {
+		Self::new(items.into_iter().collect())
+	}
+
+	pub fn as_slice(&self) -> &[T] {
+		&self.0
+	}
+
+	pub fn into_vec(self) -> Vec<T> {
+		self.0
+	}
+
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Always returns `false`, since the list cannot be empty.
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	pub fn iter(&self) -> std::slice::Iter<'_, T> {
+		self.0.iter()
+	}
+
+	/// Checks if the given item is in the list.
+	pub fn contains<Q>(&self, item: &Q) -> bool
+	where
+		T: Borrow<Q>,
+		Q: ?Sized + PartialEq,
+	{
+		self.0.iter().any(|t| t.borrow() == item)
+	}
+}
+
+impl<T: ListItem> fmt::Display for NonEmptyList<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (i, item) in self.0.iter().enumerate() {
+			if i > 0 {
+				f.write_str(" ")?;
+			}
+
+			item.fmt(f)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<T: ListItem> XsdDatatype for NonEmptyList<T> {
+	fn type_(&self) -> Datatype {
+		Datatype::List(T::LIST_DATATYPE)
+	}
+}
+
+impl<T: ListItem> From<NonEmptyList<T>> for Value {
+	fn from(value: NonEmptyList<T>) -> Self {
+		Value::List(ListValue::new_unchecked(
+			T::LIST_DATATYPE,
+			value
+				.0
+				.into_iter()
+				.map(|item| Value::String(item.to_string()))
+				.collect(),
+		))
+	}
+}
+
+/// Extracts a list value parsed with [`ListDatatype::parse`].
+impl<T: ListItem> TryFrom<Value> for NonEmptyList<T> {
+	type Error = WrongType;
+
+	fn try_from(value: Value) -> Result<Self, Self::Error> {
+		match &value {
+			Value::List(list) if list.list_datatype() == T::LIST_DATATYPE => list
+				.iter()
+				.map(|item| match item {
+					Value::String(s) => s.parse().ok(),
+					_ => None,
+				})
+				.collect::<Option<Vec<_>>>()
+				.and_then(|items| Self::new(items).ok())
+				.ok_or_else(|| WrongType::of(&value)),
+			_ => Err(WrongType::of(&value)),
+		}
+	}
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyList<T> {
+	type Item = &'a T;
+	type IntoIter = std::slice::Iter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+impl<T> IntoIterator for NonEmptyList<T> {
+	type Item = T;
+	type IntoIter = std::vec::IntoIter<T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Entities, IdRefs, NMTokenBuf, NMTokens};
+
+	#[test]
+	fn new_01() {
+		let a: NMTokenBuf = "foo-bar.123".parse().unwrap();
+		let b: NMTokenBuf = "baz".parse().unwrap();
+		let list = NMTokens::new(vec![a.clone(), b.clone()]).unwrap();
+		assert_eq!(list.len(), 2);
+		assert!(!list.is_empty());
+		assert!(list.contains(b.as_nmtoken()));
+		assert_eq!(list.to_string(), format!("{a} {b}"));
+		assert_eq!(list.type_().iri(), crate::XSD_NMTOKENS);
+		assert_eq!(NMTokens::new(Vec::new()), Err(EmptyListError));
+		assert_eq!(IdRefs::from_iter(None), Err(EmptyListError));
+		assert_eq!(
+			Entities::from_iter(["foo".parse().unwrap()])
+				.unwrap()
+				.type_()
+				.iri(),
+			crate::XSD_ENTITIES
+		)
+	}
+
+	#[test]
+	fn iter_01() {
+		let items: Vec<NMTokenBuf> = ["foo-bar.123", "baz"]
+			.into_iter()
+			.map(|s| s.parse().unwrap())
+			.collect();
+		let list = NMTokens::from_iter(items.clone()).unwrap();
+		assert!(list.iter().eq(&items));
+		assert!((&list).into_iter().eq(&items));
+		assert_eq!(list.into_iter().collect::<Vec<_>>(), items)
+	}
+
+	#[test]
+	fn value_01() {
+		let value = ListDatatype::IdRefs.parse("foo  bar").unwrap();
+		let list = IdRefs::try_from(value).unwrap();
+		assert_eq!(list.to_string(), "foo bar");
+		assert!(list.contains(&"bar".parse().unwrap()));
+
+		let value = Value::from(list);
+		assert_eq!(value.to_string(), "foo bar");
+		assert_eq!(value.as_list().map(<[Value]>::len), Some(2));
+		assert!(IdRefs::try_from(value.clone()).is_ok());
+		assert!(NMTokens::try_from(value).is_err());
+		assert!(IdRefs::try_from(Value::String("foo".to_owned())).is_err())
+	}
+}
@@ -1,8 +1,36 @@
-use crate::{Datatype, XsdDatatype};
-use core::fmt;
+use std::{fmt, str::FromStr};
 
-#[derive(Debug, Clone)]
-pub struct Notation(());
+use crate::{Datatype, InvalidQName, ParseError, QName, XsdDatatype};
+
+/// Notation name.
+///
+/// Notation values are qualified names referring to a notation declared in
+/// the schema, which is not checked at this level.
+///
+/// See: <https://www.w3.org/TR/xmlschema-2/#NOTATION>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notation(QName);
+
+impl Notation {
+	pub fn new(name: QName) -> Self {
+		Self(name)
+	}
+
+	pub fn qname(&self) -> &QName {
+		&self.0
+	}
+
+	pub fn into_qname(self) -> QName {
+		self.0
+	}
+
+	/// Parses a notation name without any namespace context.
+	///
+	/// The resulting qualified name is unresolved, see [`QName::resolve`].
+	pub fn parse_unresolved(s: &str) -> Result<Self, ParseError> {
+		s.parse().map_err(|_| ParseError)
+	}
+}
 
 impl XsdDatatype for Notation {
 	fn type_(&self) -> Datatype {
@@ -10,8 +38,39 @@ impl XsdDatatype for Notation {
 	}
 }
 
+/// Parses an unresolved notation name.
+///
+/// A namespace context is required to fully resolve the name, see
+/// [`QName::resolve`].
+impl FromStr for Notation {
+	type Err = InvalidQName;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse().map(Self)
+	}
+}
+
+impl From<QName> for Notation {
+	fn from(value: QName) -> Self {
+		Self(value)
+	}
+}
+
 impl fmt::Display for Notation {
-	fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		unimplemented!()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		let n = Notation::parse_unresolved("img:png").unwrap();
+		assert_eq!(n.qname().local_part().as_str(), "png");
+		assert_eq!(n.to_string(), "img:png");
+		assert!(Notation::parse_unresolved("img png").is_err())
 	}
 }
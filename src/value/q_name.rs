@@ -1,8 +1,107 @@
-use crate::{Datatype, XsdDatatype};
-use core::fmt;
+use std::{
+	fmt,
+	hash::{Hash, Hasher},
+	str::FromStr,
+};
 
+use iref::{Iri, IriBuf};
+
+use crate::{Datatype, NCName, NCNameBuf, NamespaceMap, XsdDatatype};
+
+/// Error raised when parsing an invalid qualified name.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid QName")]
+pub struct InvalidQName;
+
+/// Qualified name.
+///
+/// A qualified name is a local part, optionally prefixed, whose prefix is
+/// resolved into a namespace IRI using a namespace context. A name parsed
+/// without any namespace context is *unresolved*: it stores the prefix as
+/// written, but no namespace.
+///
+/// Resolved names are equal when they have the same namespace and local
+/// part, whatever their prefix, following the XSD value space. Unresolved
+/// names have no namespace, and are compared by prefix and local part.
+///
+/// See: <https://www.w3.org/TR/xmlschema-2/#QName>
 #[derive(Debug, Clone)]
-pub struct QName(());
+pub struct QName {
+	prefix: Option<NCNameBuf>,
+	local_part: NCNameBuf,
+	namespace: Option<IriBuf>,
+}
+
+impl QName {
+	/// Creates a new unresolved qualified name.
+	pub fn new(prefix: Option<NCNameBuf>, local_part: NCNameBuf) -> Self {
+		Self {
+			prefix,
+			local_part,
+			namespace: None,
+		}
+	}
+
+	/// Sets the namespace of this name.
+	pub fn with_namespace(self, namespace: IriBuf) -> Self {
+		Self {
+			namespace: Some(namespace),
+			..self
+		}
+	}
+
+	pub fn prefix(&self) -> Option<&NCName> {
+		self.prefix.as_deref()
+	}
+
+	pub fn local_part(&self) -> &NCName {
+		&self.local_part
+	}
+
+	/// Returns the namespace of this name, if it is resolved.
+	pub fn namespace(&self) -> Option<&Iri> {
+		self.namespace.as_deref()
+	}
+
+	pub fn is_resolved(&self) -> bool {
+		self.namespace.is_some()
+	}
+
+	/// Resolves the prefix of this name using the given namespace context.
+	///
+	/// Unprefixed names are resolved using the default namespace, bound to
+	/// the empty prefix, if any. Returns `None` if the prefix is not bound.
+	pub fn resolve(&self, ns: &NamespaceMap) -> Option<Self> {
+		let prefix = self.prefix.as_ref().map(|p| p.as_str()).unwrap_or("");
+		match ns.get(prefix) {
+			Some(namespace) => Some(self.clone().with_namespace(namespace.to_owned())),
+			None if self.prefix.is_none() => Some(self.clone()),
+			None => None,
+		}
+	}
+}
+
+impl PartialEq for QName {
+	fn eq(&self, other: &Self) -> bool {
+		self.namespace == other.namespace
+			&& self.local_part == other.local_part
+			&& (self.namespace.is_some() || self.prefix == other.prefix)
+	}
+}
+
+impl Eq for QName {}
+
+/// Hashes the namespace and local part of the name, and its prefix if it is
+/// unresolved, consistently with the `PartialEq` implementation.
+impl Hash for QName {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.namespace.hash(state);
+		self.local_part().as_str().hash(state);
+		if self.namespace.is_none() {
+			self.prefix().map(NCName::as_str).hash(state)
+		}
+	}
+}
 
 impl XsdDatatype for QName {
 	fn type_(&self) -> Datatype {
@@ -10,8 +109,111 @@ impl XsdDatatype for QName {
 	}
 }
 
+/// Parses an unresolved qualified name.
+///
+/// Use [`QName::resolve`] to resolve its namespace.
+impl FromStr for QName {
+	type Err = InvalidQName;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (prefix, local_part) = match s.split_once(':') {
+			Some((prefix, local_part)) => {
+				(Some(prefix.parse().map_err(|_| InvalidQName)?), local_part)
+			}
+			None => (None, s),
+		};
+
+		Ok(Self::new(
+			prefix,
+			local_part.parse().map_err(|_| InvalidQName)?,
+		))
+	}
+}
+
 impl fmt::Display for QName {
-	fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		unimplemented!()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if let Some(prefix) = &self.prefix {
+			write!(f, "{prefix}:")?
+		}
+
+		self.local_part.fmt(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_01() {
+		let name: QName = "xsd:string".parse().unwrap();
+		assert_eq!(name.prefix().unwrap().as_str(), "xsd");
+		assert_eq!(name.local_part().as_str(), "string");
+		assert!(!name.is_resolved());
+		assert_eq!(name.to_string(), "xsd:string");
+
+		let name: QName = "foo".parse().unwrap();
+		assert!(name.prefix().is_none());
+		assert_eq!(name.to_string(), "foo")
+	}
+
+	#[test]
+	fn parse_02() {
+		for input in ["", ":foo", "foo:", "a:b:c", "1foo", "foo bar"] {
+			assert!(input.parse::<QName>().is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn resolve_01() {
+		let mut ns = NamespaceMap::new();
+		ns.insert(
+			"xsd",
+			IriBuf::new("http://www.w3.org/2001/XMLSchema#".to_owned()).unwrap(),
+		);
+
+		let name = "xsd:string".parse::<QName>().unwrap().resolve(&ns).unwrap();
+		assert_eq!(
+			name.namespace().unwrap().as_str(),
+			"http://www.w3.org/2001/XMLSchema#"
+		);
+		assert!("foo:string"
+			.parse::<QName>()
+			.unwrap()
+			.resolve(&ns)
+			.is_none());
+		assert!(!"string"
+			.parse::<QName>()
+			.unwrap()
+			.resolve(&ns)
+			.unwrap()
+			.is_resolved())
+	}
+
+	#[test]
+	fn eq_01() {
+		let mut ns = NamespaceMap::new();
+		let xsd = IriBuf::new("http://www.w3.org/2001/XMLSchema#".to_owned()).unwrap();
+		ns.insert("xsd", xsd.clone());
+		ns.insert("xs", xsd);
+		ns.insert("", IriBuf::new("http://example.org/".to_owned()).unwrap());
+
+		let resolve = |s: &str| s.parse::<QName>().unwrap().resolve(&ns).unwrap();
+		assert_eq!(resolve("xsd:string"), resolve("xs:string"));
+		assert_ne!(resolve("xsd:string"), resolve("xsd:integer"));
+		assert_ne!(resolve("xsd:string"), resolve("string"));
+
+		let hash = |name: &QName| {
+			use std::collections::hash_map::DefaultHasher;
+			let mut hasher = DefaultHasher::new();
+			name.hash(&mut hasher);
+			hasher.finish()
+		};
+		assert_eq!(hash(&resolve("xsd:string")), hash(&resolve("xs:string")));
+
+		let unresolved = |s: &str| s.parse::<QName>().unwrap();
+		assert_eq!(unresolved("xsd:string"), unresolved("xsd:string"));
+		assert_ne!(unresolved("xsd:string"), unresolved("xs:string"));
+		assert_ne!(unresolved("xsd:string"), resolve("xsd:string"))
 	}
 }
@@ -1,3 +1,23 @@
+mod entity;
+mod id;
+mod id_ref;
+mod language;
+mod name;
+mod nc_name;
+mod nmtoken;
+mod normalized_string;
+mod token;
+
+pub use entity::*;
+pub use id::*;
+pub use id_ref::*;
+pub use language::*;
+pub use name::*;
+pub use nc_name::*;
+pub use nmtoken::*;
+pub use normalized_string::*;
+pub use token::*;
+
 use crate::{lexical::LexicalFormOf, Datatype, ParseRdf, XsdDatatype};
 
 pub type String = std::string::String;
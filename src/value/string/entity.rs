@@ -0,0 +1,99 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+	lexical::{self, InvalidNCName, LexicalFormOf},
+	Datatype, ListDatatype, ListItem, NCName, NCNameBuf, NCNameDatatype, NameDatatype,
+	NonEmptyList, NormalizedStringDatatype, ParseRdf, StringDatatype, TokenDatatype, XsdDatatype,
+};
+
+/// Unparsed entity name.
+///
+/// The value space is the same as `xsd:NCName`.
+///
+/// Whether the name matches an unparsed entity declared in the document is
+/// not checked at this level.
+///
+/// See: <https://www.w3.org/TR/xmlschema-2/#ENTITY>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entity(NCNameBuf);
+
+impl Entity {
+	pub fn new(name: NCNameBuf) -> Self {
+		Self(name)
+	}
+
+	pub fn as_nc_name(&self) -> &NCName {
+		&self.0
+	}
+
+	pub fn as_str(&self) -> &str {
+		self.0.as_str()
+	}
+
+	pub fn into_nc_name(self) -> NCNameBuf {
+		self.0
+	}
+}
+
+impl AsRef<NCName> for Entity {
+	fn as_ref(&self) -> &NCName {
+		&self.0
+	}
+}
+
+impl AsRef<str> for Entity {
+	fn as_ref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl From<NCNameBuf> for Entity {
+	fn from(value: NCNameBuf) -> Self {
+		Self(value)
+	}
+}
+
+impl FromStr for Entity {
+	type Err = InvalidNCName;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse().map(Self)
+	}
+}
+
+impl fmt::Display for Entity {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl XsdDatatype for Entity {
+	fn type_(&self) -> Datatype {
+		Datatype::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+				Some(NCNameDatatype::Entity),
+			))))),
+		))))
+	}
+}
+
+impl LexicalFormOf<Entity> for lexical::NCName {
+	type ValueError = std::convert::Infallible;
+
+	fn try_as_value(&self) -> Result<Entity, Self::ValueError> {
+		Ok(Entity(self.value()))
+	}
+}
+
+impl ParseRdf for Entity {
+	type LexicalForm = lexical::NCName;
+}
+
+impl ListItem for Entity {
+	const LIST_DATATYPE: ListDatatype = ListDatatype::Entities;
+}
+
+/// List of unparsed entity names.
+///
+/// See: <https://www.w3.org/TR/xmlschema-2/#ENTITIES>
+pub type Entities = NonEmptyList<Entity>;
@@ -0,0 +1,116 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+	lexical::{self, InvalidNCName, LexicalFormOf},
+	Datatype, NCName, NCNameBuf, NCNameDatatype, NameDatatype, NormalizedStringDatatype, ParseRdf,
+	StringDatatype, TokenDatatype, XsdDatatype,
+};
+
+/// Identifier.
+///
+/// The value space is the same as `xsd:NCName`.
+///
+/// The uniqueness of identifiers inside a document is not checked at this
+/// level, it is up to the caller to enforce it.
+///
+/// See: <https://www.w3.org/TR/xmlschema-2/#ID>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Id(NCNameBuf);
+
+impl Id {
+	pub fn new(name: NCNameBuf) -> Self {
+		Self(name)
+	}
+
+	pub fn as_nc_name(&self) -> &NCName {
+		&self.0
+	}
+
+	pub fn as_str(&self) -> &str {
+		self.0.as_str()
+	}
+
+	pub fn into_nc_name(self) -> NCNameBuf {
+		self.0
+	}
+}
+
+impl AsRef<NCName> for Id {
+	fn as_ref(&self) -> &NCName {
+		&self.0
+	}
+}
+
+impl AsRef<str> for Id {
+	fn as_ref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl From<NCNameBuf> for Id {
+	fn from(value: NCNameBuf) -> Self {
+		Self(value)
+	}
+}
+
+impl FromStr for Id {
+	type Err = InvalidNCName;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse().map(Self)
+	}
+}
+
+impl fmt::Display for Id {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl XsdDatatype for Id {
+	fn type_(&self) -> Datatype {
+		Datatype::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+				Some(NCNameDatatype::Id),
+			))))),
+		))))
+	}
+}
+
+impl LexicalFormOf<Id> for lexical::NCName {
+	type ValueError = std::convert::Infallible;
+
+	fn try_as_value(&self) -> Result<Id, Self::ValueError> {
+		Ok(Id(self.value()))
+	}
+}
+
+impl ParseRdf for Id {
+	type LexicalForm = lexical::NCName;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Entity, IdRef};
+
+	#[test]
+	fn parse_01() {
+		let id: Id = "foo".parse().unwrap();
+		assert_eq!(id.to_string(), "foo");
+		assert_eq!(id.as_nc_name().as_str(), "foo");
+		assert!("foo:bar".parse::<Id>().is_err());
+		assert!("1foo".parse::<IdRef>().is_err())
+	}
+
+	#[test]
+	fn parse_rdf_01() {
+		let id = Id::parse_rdf("foo").ok().unwrap();
+		assert_eq!(id.type_().iri(), crate::XSD_ID);
+		let id_ref = IdRef::parse_rdf("foo").ok().unwrap();
+		assert_eq!(id_ref.type_().iri(), crate::XSD_IDREF);
+		let entity = Entity::parse_rdf("foo").ok().unwrap();
+		assert_eq!(entity.type_().iri(), crate::XSD_ENTITY);
+		assert!(Entity::parse_rdf("").is_err())
+	}
+}
@@ -0,0 +1,96 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+	lexical::{self, InvalidNCName, LexicalFormOf},
+	Datatype, ListDatatype, ListItem, NCName, NCNameBuf, NCNameDatatype, NameDatatype,
+	NonEmptyList, NormalizedStringDatatype, ParseRdf, StringDatatype, TokenDatatype, XsdDatatype,
+};
+
+/// Identifier reference.
+///
+/// The value space is the same as `xsd:NCName`.
+///
+/// See: <https://www.w3.org/TR/xmlschema-2/#IDREF>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdRef(NCNameBuf);
+
+impl IdRef {
+	pub fn new(name: NCNameBuf) -> Self {
+		Self(name)
+	}
+
+	pub fn as_nc_name(&self) -> &NCName {
+		&self.0
+	}
+
+	pub fn as_str(&self) -> &str {
+		self.0.as_str()
+	}
+
+	pub fn into_nc_name(self) -> NCNameBuf {
+		self.0
+	}
+}
+
+impl AsRef<NCName> for IdRef {
+	fn as_ref(&self) -> &NCName {
+		&self.0
+	}
+}
+
+impl AsRef<str> for IdRef {
+	fn as_ref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl From<NCNameBuf> for IdRef {
+	fn from(value: NCNameBuf) -> Self {
+		Self(value)
+	}
+}
+
+impl FromStr for IdRef {
+	type Err = InvalidNCName;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse().map(Self)
+	}
+}
+
+impl fmt::Display for IdRef {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl XsdDatatype for IdRef {
+	fn type_(&self) -> Datatype {
+		Datatype::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+				Some(NCNameDatatype::IdRef),
+			))))),
+		))))
+	}
+}
+
+impl LexicalFormOf<IdRef> for lexical::NCName {
+	type ValueError = std::convert::Infallible;
+
+	fn try_as_value(&self) -> Result<IdRef, Self::ValueError> {
+		Ok(IdRef(self.value()))
+	}
+}
+
+impl ParseRdf for IdRef {
+	type LexicalForm = lexical::NCName;
+}
+
+impl ListItem for IdRef {
+	const LIST_DATATYPE: ListDatatype = ListDatatype::IdRefs;
+}
+
+/// List of identifier references.
+///
+/// See: <https://www.w3.org/TR/xmlschema-2/#IDREFS>
+pub type IdRefs = NonEmptyList<IdRef>;
@@ -0,0 +1,34 @@
+use crate::{
+	lexical::{self, LexicalFormOf},
+	Datatype, NormalizedStringDatatype, ParseRdf, StringDatatype, TokenDatatype, XsdDatatype,
+};
+
+pub type Language = lexical::Language;
+
+pub type LanguageBuf = lexical::LanguageBuf;
+
+impl XsdDatatype for Language {
+	fn type_(&self) -> Datatype {
+		Datatype::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Language)),
+		))))
+	}
+}
+
+impl XsdDatatype for LanguageBuf {
+	fn type_(&self) -> Datatype {
+		self.as_language().type_()
+	}
+}
+
+impl LexicalFormOf<LanguageBuf> for lexical::Language {
+	type ValueError = std::convert::Infallible;
+
+	fn try_as_value(&self) -> Result<LanguageBuf, Self::ValueError> {
+		Ok(self.value())
+	}
+}
+
+impl ParseRdf for LanguageBuf {
+	type LexicalForm = lexical::Language;
+}
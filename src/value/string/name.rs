@@ -0,0 +1,34 @@
+use crate::{
+	lexical::{self, LexicalFormOf},
+	Datatype, NormalizedStringDatatype, ParseRdf, StringDatatype, TokenDatatype, XsdDatatype,
+};
+
+pub type Name = lexical::Name;
+
+pub type NameBuf = lexical::NameBuf;
+
+impl XsdDatatype for Name {
+	fn type_(&self) -> Datatype {
+		Datatype::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Name(None))),
+		))))
+	}
+}
+
+impl XsdDatatype for NameBuf {
+	fn type_(&self) -> Datatype {
+		self.as_name().type_()
+	}
+}
+
+impl LexicalFormOf<NameBuf> for lexical::Name {
+	type ValueError = std::convert::Infallible;
+
+	fn try_as_value(&self) -> Result<NameBuf, Self::ValueError> {
+		Ok(self.value())
+	}
+}
+
+impl ParseRdf for NameBuf {
+	type LexicalForm = lexical::Name;
+}
@@ -0,0 +1,37 @@
+use crate::{
+	lexical::{self, LexicalFormOf},
+	Datatype, NameDatatype, NormalizedStringDatatype, ParseRdf, StringDatatype, TokenDatatype,
+	XsdDatatype,
+};
+
+pub type NCName = lexical::NCName;
+
+pub type NCNameBuf = lexical::NCNameBuf;
+
+impl XsdDatatype for NCName {
+	fn type_(&self) -> Datatype {
+		Datatype::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::Name(Some(NameDatatype::NCName(
+				None,
+			))))),
+		))))
+	}
+}
+
+impl XsdDatatype for NCNameBuf {
+	fn type_(&self) -> Datatype {
+		self.as_nc_name().type_()
+	}
+}
+
+impl LexicalFormOf<NCNameBuf> for lexical::NCName {
+	type ValueError = std::convert::Infallible;
+
+	fn try_as_value(&self) -> Result<NCNameBuf, Self::ValueError> {
+		Ok(self.value())
+	}
+}
+
+impl ParseRdf for NCNameBuf {
+	type LexicalForm = lexical::NCName;
+}
@@ -0,0 +1,57 @@
+use crate::{
+	lexical::{self, LexicalFormOf},
+	Datatype, ListDatatype, ListItem, NonEmptyList, NormalizedStringDatatype, ParseRdf,
+	StringDatatype, TokenDatatype, XsdDatatype,
+};
+
+pub type NMToken = lexical::NMToken;
+
+pub type NMTokenBuf = lexical::NMTokenBuf;
+
+impl XsdDatatype for NMToken {
+	fn type_(&self) -> Datatype {
+		Datatype::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(Some(TokenDatatype::NMToken)),
+		))))
+	}
+}
+
+impl XsdDatatype for NMTokenBuf {
+	fn type_(&self) -> Datatype {
+		self.as_nmtoken().type_()
+	}
+}
+
+impl LexicalFormOf<NMTokenBuf> for lexical::NMToken {
+	type ValueError = std::convert::Infallible;
+
+	fn try_as_value(&self) -> Result<NMTokenBuf, Self::ValueError> {
+		Ok(self.value())
+	}
+}
+
+impl ParseRdf for NMTokenBuf {
+	type LexicalForm = lexical::NMToken;
+}
+
+impl ListItem for NMTokenBuf {
+	const LIST_DATATYPE: ListDatatype = ListDatatype::NMTokens;
+}
+
+/// List of XML name tokens.
+///
+/// See: <https://www.w3.org/TR/xmlschema-2/#NMTOKENS>
+pub type NMTokens = NonEmptyList<NMTokenBuf>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_rdf_01() {
+		let token = NMTokenBuf::parse_rdf("foo-bar.123").ok().unwrap();
+		assert_eq!(token.as_str(), "foo-bar.123");
+		assert_eq!(token.type_().iri(), crate::XSD_NMTOKEN);
+		assert!(NMTokenBuf::parse_rdf("foo bar").is_err())
+	}
+}
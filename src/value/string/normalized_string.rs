@@ -0,0 +1,32 @@
+use crate::{
+	lexical::{self, LexicalFormOf},
+	Datatype, ParseRdf, StringDatatype, XsdDatatype,
+};
+
+pub type NormalizedString = lexical::NormalizedString;
+
+pub type NormalizedStringBuf = lexical::NormalizedStringBuf;
+
+impl XsdDatatype for NormalizedString {
+	fn type_(&self) -> Datatype {
+		Datatype::String(Some(StringDatatype::NormalizedString(None)))
+	}
+}
+
+impl XsdDatatype for NormalizedStringBuf {
+	fn type_(&self) -> Datatype {
+		self.as_normalized_string().type_()
+	}
+}
+
+impl LexicalFormOf<NormalizedStringBuf> for lexical::NormalizedString {
+	type ValueError = std::convert::Infallible;
+
+	fn try_as_value(&self) -> Result<NormalizedStringBuf, Self::ValueError> {
+		Ok(self.value())
+	}
+}
+
+impl ParseRdf for NormalizedStringBuf {
+	type LexicalForm = lexical::NormalizedString;
+}
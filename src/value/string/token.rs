@@ -0,0 +1,34 @@
+use crate::{
+	lexical::{self, LexicalFormOf},
+	Datatype, NormalizedStringDatatype, ParseRdf, StringDatatype, XsdDatatype,
+};
+
+pub type Token = lexical::Token;
+
+pub type TokenBuf = lexical::TokenBuf;
+
+impl XsdDatatype for Token {
+	fn type_(&self) -> Datatype {
+		Datatype::String(Some(StringDatatype::NormalizedString(Some(
+			NormalizedStringDatatype::Token(None),
+		))))
+	}
+}
+
+impl XsdDatatype for TokenBuf {
+	fn type_(&self) -> Datatype {
+		self.as_token().type_()
+	}
+}
+
+impl LexicalFormOf<TokenBuf> for lexical::Token {
+	type ValueError = std::convert::Infallible;
+
+	fn try_as_value(&self) -> Result<TokenBuf, Self::ValueError> {
+		Ok(self.value())
+	}
+}
+
+impl ParseRdf for TokenBuf {
+	type LexicalForm = lexical::Token;
+}
@@ -1,8 +1,15 @@
-use chrono::{FixedOffset, NaiveTime};
+use chrono::{FixedOffset, NaiveTime, Timelike};
 
-use crate::{Datatype, XsdDatatype};
+use crate::{lexical, Datatype, Duration, ParseRdf, XsdDatatype};
 use core::fmt;
 
+use super::{
+	date::fmt_time_zone,
+	duration::{NANOSECONDS_PER_SECOND, SECONDS_PER_DAY},
+};
+
+const NANOSECONDS_PER_DAY: i128 = SECONDS_PER_DAY as i128 * NANOSECONDS_PER_SECOND as i128;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Time {
 	pub time: NaiveTime,
@@ -13,6 +20,54 @@ impl Time {
 	pub fn new(time: NaiveTime, time_zone: FixedOffset) -> Self {
 		Self { time, time_zone }
 	}
+
+	/// Returns `00:00:00` in UTC.
+	pub fn midnight() -> Self {
+		Self::new(NaiveTime::MIN, FixedOffset::east_opt(0).unwrap())
+	}
+
+	/// Returns the number of nanoseconds elapsed since midnight.
+	fn nano_seconds_from_midnight(&self) -> i128 {
+		self.time.num_seconds_from_midnight() as i128 * NANOSECONDS_PER_SECOND as i128
+			+ self.time.nanosecond() as i128
+	}
+
+	/// Adds the given duration to this time.
+	///
+	/// Only the day-time part of the duration is considered, the year-month
+	/// part being meaningless without a date. Returns the resulting time along
+	/// with the number of days the addition overflowed into (negative if the
+	/// result lands on a previous day).
+	pub fn add_duration(&self, d: &Duration) -> (Self, i64) {
+		let n = self.nano_seconds_from_midnight() + d.signed_nano_seconds();
+		let days = n.div_euclid(NANOSECONDS_PER_DAY) as i64;
+		let n = n.rem_euclid(NANOSECONDS_PER_DAY);
+
+		let time = NaiveTime::from_num_seconds_from_midnight_opt(
+			(n / NANOSECONDS_PER_SECOND as i128) as u32,
+			(n % NANOSECONDS_PER_SECOND as i128) as u32,
+		)
+		.unwrap();
+
+		(Self::new(time, self.time_zone), days)
+	}
+
+	/// Subtracts the given duration from this time.
+	///
+	/// See [`Self::add_duration`].
+	pub fn sub_duration(&self, d: &Duration) -> (Self, i64) {
+		self.add_duration(&-*d)
+	}
+
+	/// Computes the signed duration between `other` and `self`, assuming both
+	/// times are on the same day.
+	pub fn difference(&self, other: &Self) -> Duration {
+		let offset_diff = (self.time_zone.local_minus_utc() - other.time_zone.local_minus_utc())
+			as i128 * NANOSECONDS_PER_SECOND as i128;
+		Duration::from_signed_nano_seconds(
+			self.nano_seconds_from_midnight() - other.nano_seconds_from_midnight() - offset_diff,
+		)
+	}
 }
 
 impl XsdDatatype for Time {
@@ -21,8 +76,110 @@ impl XsdDatatype for Time {
 	}
 }
 
+impl ParseRdf for Time {
+	type LexicalForm = lexical::Time;
+}
+
 impl fmt::Display for Time {
-	fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		unimplemented!()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{:02}:{:02}:{:02}",
+			self.time.hour(),
+			self.time.minute(),
+			self.time.second()
+		)?;
+
+		let nano_seconds = self.time.nanosecond() % NANOSECONDS_PER_SECOND;
+		if nano_seconds != 0 {
+			let fraction = format!("{nano_seconds:09}");
+			write!(f, ".{}", fraction.trim_end_matches('0'))?
+		}
+
+		fmt_time_zone(&self.time_zone, f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn time(h: u32, m: u32, s: u32) -> Time {
+		Time::new(
+			NaiveTime::from_hms_opt(h, m, s).unwrap(),
+			FixedOffset::east_opt(0).unwrap(),
+		)
+	}
+
+	#[test]
+	fn add_duration_01() {
+		let (t, days) = time(23, 0, 0).add_duration(&Duration::new(false, 0, 7200, 0));
+		assert_eq!(t.time, time(1, 0, 0).time);
+		assert_eq!(days, 1)
+	}
+
+	#[test]
+	fn add_duration_02() {
+		let (t, days) = time(1, 0, 0).add_duration(&Duration::new(true, 0, 7200, 0));
+		assert_eq!(t.time, time(23, 0, 0).time);
+		assert_eq!(days, -1)
+	}
+
+	#[test]
+	fn add_duration_03() {
+		let (t, days) = time(12, 0, 0).add_duration(&Duration::new(false, 0, 3 * 86400 + 60, 0));
+		assert_eq!(t.time, time(12, 1, 0).time);
+		assert_eq!(days, 3)
+	}
+
+	#[test]
+	fn sub_duration_01() {
+		let (t, days) = time(0, 30, 0).sub_duration(&Duration::new(false, 0, 3600, 0));
+		assert_eq!(t.time, time(23, 30, 0).time);
+		assert_eq!(days, -1)
+	}
+
+	#[test]
+	fn difference_01() {
+		assert_eq!(
+			time(10, 0, 0).difference(&time(8, 30, 0)),
+			Duration::new(false, 0, 5400, 0)
+		);
+		assert_eq!(
+			time(8, 30, 0).difference(&time(10, 0, 0)),
+			Duration::new(true, 0, 5400, 0)
+		)
+	}
+
+	#[test]
+	fn format_01() {
+		assert_eq!(time(13, 20, 0).to_string(), "13:20:00Z");
+		assert_eq!(
+			Time::new(
+				NaiveTime::from_hms_nano_opt(1, 2, 3, 500_000_000).unwrap(),
+				FixedOffset::west_opt(5 * 3600).unwrap()
+			)
+			.to_string(),
+			"01:02:03.5-05:00"
+		)
+	}
+
+	#[test]
+	fn midnight_01() {
+		assert_eq!(Time::midnight().time, time(0, 0, 0).time)
+	}
+
+	#[test]
+	fn parse_rdf_01() {
+		for (input, expected) in [
+			("12:00:00", "12:00:00Z"),
+			("23:59:59.250-05:00", "23:59:59.25-05:00"),
+			("24:00:00", "00:00:00Z"),
+			("01:02:03.1234567891", "01:02:03.123456789Z"),
+		] {
+			assert_eq!(Time::parse_rdf(input).ok().unwrap().to_string(), expected)
+		}
+
+		assert!(Time::parse_rdf("12:00").is_err())
 	}
 }
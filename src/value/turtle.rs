@@ -0,0 +1,321 @@
+use std::{collections::HashMap, fmt};
+
+use iref::{Iri, IriBuf};
+
+use crate::{Datatype, ParseError, Value, XsdDatatype};
+
+/// Typed literal representation of a value in Turtle or N-Triples syntax.
+///
+/// The literal is displayed as the quoted canonical lexical form of the
+/// value followed by its datatype IRI, e.g.
+/// `"42"^^<http://www.w3.org/2001/XMLSchema#integer>`.
+#[derive(Debug, Clone, Copy)]
+pub struct TurtleLiteral<'a>(pub &'a Value);
+
+impl<'a> TurtleLiteral<'a> {
+	/// Returns the N-Triples representation of the literal.
+	///
+	/// Typed literals use the same syntax in N-Triples as the one emitted
+	/// by the `Display` implementation, with the datatype IRI always between
+	/// angle brackets.
+	pub fn to_n_triples(&self) -> String {
+		self.to_string()
+	}
+}
+
+/// Map from prefixes to namespace IRIs, used to resolve prefixed names.
+#[derive(Debug, Default, Clone)]
+pub struct NamespaceMap(HashMap<String, IriBuf>);
+
+impl NamespaceMap {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Binds the given prefix to a namespace IRI, returning the namespace
+	/// previously bound to it, if any.
+	pub fn insert(&mut self, prefix: impl Into<String>, namespace: IriBuf) -> Option<IriBuf> {
+		self.0.insert(prefix.into(), namespace)
+	}
+
+	/// Returns the namespace IRI bound to the given prefix.
+	pub fn get(&self, prefix: &str) -> Option<&Iri> {
+		self.0.get(prefix).map(IriBuf::as_iri)
+	}
+}
+
+impl Value {
+	/// Returns a wrapper displaying this value as a Turtle typed literal.
+	pub fn turtle_literal(&self) -> TurtleLiteral<'_> {
+		TurtleLiteral(self)
+	}
+
+	/// Parses a Turtle typed literal whose datatype is given as a full IRI,
+	/// e.g. `"42"^^<http://www.w3.org/2001/XMLSchema#integer>`.
+	pub fn parse_turtle_literal(s: &str) -> Result<Self, ParseError> {
+		Self::parse_turtle_literal_with_ns(s, &NamespaceMap::new())
+	}
+
+	/// Parses a Turtle typed literal whose datatype is given either as a full
+	/// IRI or as a prefixed name (e.g. `"42"^^xsd:integer`) resolved using
+	/// the given namespace map.
+	pub fn parse_turtle_literal_with_ns(s: &str, ns: &NamespaceMap) -> Result<Self, ParseError> {
+		let (lexical_value, rest) = parse_quoted(s)?;
+		let datatype = rest.strip_prefix("^^").ok_or(ParseError)?;
+
+		let datatype = match datatype.strip_prefix('<') {
+			Some(iri) => {
+				let iri = iri.strip_suffix('>').ok_or(ParseError)?;
+				Datatype::from_iri(Iri::new(iri).map_err(|_| ParseError)?)
+			}
+			None => {
+				let (prefix, local) = datatype.split_once(':').ok_or(ParseError)?;
+				let iri = format!("{}{local}", ns.get(prefix).ok_or(ParseError)?);
+				Datatype::from_iri(Iri::new(&iri).map_err(|_| ParseError)?)
+			}
+		};
+
+		datatype.ok_or(ParseError)?.parse(&lexical_value)
+	}
+}
+
+impl<'a> fmt::Display for TurtleLiteral<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("\"")?;
+		write_escaped(&self.0.canonical_lexical_form(), f)?;
+		write!(f, "\"^^<{}>", self.0.type_().iri())
+	}
+}
+
+/// Writes the given string, escaping characters that cannot appear as is in
+/// a quoted Turtle/N-Triples string literal.
+fn write_escaped(s: &str, f: &mut fmt::Formatter) -> fmt::Result {
+	for c in s.chars() {
+		match c {
+			'"' => f.write_str("\\\"")?,
+			'\\' => f.write_str("\\\\")?,
+			'\n' => f.write_str("\\n")?,
+			'\r' => f.write_str("\\r")?,
+			'\t' => f.write_str("\\t")?,
+			'\u{08}' => f.write_str("\\b")?,
+			'\u{0c}' => f.write_str("\\f")?,
+			c => fmt::Write::write_char(f, c)?,
+		}
+	}
+
+	Ok(())
+}
+
+/// Parses a single-line quoted string literal, returning its unescaped
+/// content and the remaining input.
+fn parse_quoted(s: &str) -> Result<(String, &str), ParseError> {
+	let mut chars = s.char_indices();
+	let quote = match chars.next() {
+		Some((_, c @ ('"' | '\''))) => c,
+		_ => return Err(ParseError),
+	};
+
+	let mut result = String::new();
+	while let Some((i, c)) = chars.next() {
+		match c {
+			c if c == quote => return Ok((result, &s[i + 1..])),
+			'\\' => {
+				let c = match chars.next().ok_or(ParseError)?.1 {
+					't' => '\t',
+					'b' => '\u{08}',
+					'n' => '\n',
+					'r' => '\r',
+					'f' => '\u{0c}',
+					'"' => '"',
+					'\'' => '\'',
+					'\\' => '\\',
+					'u' => parse_hex_char(&mut chars, 4)?,
+					'U' => parse_hex_char(&mut chars, 8)?,
+					_ => return Err(ParseError),
+				};
+
+				result.push(c)
+			}
+			'\n' | '\r' => return Err(ParseError),
+			c => result.push(c),
+		}
+	}
+
+	Err(ParseError)
+}
+
+fn parse_hex_char(chars: &mut std::str::CharIndices, len: usize) -> Result<char, ParseError> {
+	let mut n = 0;
+	for _ in 0..len {
+		let d = chars
+			.next()
+			.ok_or(ParseError)?
+			.1
+			.to_digit(16)
+			.ok_or(ParseError)?;
+		n = n * 16 + d
+	}
+
+	char::from_u32(n).ok_or(ParseError)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Date, DateTime, Double, Integer};
+
+	fn literal(value: Value) -> String {
+		value.turtle_literal().to_string()
+	}
+
+	#[test]
+	fn format_01() {
+		assert_eq!(
+			literal(Value::Integer(Integer::from(42i64))),
+			"\"42\"^^<http://www.w3.org/2001/XMLSchema#integer>"
+		);
+		assert_eq!(
+			literal(Value::UnsignedByte(42)),
+			"\"42\"^^<http://www.w3.org/2001/XMLSchema#unsignedByte>"
+		);
+		assert_eq!(
+			literal(Value::Decimal("1.50".parse().unwrap())),
+			"\"1.5\"^^<http://www.w3.org/2001/XMLSchema#decimal>"
+		);
+		assert_eq!(
+			literal(Value::Boolean(true)),
+			"\"true\"^^<http://www.w3.org/2001/XMLSchema#boolean>"
+		);
+	}
+
+	#[test]
+	fn format_02() {
+		assert_eq!(
+			literal(Value::String("say \"hi\"\n\\".to_owned())),
+			"\"say \\\"hi\\\"\\n\\\\\"^^<http://www.w3.org/2001/XMLSchema#string>"
+		)
+	}
+
+	#[test]
+	fn format_03() {
+		assert_eq!(
+			literal(Value::Double(Double::from(1.5f64))),
+			format!(
+				"\"{}\"^^<http://www.w3.org/2001/XMLSchema#double>",
+				Double::from(1.5f64)
+			)
+		);
+		assert_eq!(
+			literal(Value::Date(Date::from_ymd(2002, 10, 10).unwrap())),
+			"\"2002-10-10Z\"^^<http://www.w3.org/2001/XMLSchema#date>"
+		);
+		let date_time: DateTime = "2002-10-10T12:00:00-05:00".parse().unwrap();
+		assert_eq!(
+			literal(Value::DateTime(date_time)),
+			"\"2002-10-10T12:00:00-05:00\"^^<http://www.w3.org/2001/XMLSchema#dateTime>"
+		)
+	}
+
+	#[test]
+	fn n_triples_01() {
+		let value = Value::Integer(Integer::from(-1i64));
+		assert_eq!(
+			value.turtle_literal().to_n_triples(),
+			"\"-1\"^^<http://www.w3.org/2001/XMLSchema#integer>"
+		)
+	}
+
+	#[test]
+	fn parse_01() {
+		assert_eq!(
+			Value::parse_turtle_literal(r#""42"^^<http://www.w3.org/2001/XMLSchema#integer>"#)
+				.unwrap(),
+			Value::Integer(Integer::from(42i64))
+		);
+		assert_eq!(
+			Value::parse_turtle_literal(r#""1.5"^^<http://www.w3.org/2001/XMLSchema#decimal>"#)
+				.unwrap(),
+			Value::Decimal("1.5".parse().unwrap())
+		);
+		assert_eq!(
+			Value::parse_turtle_literal(r#""true"^^<http://www.w3.org/2001/XMLSchema#boolean>"#)
+				.unwrap(),
+			Value::Boolean(true)
+		);
+		assert_eq!(
+			Value::parse_turtle_literal(
+				r#""2002-10-10T12:00:00-05:00"^^<http://www.w3.org/2001/XMLSchema#dateTime>"#
+			)
+			.unwrap(),
+			Value::DateTime("2002-10-10T12:00:00-05:00".parse().unwrap())
+		);
+	}
+
+	#[test]
+	fn parse_02() {
+		let mut ns = NamespaceMap::new();
+		ns.insert(
+			"xsd",
+			IriBuf::new("http://www.w3.org/2001/XMLSchema#".to_owned()).unwrap(),
+		);
+
+		assert_eq!(
+			Value::parse_turtle_literal_with_ns(r#"'12'^^xsd:int"#, &ns).unwrap(),
+			Value::Int(12)
+		);
+		assert_eq!(
+			Value::parse_turtle_literal_with_ns(r#""a\"b\u00e9"^^xsd:string"#, &ns).unwrap(),
+			Value::String("a\"b\u{e9}".to_owned())
+		);
+		assert!(Value::parse_turtle_literal_with_ns(r#""12"^^foo:int"#, &ns).is_err());
+	}
+
+	#[test]
+	fn parse_03() {
+		assert!(Value::parse_turtle_literal(r#""42"^^<http://example.org/#integer>"#).is_err());
+		assert!(Value::parse_turtle_literal(
+			r#""4.2"^^<http://www.w3.org/2001/XMLSchema#integer>"#
+		)
+		.is_err());
+		assert!(Value::parse_turtle_literal(r#""42"^^xsd:integer"#).is_err());
+		assert!(Value::parse_turtle_literal(r#""42"#).is_err());
+	}
+
+	#[test]
+	fn round_trip_01() {
+		let value = Value::String("line\n\"quoted\"\ttab".to_owned());
+		assert_eq!(
+			Value::parse_turtle_literal(&value.turtle_literal().to_string()).unwrap(),
+			value
+		)
+	}
+
+	#[test]
+	fn round_trip_02() {
+		let value = crate::ListDatatype::NMTokens.parse("a  b").unwrap();
+		let literal = value.turtle_literal().to_string();
+		assert_eq!(
+			literal,
+			"\"a b\"^^<http://www.w3.org/2001/XMLSchema#NMTOKENS>"
+		);
+		assert_eq!(Value::parse_turtle_literal(&literal).unwrap(), value)
+	}
+
+	#[test]
+	fn round_trip_03() {
+		for value in [
+			Value::Double(Double::INFINITY),
+			Value::Double(Double::NEG_INFINITY),
+			Value::Double(Double::NAN),
+			Value::Float(crate::Float::NEG_INFINITY),
+		] {
+			let literal = value.turtle_literal().to_string();
+			assert_eq!(Value::parse_turtle_literal(&literal).unwrap(), value)
+		}
+
+		assert_eq!(
+			literal(Value::Double(Double::INFINITY)),
+			"\"INF\"^^<http://www.w3.org/2001/XMLSchema#double>"
+		)
+	}
+}
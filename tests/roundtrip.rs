@@ -0,0 +1,205 @@
+//! Property-based round-trip tests for numeric values: formatting a value and
+//! parsing it back must give the same value.
+#![cfg(feature = "std")]
+use proptest::prelude::*;
+use xsd_types::{
+	lexical::{self, LexicalFormOf},
+	Byte, Decimal, Double, Float, Int, Integer, Long, NonNegativeInteger, Short, UnsignedByte,
+	UnsignedInt, UnsignedLong, UnsignedShort,
+};
+
+/// Formats a primitive integer value and parses it back through its XSD
+/// lexical form.
+fn integer_roundtrip<T>(value: T) -> T
+where
+	T: ToString,
+	lexical::Integer: LexicalFormOf<T>,
+	<lexical::Integer as LexicalFormOf<T>>::ValueError: std::fmt::Debug,
+{
+	let s = value.to_string();
+	lexical::Integer::new(&s).unwrap().try_as_value().unwrap()
+}
+
+/// Formats a primitive unsigned integer value and parses it back through its
+/// XSD lexical form.
+fn unsigned_roundtrip<T>(value: T) -> T
+where
+	T: ToString,
+	lexical::NonNegativeInteger: LexicalFormOf<T>,
+	<lexical::NonNegativeInteger as LexicalFormOf<T>>::ValueError: std::fmt::Debug,
+{
+	let s = value.to_string();
+	lexical::NonNegativeInteger::new(&s)
+		.unwrap()
+		.try_as_value()
+		.unwrap()
+}
+
+/// Checks that a parsed float has the same bit pattern as the original one.
+///
+/// XSD has a single `NaN` value, formatted as `NaN`, so any `NaN` payload is
+/// expected to come back as [`f32::NAN`].
+fn same_f32(parsed: f32, original: f32) -> bool {
+	let expected = if original.is_nan() {
+		f32::NAN
+	} else {
+		original
+	};
+	parsed.to_bits() == expected.to_bits()
+}
+
+/// Checks that a parsed double has the same bit pattern as the original one.
+///
+/// XSD has a single `NaN` value, formatted as `NaN`, so any `NaN` payload is
+/// expected to come back as [`f64::NAN`].
+fn same_f64(parsed: f64, original: f64) -> bool {
+	let expected = if original.is_nan() {
+		f64::NAN
+	} else {
+		original
+	};
+	parsed.to_bits() == expected.to_bits()
+}
+
+#[test]
+fn float_special_values() {
+	for f in [f32::INFINITY, f32::NEG_INFINITY, -0.0, 0.0, f32::NAN] {
+		let s = Float::from(f).to_string();
+		let parsed: Float = s.parse().unwrap();
+		assert!(same_f32(parsed.into_f32(), f), "{s}");
+		assert_eq!(parsed.to_string(), s)
+	}
+}
+
+#[test]
+fn double_special_values() {
+	for f in [f64::INFINITY, f64::NEG_INFINITY, -0.0, 0.0, f64::NAN] {
+		let s = Double::from(f).to_string();
+		let parsed: Double = s.parse().unwrap();
+		assert!(same_f64(parsed.into_f64(), f), "{s}");
+		assert_eq!(parsed.to_string(), s)
+	}
+}
+
+proptest! {
+	#[test]
+	fn integer_i64(n: i64) {
+		let value = Integer::from(n);
+		prop_assert_eq!(value.to_string().parse::<Integer>().unwrap(), value);
+	}
+
+	#[test]
+	fn integer_big(s in "-?[1-9][0-9]{0,80}") {
+		let value: Integer = s.parse().unwrap();
+		prop_assert_eq!(value.to_string().parse::<Integer>().unwrap(), value);
+	}
+
+	#[test]
+	fn long(n: i64) {
+		prop_assert_eq!(integer_roundtrip::<Long>(n), n);
+	}
+
+	#[test]
+	fn int(n: i32) {
+		prop_assert_eq!(integer_roundtrip::<Int>(n), n);
+	}
+
+	#[test]
+	fn short(n: i16) {
+		prop_assert_eq!(integer_roundtrip::<Short>(n), n);
+	}
+
+	#[test]
+	fn byte(n: i8) {
+		prop_assert_eq!(integer_roundtrip::<Byte>(n), n);
+	}
+
+	#[test]
+	fn non_negative_integer_u64(n: u64) {
+		let value = NonNegativeInteger::from(n);
+		prop_assert_eq!(value.to_string().parse::<NonNegativeInteger>().unwrap(), value);
+	}
+
+	#[test]
+	fn non_negative_integer_big(s in "[1-9][0-9]{0,80}") {
+		let value: NonNegativeInteger = s.parse().unwrap();
+		prop_assert_eq!(value.to_string().parse::<NonNegativeInteger>().unwrap(), value);
+	}
+
+	#[test]
+	fn unsigned_long(n: u64) {
+		prop_assert_eq!(unsigned_roundtrip::<UnsignedLong>(n), n);
+	}
+
+	#[test]
+	fn unsigned_int(n: u32) {
+		prop_assert_eq!(unsigned_roundtrip::<UnsignedInt>(n), n);
+	}
+
+	#[test]
+	fn unsigned_short(n: u16) {
+		prop_assert_eq!(unsigned_roundtrip::<UnsignedShort>(n), n);
+	}
+
+	#[test]
+	fn unsigned_byte(n: u8) {
+		prop_assert_eq!(unsigned_roundtrip::<UnsignedByte>(n), n);
+	}
+
+	#[test]
+	fn decimal(s in "-?(0|[1-9][0-9]{0,30})(\\.[0-9]{0,30}[1-9])?") {
+		let value: Decimal = s.parse().unwrap();
+		prop_assert_eq!(value.to_string().parse::<Decimal>().unwrap(), value);
+	}
+
+	#[test]
+	fn float(f: f32) {
+		let value = Float::from(f);
+		let parsed: Float = value.to_string().parse().unwrap();
+		prop_assert!(same_f32(parsed.into_f32(), f), "{} != {}", parsed, value);
+	}
+
+	#[test]
+	fn float_bits(bits: u32) {
+		let f = f32::from_bits(bits);
+		let parsed: Float = Float::from(f).to_string().parse().unwrap();
+		prop_assert!(same_f32(parsed.into_f32(), f));
+	}
+
+	#[test]
+	fn double(f: f64) {
+		let value = Double::from(f);
+		let parsed: Double = value.to_string().parse().unwrap();
+		prop_assert!(same_f64(parsed.into_f64(), f), "{} != {}", parsed, value);
+	}
+
+	#[test]
+	fn double_bits(bits: u64) {
+		let f = f64::from_bits(bits);
+		let parsed: Double = Double::from(f).to_string().parse().unwrap();
+		prop_assert!(same_f64(parsed.into_f64(), f));
+	}
+
+	#[test]
+	fn canonical_integer(s in "0|-?[1-9][0-9]{0,80}") {
+		prop_assert_eq!(s.parse::<Integer>().unwrap().to_string(), s);
+	}
+
+	#[test]
+	fn canonical_decimal(s in "0|-?(0|[1-9][0-9]{0,30})\\.[0-9]{0,30}[1-9]|-?[1-9][0-9]{0,30}") {
+		let value: Decimal = s.parse().unwrap();
+		prop_assert_eq!(value.to_string(), s);
+	}
+
+	#[test]
+	fn canonical_float(f: f32) {
+		let s = Float::from(f).to_string();
+		prop_assert_eq!(s.parse::<Float>().unwrap().to_string(), s);
+	}
+
+	#[test]
+	fn canonical_double(f: f64) {
+		let s = Double::from(f).to_string();
+		prop_assert_eq!(s.parse::<Double>().unwrap().to_string(), s);
+	}
+}